@@ -0,0 +1,119 @@
+use std::{
+    cmp::Reverse,
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct BackupFile {
+    pub path: PathBuf,
+    pub timestamp: u64,
+}
+
+// Every save directory gets its own backup directory so that saves with the same name
+// (`Save_0001.pcsav` in two careers for example) do not share their backups
+fn backup_dir(path: &Path) -> Option<PathBuf> {
+    let parent = path.parent()?;
+    let parent_name = parent.file_name().map(OsStr::to_string_lossy).unwrap_or_default();
+
+    // FNV-1a, stable between builds unlike `DefaultHasher`
+    let hash = parent.to_string_lossy().bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+
+    dirs::data_local_dir().map(|mut dir| {
+        dir.push("Trilogy Save Editor");
+        dir.push("Backups");
+        dir.push(format!("{} {:016x}", parent_name, hash));
+        dir
+    })
+}
+
+// `Save_0001.pcsav` => (`Save_0001.`, `.pcsav`)
+fn backup_affixes(path: &Path) -> Option<(String, String)> {
+    let stem = path.file_stem()?.to_string_lossy();
+    let ext = path.extension().map(OsStr::to_string_lossy).unwrap_or_default();
+    Some((format!("{}.", stem), format!(".{}", ext)))
+}
+
+pub fn list(path: &Path) -> Result<Vec<BackupFile>> {
+    let (dir, (prefix, suffix)) = match backup_dir(path).zip(backup_affixes(path)) {
+        Some(backup) => backup,
+        None => return Ok(Vec::new()),
+    };
+
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut backups = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let timestamp = path.file_name().map(OsStr::to_string_lossy).and_then(|file_name| {
+            file_name
+                .strip_prefix(&prefix)
+                .and_then(|file_name| file_name.strip_suffix(&suffix))
+                .and_then(|timestamp| timestamp.parse().ok())
+        });
+
+        if let Some(timestamp) = timestamp {
+            backups.push(BackupFile { path, timestamp });
+        }
+    }
+
+    // Newest first
+    backups.sort_by_key(|backup| Reverse(backup.timestamp));
+    Ok(backups)
+}
+
+pub fn create(path: &Path, retention: usize) -> Result<()> {
+    if retention == 0 {
+        return Ok(());
+    }
+    backup_and_rotate(path, retention, None)
+}
+
+// Keeps a backup of the current save whatever the retention so that the restoration can be
+// undone, the restored backup is left out of the rotation
+pub fn create_before_restore(path: &Path, retention: usize, restored: &Path) -> Result<()> {
+    backup_and_rotate(path, retention.max(1), Some(restored))
+}
+
+fn backup_and_rotate(path: &Path, retention: usize, keep: Option<&Path>) -> Result<()> {
+    if !path.is_file() {
+        return Ok(());
+    }
+
+    let (dir, (prefix, suffix)) = match backup_dir(path).zip(backup_affixes(path)) {
+        Some(backup) => backup,
+        None => return Ok(()),
+    };
+    fs::create_dir_all(&dir).context("Failed to create the backup directory")?;
+
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+    let to = dir.join(format!("{}{}{}", prefix, timestamp, suffix));
+    fs::copy(path, to).context("Failed to backup the save")?;
+
+    // Rotate
+    let old_backups = list(path)?
+        .into_iter()
+        .filter(|backup| keep.map_or(true, |keep| !same_file(&backup.path, keep)))
+        .skip(retention);
+    for old_backup in old_backups {
+        fs::remove_file(old_backup.path)?;
+    }
+
+    Ok(())
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
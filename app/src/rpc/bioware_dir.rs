@@ -0,0 +1,135 @@
+use std::path::PathBuf;
+#[cfg(target_os = "linux")]
+use std::{env, fs, path::Path};
+
+// Directory where the games put their `Mass Effect *` save directories
+pub fn bioware_dir() -> Option<PathBuf> {
+    bioware_dirs().into_iter().next()
+}
+
+#[cfg(target_os = "windows")]
+pub fn bioware_dirs() -> Vec<PathBuf> {
+    dirs::document_dir()
+        .map(|path| path.join("BioWare"))
+        .into_iter()
+        .filter(|path| path.is_dir())
+        .collect()
+}
+
+// Every game runs in its own Wine prefix on Linux, so there can be several `BioWare` directories:
+// - Steam Proton: `<library>/steamapps/compatdata/<appid>/pfx/drive_c/users/steamuser/Documents/BioWare`
+// - Flatpak Steam: same thing but with the Steam directory inside `~/.var/app`
+// - Lutris / Wine: `<prefix>/drive_c/users/<user>/Documents/BioWare`
+#[cfg(target_os = "linux")]
+pub fn bioware_dirs() -> Vec<PathBuf> {
+    // ME1, ME2, ME3, Legendary Edition
+    const STEAM_APP_IDS: [&str; 4] = ["17460", "24980", "1238020", "1328670"];
+
+    let mut prefixes = Vec::new();
+
+    for library in steam_libraries() {
+        let compatdata = library.join("steamapps/compatdata");
+        for app_id in STEAM_APP_IDS {
+            prefixes.push(compatdata.join(app_id).join("pfx"));
+        }
+    }
+
+    if let Some(wine_prefix) = env::var_os("WINEPREFIX") {
+        prefixes.push(PathBuf::from(wine_prefix));
+    }
+
+    if let Some(home) = dirs::home_dir() {
+        prefixes.push(home.join(".wine"));
+
+        // Lutris installs every game in its own prefix inside `~/Games` by default
+        if let Ok(entries) = fs::read_dir(home.join("Games")) {
+            prefixes.extend(entries.flatten().map(|entry| entry.path()));
+        }
+    }
+
+    let mut bioware_dirs: Vec<PathBuf> = Vec::new();
+    for prefix in prefixes {
+        for bioware_dir in prefix_bioware_dirs(&prefix) {
+            let bioware_dir = bioware_dir.canonicalize().unwrap_or(bioware_dir);
+            if !bioware_dirs.contains(&bioware_dir) {
+                bioware_dirs.push(bioware_dir);
+            }
+        }
+    }
+    bioware_dirs
+}
+
+#[cfg(all(not(target_os = "linux"), not(target_os = "windows")))]
+pub fn bioware_dirs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+#[cfg(target_os = "linux")]
+fn steam_libraries() -> Vec<PathBuf> {
+    let home = match dirs::home_dir() {
+        Some(home) => home,
+        None => return Vec::new(),
+    };
+
+    let steam_roots = [
+        ".steam/root",
+        ".steam/steam",
+        ".local/share/Steam",
+        // Flatpak
+        ".var/app/com.valvesoftware.Steam/.local/share/Steam",
+        ".var/app/com.valvesoftware.Steam/data/Steam",
+    ];
+
+    let mut libraries: Vec<PathBuf> = Vec::new();
+    for steam_root in steam_roots.iter().map(|steam_root| home.join(steam_root)) {
+        if !steam_root.is_dir() {
+            continue;
+        }
+
+        let library_folders = steam_root.join("steamapps/libraryfolders.vdf");
+        let mut roots = vec![steam_root];
+        roots.extend(
+            fs::read_to_string(library_folders).map(parse_library_folders).unwrap_or_default(),
+        );
+
+        for library in roots {
+            let library = library.canonicalize().unwrap_or(library);
+            if !libraries.contains(&library) {
+                libraries.push(library);
+            }
+        }
+    }
+    libraries
+}
+
+// Only looks for the `"path"  "/path/to/library"` lines, no need for a full VDF parser
+#[cfg(target_os = "linux")]
+fn parse_library_folders(vdf: String) -> Vec<PathBuf> {
+    vdf.lines()
+        .filter_map(|line| {
+            let mut tokens = line.split('"').filter(|token| !token.trim().is_empty());
+            match (tokens.next(), tokens.next()) {
+                (Some("path"), Some(path)) => Some(PathBuf::from(path.replace("\\\\", "\\"))),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+// `<prefix>/drive_c/users/*/{Documents,My Documents}/BioWare`
+#[cfg(target_os = "linux")]
+fn prefix_bioware_dirs(prefix: &Path) -> Vec<PathBuf> {
+    let users = match prefix.join("drive_c/users").read_dir() {
+        Ok(users) => users,
+        Err(_) => return Vec::new(),
+    };
+
+    users
+        .flatten()
+        .flat_map(|user| {
+            let user = user.path();
+            ["Documents", "My Documents"].map(|documents| user.join(documents).join("BioWare"))
+        })
+        .filter(|bioware_dir| bioware_dir.is_dir())
+        .collect()
+}
@@ -1,12 +1,14 @@
-use std::{
-    fs,
-    path::{Path, PathBuf},
-};
+use std::{fs, io::Read, path::PathBuf};
 
-use anyhow::{Error, Result};
+use anyhow::{Context, Error, Result};
 use serde::{Deserialize, Serialize};
 
-use super::{dialog, Event, RpcUtils};
+use super::{
+    backup::{self, BackupFile},
+    bioware_dir, dialog,
+    saves::{self, SaveFile},
+    Event, RpcUtils,
+};
 
 // Commands
 pub fn init(utils: &RpcUtils) {
@@ -100,6 +102,41 @@ pub fn export_head_morph_dialog(utils: &RpcUtils) -> Result<Option<PathBuf>> {
     Ok(result)
 }
 
+pub fn backup_save(_: &RpcUtils, params: BackupParams) -> Result<()> {
+    let BackupParams { path, retention } = params;
+    backup::create(&path, retention)
+}
+
+pub fn list_backups(_: &RpcUtils, path: PathBuf) -> Result<Vec<BackupFile>> {
+    backup::list(&path)
+}
+
+pub fn restore_backup(_: &RpcUtils, params: RestoreParams) -> Result<RpcFile> {
+    let RestoreParams { backup, path, retention } = params;
+
+    // Read before backing up the current save, the rotation must not lose the restored backup
+    let restored = fs::read(&backup).context("Failed to read the backup")?;
+    backup::create_before_restore(&path, retention, &backup)?;
+    fs::write(&path, restored)?;
+
+    open_file(path)
+}
+
+// Only the start of the file, enough to list a save without sending all of it
+pub fn read_save_header(_: &RpcUtils, params: HeaderParams) -> Result<RpcFile> {
+    let HeaderParams { path, len } = params;
+
+    let mut file = Vec::new();
+    fs::File::open(path.canonicalize()?)?.take(len as u64).read_to_end(&mut file)?;
+    let unencoded_size = file.len();
+    let base64 = base64::encode(file);
+    Ok(RpcFile { path, file: Base64File { unencoded_size, base64 } })
+}
+
+pub fn list_saves(_: &RpcUtils) -> Result<Vec<SaveFile>> {
+    saves::list(&bioware_dir::bioware_dirs())
+}
+
 pub fn load_database(_: &RpcUtils, path: PathBuf) -> Result<RpcFile> {
     #[cfg(not(debug_assertions))]
     let path = std::env::current_exe()?.parent().map(|parent| parent.join(&path)).unwrap_or(path);
@@ -117,16 +154,6 @@ fn open_file(path: PathBuf) -> Result<RpcFile> {
 
 fn write_file(rpc_file: RpcFile) -> Result<()> {
     let RpcFile { path, file } = rpc_file;
-
-    // Backup if file exists
-    if path.exists() {
-        if let Some(ext) = path.extension() {
-            let mut ext = ext.to_owned();
-            ext.push(".bak");
-            let to = Path::with_extension(&path, ext);
-            fs::copy(&path, to)?;
-        }
-    }
     fs::write(path, file.decode()?)?;
 
     Ok(())
@@ -138,6 +165,25 @@ pub struct DialogParams {
     pub filters: Vec<(String, Vec<String>)>,
 }
 
+#[derive(Deserialize, Default)]
+pub struct BackupParams {
+    pub path: PathBuf,
+    pub retention: usize,
+}
+
+#[derive(Deserialize, Default)]
+pub struct RestoreParams {
+    pub backup: PathBuf,
+    pub path: PathBuf,
+    pub retention: usize,
+}
+
+#[derive(Deserialize, Default)]
+pub struct HeaderParams {
+    pub path: PathBuf,
+    pub len: usize,
+}
+
 #[derive(Deserialize, Serialize, Default)]
 pub struct RpcFile {
     pub path: PathBuf,
@@ -3,7 +3,7 @@ use std::path::PathBuf;
 
 use wry::application::window::Window;
 
-use super::command::DialogParams;
+use super::{bioware_dir::bioware_dir, command::DialogParams};
 
 pub fn open_save(window: &Window, last_dir: bool) -> Option<PathBuf> {
     let mut dialog = rfd::FileDialog::new()
@@ -59,31 +59,6 @@ pub fn export_head_morph(window: &Window) -> Option<PathBuf> {
     with_parent(dialog, window).save_file()
 }
 
-#[cfg(target_os = "windows")]
-fn bioware_dir() -> Option<PathBuf> {
-    dirs::document_dir().and_then(|mut path| {
-        path.push("BioWare\\");
-        path.is_dir().then(|| path)
-    })
-}
-
-// FIXME: Find some nicer way of finding where the game saves are.
-// Currently, this should be universal for everyone who has their
-// Mass Effect games installed in the default steam library, in
-// the user's home directory.
-#[cfg(target_os = "linux")]
-fn bioware_dir() -> Option<PathBuf> {
-    dirs::home_dir().and_then(|mut path| {
-        path.push(".steam/root/steamapps/compatdata/1328670/pfx/drive_c/users/steamuser/My Documents/BioWare/");
-        path.is_dir().then(|| path)
-    })
-}
-
-#[cfg(all(not(target_os = "linux"), not(target_os = "windows")))]
-fn bioware_dir() -> Option<PathBuf> {
-    None
-}
-
 // FIXME: Remove this and set directly `set_parent` when `tao` will implement `raw_window_handle` for linux
 #[cfg(not(target_os = "linux"))]
 fn with_parent(dialog: rfd::FileDialog, window: &Window) -> rfd::FileDialog {
@@ -1,5 +1,8 @@
+mod backup;
+mod bioware_dir;
 mod command;
 mod dialog;
+mod saves;
 
 use std::env;
 use std::path::PathBuf;
@@ -88,6 +91,7 @@ pub fn rpc_handler(mut req: RpcRequest, utils: RpcUtils) -> Option<RpcResponse>
             command::download_and_install_update,
             command::import_head_morph,
             command::export_head_morph_dialog,
+            command::list_saves,
         ]);
 
         call_commands_with_param!(req, utils => [
@@ -96,6 +100,10 @@ pub fn rpc_handler(mut req: RpcRequest, utils: RpcUtils) -> Option<RpcResponse>
             command::save_file,
             command::save_save_dialog,
             command::reload_save,
+            command::backup_save,
+            command::list_backups,
+            command::restore_backup,
+            command::read_save_header,
             command::load_database,
         ]);
 
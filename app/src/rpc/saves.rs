@@ -0,0 +1,70 @@
+use std::{
+    cmp::Reverse,
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+
+use anyhow::Result;
+use serde::Serialize;
+
+const SAVE_EXTENSIONS: [&str; 4] = ["pcsav", "xbsav", "ps4sav", "MassEffectSave"];
+
+// `BioWare/Mass Effect Legendary Edition/Save/ME1/<Career>/<Save>` is the deepest layout
+const MAX_DEPTH: usize = 5;
+
+#[derive(Serialize)]
+pub struct SaveFile {
+    pub path: PathBuf,
+    pub modified: u64,
+}
+
+pub fn list(bioware_dirs: &[PathBuf]) -> Result<Vec<SaveFile>> {
+    let mut saves = Vec::new();
+    for bioware_dir in bioware_dirs {
+        scan_dir(bioware_dir, 0, &mut saves);
+    }
+
+    // Newest first
+    saves.sort_by_key(|save| Reverse(save.modified));
+    Ok(saves)
+}
+
+// An unreadable entry is skipped so that it doesn't hide the other saves
+fn scan_dir(dir: &Path, depth: usize, saves: &mut Vec<SaveFile>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries {
+        let (path, metadata) = match entry.and_then(|entry| Ok((entry.path(), entry.metadata()?))) {
+            Ok(entry) => entry,
+            Err(_) => continue,
+        };
+
+        if metadata.is_dir() {
+            if depth < MAX_DEPTH {
+                scan_dir(&path, depth + 1, saves);
+            }
+            continue;
+        }
+
+        let is_save = path
+            .extension()
+            .and_then(OsStr::to_str)
+            .map(|ext| SAVE_EXTENSIONS.iter().any(|save_ext| save_ext.eq_ignore_ascii_case(ext)))
+            .unwrap_or_default();
+
+        if is_save {
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+                .map(|modified| modified.as_millis() as u64)
+                .unwrap_or_default();
+            saves.push(SaveFile { path, modified });
+        }
+    }
+}
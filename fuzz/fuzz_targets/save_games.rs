@@ -0,0 +1,30 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use serde::de::DeserializeOwned;
+use trilogy_save_editor::{
+    save_data::{
+        mass_effect_1::{player::Player, state::State, Me1SaveGame},
+        mass_effect_1_le::{Me1LeSaveData, Me1LeSaveGame},
+        mass_effect_2::{Me2LeSaveGame, Me2SaveGame},
+        mass_effect_3::Me3SaveGame,
+    },
+    unreal,
+};
+
+// Arbitrary bytes must return an error, never panic
+fn deserialize<T: DeserializeOwned>(data: &[u8]) {
+    let _ = unreal::Deserializer::from_bytes::<T>(data);
+    let _ = unreal::Deserializer::from_be_bytes::<T>(data);
+}
+
+fuzz_target!(|data: &[u8]| {
+    deserialize::<Me1SaveGame>(data);
+    deserialize::<Player>(data);
+    deserialize::<State>(data);
+    deserialize::<Me1LeSaveGame>(data);
+    deserialize::<Me1LeSaveData>(data);
+    deserialize::<Me2SaveGame>(data);
+    deserialize::<Me2LeSaveGame>(data);
+    deserialize::<Me3SaveGame>(data);
+});
@@ -9,17 +9,22 @@ use crate::{
     gui::{
         components::{AutoUpdate, NavBar, Tab, TabBar, Table},
         format_code,
-        mass_effect_1::{Me1General, Me1Plot, Me1RawData, Me1RawPlot},
-        mass_effect_1_le::{Me1LeGeneral, Me1LeInventory},
-        mass_effect_2::{Me2General, Me2Plot, Me2RawPlot, Me2Type},
-        mass_effect_3::{Me3General, Me3Plot, Me3RawPlot},
+        mass_effect_1::{Me1General, Me1Inventory, Me1Plot, Me1RawData, Me1RawPlot, Me1Talents},
+        mass_effect_1_le::{
+            Me1LeGeneral, Me1LeInventory, Me1LeLoadouts, Me1LeMerchants, Me1LeTalents,
+            Me1LeWorldObjects,
+        },
+        mass_effect_2::{Me2GalaxyMap, Me2General, Me2Plot, Me2RawPlot, Me2Squad, Me2Type},
+        mass_effect_3::{
+            Me3GalaxyMap, Me3General, Me3PlayerVariables, Me3Plot, Me3Powers, Me3RawPlot, Me3Squad,
+            Me3Treasures, Me3WarAssets, Me3Weapons,
+        },
         raw_ui::RawUi,
-        shared::HeadMorph,
-        shared::{FloatPlotType, IntPlotType},
+        shared::{Appearance, FloatPlotType, HeadMorph, IntPlotType, Journal, JournalType},
     },
     save_data::{
         mass_effect_1::Me1SaveGame, mass_effect_1_le::Me1LeSaveData, mass_effect_3::Me3SaveGame,
-        RcRef,
+        RcCell, RcRef,
     },
     services::{
         database::DatabaseProvider,
@@ -201,6 +206,12 @@ fn mass_effect_1(save_game: Ref<'_, Me1SaveGame>) -> Html {
                         integers={IntPlotType::Vec(RcRef::clone(&plot.integers))}
                     />
                 </Tab>
+                <Tab title="Talents">
+                    <Me1Talents player={RcRef::clone(&save_game.player)} />
+                </Tab>
+                <Tab title="Inventory">
+                    <Me1Inventory player={RcRef::clone(&save_game.player)} />
+                </Tab>
                 <Tab title="Raw Data">
                     <Me1RawData player={RcRef::clone(&save_game.player)} />
                 </Tab>
@@ -220,6 +231,21 @@ fn mass_effect_1_le(save_game: RcRef<Me1LeSaveData>) -> Html {
     let me1 = save_game.borrow();
     let plot = me1.plot();
     let head_morph = RcRef::clone(&me1.player().head_morph);
+    // Export saves have no merchants or world objects
+    let merchants = me1.no_export().as_ref().map(|no_export| {
+        html_nested! {
+            <Tab title="Merchants">
+                <Me1LeMerchants no_export={RcRef::clone(no_export)} />
+            </Tab>
+        }
+    });
+    let world_objects = me1.no_export().as_ref().map(|no_export| {
+        html_nested! {
+            <Tab title="World Objects">
+                <Me1LeWorldObjects no_export={RcRef::clone(no_export)} />
+            </Tab>
+        }
+    });
 
     html! {
         <section class="flex-auto flex p-1">
@@ -233,12 +259,32 @@ fn mass_effect_1_le(save_game: RcRef<Me1LeSaveData>) -> Html {
                         integers={IntPlotType::Vec(RcRef::clone(&plot.integers))}
                     />
                 </Tab>
+                <Tab title="Talents">
+                    <Me1LeTalents
+                        player={RcRef::clone(&me1.player)}
+                        squad={RcRef::clone(&me1.squad)}
+                    />
+                </Tab>
                 <Tab title="Inventory">
                     <Me1LeInventory
                         player={RcRef::clone(&me1.player)}
                         squad={RcRef::clone(&me1.squad)}
                     />
                 </Tab>
+                <Tab title="Loadouts">
+                    <Me1LeLoadouts
+                        player={RcRef::clone(&me1.player)}
+                        squad={RcRef::clone(&me1.squad)}
+                    />
+                </Tab>
+                { for merchants }
+                { for world_objects }
+                <Tab title="Journal">
+                    <Journal
+                        journal={JournalType::Me1Le(RcRef::clone(&me1.journal))}
+                        codex={RcRef::clone(&me1.codex)}
+                    />
+                </Tab>
                 <Tab title="Head Morph">
                     <HeadMorph {head_morph} />
                 </Tab>
@@ -258,21 +304,31 @@ fn mass_effect_1_le(save_game: RcRef<Me1LeSaveData>) -> Html {
 }
 
 fn mass_effect_2(save_game: Me2Type) -> Html {
-    let (raw_data, plot, me1_plot, head_morph) = match save_game {
+    let (raw_data, plot, me1_plot, squad, appearance, galaxy_map, journal, codex) = match save_game
+    {
         Me2Type::Vanilla(ref me2) => (
             me2.view_opened("Mass Effect 2", true),
             RcRef::clone(&me2.borrow().plot),
             RcRef::clone(&me2.borrow().me1_plot),
-            RcRef::clone(&me2.borrow().player().appearance().head_morph),
+            RcRef::clone(&me2.borrow().squad),
+            RcRef::clone(&me2.borrow().player().appearance),
+            RcRef::clone(&me2.borrow().galaxy_map),
+            JournalType::Me2(RcRef::clone(&me2.borrow().journal)),
+            RcRef::clone(&me2.borrow().codex),
         ),
         Me2Type::Legendary(ref me2) => (
             me2.view_opened("Mass Effect 2", true),
             RcRef::clone(&me2.borrow().plot),
             RcRef::clone(&me2.borrow().me1_plot),
-            RcRef::clone(&me2.borrow().player().appearance().head_morph),
+            RcRef::clone(&me2.borrow().squad),
+            RcRef::clone(&me2.borrow().player().appearance),
+            RcRef::clone(&me2.borrow().galaxy_map),
+            JournalType::Me2(RcRef::clone(&me2.borrow().journal)),
+            RcRef::clone(&me2.borrow().codex),
         ),
     };
     let (plot, me1_plot) = (plot.borrow(), me1_plot.borrow());
+    let head_morph = RcRef::clone(&appearance.borrow().head_morph);
 
     html! {
         <section class="flex-auto flex p-1">
@@ -288,6 +344,18 @@ fn mass_effect_2(save_game: Me2Type) -> Html {
                         me1_integers={IntPlotType::Vec(RcRef::clone(&me1_plot.integers))}
                     />
                 </Tab>
+                <Tab title="Squad">
+                    <Me2Squad {squad} />
+                </Tab>
+                <Tab title="Galaxy Map">
+                    <Me2GalaxyMap {galaxy_map} />
+                </Tab>
+                <Tab title="Journal">
+                    <Journal {journal} {codex} />
+                </Tab>
+                <Tab title="Appearance">
+                    <Appearance {appearance} />
+                </Tab>
                 <Tab title="Head Morph">
                     <HeadMorph {head_morph} />
                 </Tab>
@@ -308,8 +376,8 @@ fn mass_effect_2(save_game: Me2Type) -> Html {
 
 fn mass_effect_3(save_game: RcRef<Me3SaveGame>) -> Html {
     let me3 = save_game.borrow();
-    let plot = me3.plot();
-    let head_morph = RcRef::clone(&me3.player().appearance().head_morph);
+    let (player, plot) = (me3.player(), me3.plot());
+    let head_morph = RcRef::clone(&player.appearance().head_morph);
 
     html! {
         <section class="flex-auto flex p-1">
@@ -324,6 +392,43 @@ fn mass_effect_3(save_game: RcRef<Me3SaveGame>) -> Html {
                         variables={RcRef::clone(&me3.player_variables)}
                     />
                 </Tab>
+                <Tab title="Powers">
+                    <Me3Powers
+                        powers={RcRef::clone(&player.powers)}
+                        talent_points={RcCell::clone(&player.talent_points)}
+                    />
+                </Tab>
+                <Tab title="Squad">
+                    <Me3Squad squad={RcRef::clone(&me3.squad)} />
+                </Tab>
+                <Tab title="Galaxy Map">
+                    <Me3GalaxyMap galaxy_map={RcRef::clone(&me3.galaxy_map)} />
+                </Tab>
+                <Tab title="Journal">
+                    <Journal
+                        journal={JournalType::Me3(RcRef::clone(&me3.journal))}
+                        codex={RcRef::clone(&me3.codex)}
+                    />
+                </Tab>
+                <Tab title="War Assets">
+                    <Me3WarAssets player={RcRef::clone(&me3.player)} />
+                </Tab>
+                <Tab title="Weapons">
+                    <Me3Weapons
+                        weapons={RcRef::clone(&player.weapons)}
+                        weapons_mods={RcRef::clone(&player.weapons_mods)}
+                        weapons_loadout={RcRef::clone(&player.weapons_loadout)}
+                    />
+                </Tab>
+                <Tab title="Treasures">
+                    <Me3Treasures save_game={RcRef::clone(&save_game)} />
+                </Tab>
+                <Tab title="Player Variables">
+                    <Me3PlayerVariables variables={RcRef::clone(&me3.player_variables)} />
+                </Tab>
+                <Tab title="Appearance">
+                    <Appearance appearance={RcRef::clone(&player.appearance)} />
+                </Tab>
                 <Tab title="Head Morph">
                     <HeadMorph {head_morph} />
                 </Tab>
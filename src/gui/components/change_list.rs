@@ -0,0 +1,36 @@
+use std::rc::Rc;
+
+use yew::prelude::*;
+
+use crate::{gui::components::Table, services::save_summary::Change};
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub title: String,
+    pub changes: Rc<Vec<Change>>,
+}
+
+#[function_component(ChangeList)]
+pub fn change_list(props: &Props) -> Html {
+    let Props { title, changes } = props;
+
+    if changes.is_empty() {
+        return html! { <span>{ "No difference" }</span> };
+    }
+
+    let rows = changes.iter().map(|Change { label, old, new }| {
+        html! {
+            <div class="flex gap-1">
+                <span class="w-1/3">{ label }</span>
+                <span class="w-1/3">{ old }</span>
+                <span class="w-1/3">{ new }</span>
+            </div>
+        }
+    });
+
+    html! {
+        <Table title={format!("{} ({} differences)", title, changes.len())}>
+            { for rows }
+        </Table>
+    }
+}
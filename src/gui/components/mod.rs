@@ -1,4 +1,5 @@
 mod auto_update;
+mod change_list;
 mod check_box;
 mod color_picker;
 mod helper;
@@ -6,13 +7,15 @@ mod input_number;
 mod input_text;
 mod nav_bar;
 pub mod raw_ui;
+mod restore_backup;
+mod save_browser;
 mod select;
 mod tab_bar;
 mod table;
 
 pub use self::{
-    auto_update::*, check_box::*, color_picker::*, helper::*, input_number::*, input_text::*,
-    nav_bar::*, select::*, tab_bar::*, table::*,
+    auto_update::*, change_list::*, check_box::*, color_picker::*, helper::*, input_number::*,
+    input_text::*, nav_bar::*, restore_backup::*, save_browser::*, select::*, tab_bar::*, table::*,
 };
 
 pub enum CallbackType {
@@ -3,7 +3,7 @@ use web_sys::HtmlElement;
 use yew::{context::ContextHandle, prelude::*};
 
 use crate::{
-    gui::components::{Tab, TabBar},
+    gui::components::{RestoreBackup, SaveBrowser, Tab, TabBar},
     services::{
         rpc,
         save_handler::{Action, SaveHandler},
@@ -17,8 +17,10 @@ const DONATION_LINK: &str = "https://www.paypal.com/donate/?business=karlitos.vi
 pub enum Msg {
     SaveLoaded(SaveHandler),
     OpenSave,
+    ToggleBrowser,
     SaveSave,
     ReloadSave,
+    ToggleBackups,
     MenuOpen,
     MenuClose,
     MenuBlur,
@@ -37,6 +39,8 @@ pub struct NavBar {
     about_ref: NodeRef,
     about_opened: bool,
     licenses_opened: bool,
+    backups_opened: bool,
+    browser_opened: bool,
 }
 
 impl Component for NavBar {
@@ -55,12 +59,17 @@ impl Component for NavBar {
             about_ref: Default::default(),
             about_opened: false,
             licenses_opened: false,
+            backups_opened: false,
+            browser_opened: false,
         }
     }
 
     fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             Msg::SaveLoaded(save_handler) => {
+                if save_handler.save_game.is_none() {
+                    self.backups_opened = false;
+                }
                 self.save_handler = save_handler;
                 true
             }
@@ -69,6 +78,10 @@ impl Component for NavBar {
                 self.save_handler.action(Action::OpenSave);
                 false
             }
+            Msg::ToggleBrowser => {
+                self.browser_opened = !self.browser_opened;
+                true
+            }
             Msg::SaveSave => {
                 self.save_handler.action(Action::SaveSave);
                 false
@@ -77,6 +90,10 @@ impl Component for NavBar {
                 self.save_handler.action(Action::ReloadSave);
                 false
             }
+            Msg::ToggleBackups => {
+                self.backups_opened = !self.backups_opened;
+                true
+            }
             // Menus
             Msg::MenuOpen => {
                 self.about_opened = true;
@@ -116,19 +133,36 @@ impl Component for NavBar {
                 <button class="button" onclick={ctx.link().callback(|_| Msg::ReloadSave)}>
                     {"Reload"}
                 </button>
+                <span>{"-"}</span>
+                <button class="button" onclick={ctx.link().callback(|_| Msg::ToggleBackups)}>
+                    {"Backups"}
+                </button>
             </> }
         });
 
+        let browser = self.browser_opened.then(|| {
+            html! { <SaveBrowser onclose={ctx.link().callback(|_| Msg::ToggleBrowser)} /> }
+        });
+
+        let backups = self.backups_opened.then(|| {
+            html! { <RestoreBackup onclose={ctx.link().callback(|_| Msg::ToggleBackups)} /> }
+        });
+
         html! {
             <nav class="bg-menu-bar select-none flex">
                 <div class="flex items-center gap-2 px-1">
                     <button class="button" onclick={ctx.link().callback(|_| Msg::OpenSave)}>
                         {"Open"}
                     </button>
+                    <button class="button" onclick={ctx.link().callback(|_| Msg::ToggleBrowser)}>
+                        {"Browse"}
+                    </button>
                     { for loaded_buttons }
                     { self.view_about_menu(ctx) }
                 </div>
                 { ctx.props().children.clone() }
+                { for browser }
+                { for backups }
             </nav>
         }
     }
@@ -220,11 +220,11 @@ where
                         .enumerate()
                         .map(|(idx, (key, value))| {
                             let input_k = html! {
-                                <InputNumber label={key.as_str()} value={NumberType::Int((*key).into())}
+                                <InputNumber label="Id" value={NumberType::Int((*key).into())}
                                     onchange={ctx.link().callback(move |callback| Msg::EditKey(idx, callback))}
                                 />
                             };
-                            view(idx, format!("{0} ({1})", key.as_str(), key.0), input_k, value)
+                            view(idx, key.0.to_string(), input_k, value)
                         })
                         .collect::<Vec<_>>(),
                     IndexMapKeyType::String(ref index_map) => index_map
@@ -0,0 +1,252 @@
+use std::rc::Rc;
+
+use anyhow::{Context as ErrorContext, Error};
+use yew::{context::ContextHandle, prelude::*};
+
+use crate::{
+    gui::components::{CallbackType, ChangeList, InputNumber, NumberType, Table},
+    save_data::RcCell,
+    services::{
+        rpc::{self, BackupFile},
+        save_handler::{self, Action, SaveGame, SaveHandler},
+        save_summary::{self, Change, SaveSummary},
+    },
+};
+
+pub struct Backup {
+    file: BackupFile,
+    summary: Option<SaveSummary>,
+    changes: Option<Rc<Vec<Change>>>,
+}
+
+pub enum Msg {
+    SaveHandler(SaveHandler),
+    BackupsLoaded(Vec<Backup>),
+    Retention(CallbackType),
+    Diff(usize),
+    DiffLoaded(usize, Vec<Change>),
+    Restore(usize),
+    Error(Error),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub onclose: Callback<()>,
+}
+
+pub struct RestoreBackup {
+    _save_handle: ContextHandle<SaveHandler>,
+    save_handler: SaveHandler,
+    backups: Option<Vec<Backup>>,
+    retention: RcCell<i32>,
+    error: Option<Error>,
+}
+
+impl Component for RestoreBackup {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (save_handler, _save_handle) = ctx
+            .link()
+            .context::<SaveHandler>(ctx.link().callback(Msg::SaveHandler))
+            .expect("no save handler provider");
+
+        let this = RestoreBackup {
+            _save_handle,
+            save_handler,
+            backups: None,
+            retention: RcCell::new(save_handler::backup_retention() as i32),
+            error: None,
+        };
+        this.load_backups(ctx);
+        this
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::SaveHandler(save_handler) => {
+                self.save_handler = save_handler;
+                self.backups = None;
+                self.load_backups(ctx);
+                true
+            }
+            Msg::BackupsLoaded(backups) => {
+                self.backups = Some(backups);
+                true
+            }
+            Msg::Retention(CallbackType::Int(retention)) => {
+                let retention = retention.max(0);
+                self.retention.set(retention);
+                save_handler::set_backup_retention(retention as usize);
+                true
+            }
+            Msg::Diff(idx) => {
+                if let Some((save_game, backup)) = self.current_save().zip(self.backup(idx)) {
+                    let current = save_game.file_path().clone();
+                    let backup = backup.file.path.clone();
+                    ctx.link().send_future(async move {
+                        match save_summary::diff_saves(backup, current)
+                            .await
+                            .context("Failed to diff the backup")
+                        {
+                            Ok(changes) => Msg::DiffLoaded(idx, changes),
+                            Err(err) => Msg::Error(err),
+                        }
+                    });
+                }
+                false
+            }
+            Msg::DiffLoaded(idx, changes) => {
+                if let Some(backup) = self.backups.as_mut().and_then(|backups| backups.get_mut(idx))
+                {
+                    backup.changes = Some(Rc::new(changes));
+                }
+                true
+            }
+            Msg::Restore(idx) => {
+                if let Some(backup) = self.backup(idx) {
+                    self.save_handler.action(Action::RestoreBackup(backup.file.path.clone()));
+                    ctx.props().onclose.emit(());
+                }
+                false
+            }
+            Msg::Error(err) => {
+                self.error = Some(err);
+                true
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+
+        let content = match self.backups {
+            Some(ref backups) if backups.is_empty() => html! {
+                <p>{ "No backup for this save yet" }</p>
+            },
+            Some(ref backups) => {
+                let backups = backups.iter().enumerate().map(|(idx, backup)| {
+                    html! {
+                        <Table title={save_summary::local_date(backup.file.timestamp)}>
+                            { Self::view_summary(backup.summary.as_ref()) }
+                            <div class="flex gap-1">
+                                <button class="button" onclick={link.callback(move |_| Msg::Restore(idx))}>
+                                    { "Restore" }
+                                </button>
+                                <button class="button" onclick={link.callback(move |_| Msg::Diff(idx))}>
+                                    { "Diff with current file" }
+                                </button>
+                            </div>
+                            { for backup.changes.as_ref().map(|changes| html! {
+                                <ChangeList title="Backup => Current" changes={Rc::clone(changes)} />
+                            }) }
+                        </Table>
+                    }
+                });
+                html! { for backups }
+            }
+            None => html! { <p>{ "Loading backups..." }</p> },
+        };
+
+        let error = self.error.as_ref().map(|error| {
+            let chain = error.chain().map(|error| html! { <p>{ error.to_string() }</p> });
+            html! {
+                <>
+                    <hr class="border-t border-default-border" />
+                    { for chain }
+                </>
+            }
+        });
+
+        html! {
+            <div class="absolute w-screen h-[calc(100vh-28px)] grid place-content-center bg-white/30 z-40">
+                <div class="flex flex-col border border-default-border bg-default-bg w-[700px] max-h-[calc(100vh-60px)]">
+                    <div class="px-1 bg-theme-tab select-none">{"Restore backup"}</div>
+                    <div class="flex flex-col gap-1 p-1 pt-0.5 min-h-0 overflow-y-auto">
+                        <InputNumber
+                            label="Backups to keep per save"
+                            value={NumberType::Int(RcCell::clone(&self.retention))}
+                            onchange={link.callback(Msg::Retention)}
+                            helper={BACKUP_RETENTION_HELPER}
+                        />
+                        <hr class="border-t border-default-border" />
+                        { content }
+                        { for error }
+                    </div>
+                    <div class="p-1 pt-0">
+                        <hr class="mb-1 border-t border-default-border" />
+                        <button class="button w-12" onclick={ctx.props().onclose.reform(|_| ())}>
+                            {"Close"}
+                        </button>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+const BACKUP_RETENTION_HELPER: &str =
+    "Every time you save over a file, the previous version is copied into the backup folder.\n\
+    The oldest backups are deleted when there are more than this number.\n\
+    Set to `0` to disable the backups, restoring a backup still keeps a copy of the current save.";
+
+impl RestoreBackup {
+    fn current_save(&self) -> Option<&SaveGame> {
+        self.save_handler.save_game.as_deref()
+    }
+
+    fn backup(&self, idx: usize) -> Option<&Backup> {
+        self.backups.as_ref().and_then(|backups| backups.get(idx))
+    }
+
+    fn load_backups(&self, ctx: &Context<Self>) {
+        let path = match self.current_save() {
+            Some(save_game) => save_game.file_path().clone(),
+            None => return,
+        };
+
+        ctx.link().send_future(async move {
+            let handle_backups = async {
+                let mut backups = Vec::new();
+                for file in rpc::list_backups(path).await? {
+                    // A corrupted backup is still listed, it just has no summary
+                    let summary = save_summary::open_summary(file.path.clone()).await.ok();
+                    backups.push(Backup { file, summary, changes: None });
+                }
+                Ok::<_, Error>(backups)
+            };
+
+            match handle_backups.await.context("Failed to list the backups") {
+                Ok(backups) => Msg::BackupsLoaded(backups),
+                Err(err) => Msg::Error(err),
+            }
+        });
+    }
+
+    fn view_summary(summary: Option<&SaveSummary>) -> Html {
+        match summary {
+            Some(summary) => {
+                let SaveSummary {
+                    game,
+                    character_name,
+                    class,
+                    level,
+                    base_level_name,
+                    timestamp,
+                    ..
+                } = summary;
+                html! {
+                    <div class="flex flex-col">
+                        <span>{ format!("{} - {}", game, character_name) }</span>
+                        <span>{ format!("{} - Level {}", class, level) }</span>
+                        <span>{ base_level_name }</span>
+                        { for timestamp.as_ref().map(|timestamp| html! { <span>{ timestamp }</span> }) }
+                    </div>
+                }
+            }
+            None => html! { <span>{ "Unreadable backup" }</span> },
+        }
+    }
+}
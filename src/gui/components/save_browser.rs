@@ -0,0 +1,336 @@
+use std::{ffi::OsStr, rc::Rc};
+
+use anyhow::{Context as ErrorContext, Error};
+use indexmap::IndexMap;
+use yew::{context::ContextHandle, prelude::*};
+
+use crate::{
+    gui::components::{ChangeList, Table},
+    services::{
+        rpc::{self, BackupParams, SaveFile},
+        save_handler::{self, Action, SaveHandler},
+        save_summary::{self, Change, SaveSummary},
+    },
+};
+
+pub enum Parsed {
+    Loading,
+    Summary(SaveSummary),
+    Unreadable,
+}
+
+pub struct BrowsedSave {
+    file: SaveFile,
+    career: String,
+    parsed: Parsed,
+    backed_up: bool,
+}
+
+pub struct Comparison {
+    title: String,
+    changes: Rc<Vec<Change>>,
+}
+
+pub enum Msg {
+    SavesListed(Vec<SaveFile>),
+    Parsed(usize, Parsed),
+    Open(usize),
+    Backup(usize),
+    BackedUp(usize),
+    Compare(usize),
+    Compared(Comparison),
+    Error(Error),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub onclose: Callback<()>,
+}
+
+pub struct SaveBrowser {
+    _save_handle: ContextHandle<SaveHandler>,
+    save_handler: SaveHandler,
+    saves: Option<Vec<BrowsedSave>>,
+    compare_with: Option<usize>,
+    comparison: Option<Comparison>,
+    error: Option<Error>,
+}
+
+impl Component for SaveBrowser {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (save_handler, _save_handle) =
+            ctx.link().context::<SaveHandler>(Callback::noop()).expect("no save handler provider");
+
+        ctx.link().send_future(async {
+            match rpc::list_saves().await.context("Failed to list the saves") {
+                Ok(saves) => Msg::SavesListed(saves),
+                Err(err) => Msg::Error(err),
+            }
+        });
+
+        SaveBrowser {
+            _save_handle,
+            save_handler,
+            saves: None,
+            compare_with: None,
+            comparison: None,
+            error: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::SavesListed(files) => {
+                let saves = files
+                    .into_iter()
+                    .map(|file| {
+                        let career = file
+                            .path
+                            .parent()
+                            .and_then(|parent| parent.file_name())
+                            .map(OsStr::to_string_lossy)
+                            .unwrap_or_default()
+                            .into_owned();
+                        BrowsedSave { file, career, parsed: Parsed::Loading, backed_up: false }
+                    })
+                    .collect();
+                self.saves = Some(saves);
+                self.parse_save(ctx, 0);
+                true
+            }
+            Msg::Parsed(idx, parsed) => {
+                if let Some(save) = self.save_mut(idx) {
+                    save.parsed = parsed;
+                }
+                // One save at a time so the list fills up progressively
+                self.parse_save(ctx, idx + 1);
+                true
+            }
+            Msg::Open(idx) => {
+                if let Some(save) = self.save(idx) {
+                    self.save_handler.action(Action::OpenPath(save.file.path.clone()));
+                    ctx.props().onclose.emit(());
+                }
+                false
+            }
+            Msg::Backup(idx) => {
+                if let Some(save) = self.save(idx) {
+                    let params = BackupParams {
+                        path: save.file.path.clone(),
+                        // Always keep at least the one the user asked for
+                        retention: save_handler::backup_retention().max(1),
+                    };
+                    ctx.link().send_future(async move {
+                        match rpc::backup_save(params).await.context("Failed to backup the save") {
+                            Ok(()) => Msg::BackedUp(idx),
+                            Err(err) => Msg::Error(err),
+                        }
+                    });
+                }
+                false
+            }
+            Msg::BackedUp(idx) => {
+                if let Some(save) = self.save_mut(idx) {
+                    save.backed_up = true;
+                }
+                true
+            }
+            Msg::Compare(idx) => {
+                match self.compare_with.take() {
+                    Some(compare_with) if compare_with != idx => {
+                        if let Some((old, new)) = self.save(compare_with).zip(self.save(idx)) {
+                            let title = format!("{} => {}", Self::name(old), Self::name(new));
+                            let (old, new) = (old.file.path.clone(), new.file.path.clone());
+                            ctx.link().send_future(async move {
+                                match save_summary::diff_saves(old, new)
+                                    .await
+                                    .context("Failed to compare the saves")
+                                {
+                                    Ok(changes) => Msg::Compared(Comparison {
+                                        title,
+                                        changes: Rc::new(changes),
+                                    }),
+                                    Err(err) => Msg::Error(err),
+                                }
+                            });
+                        }
+                    }
+                    Some(_) => (),
+                    None => self.compare_with = Some(idx),
+                }
+                true
+            }
+            Msg::Compared(comparison) => {
+                self.comparison = Some(comparison);
+                true
+            }
+            Msg::Error(err) => {
+                self.error = Some(err);
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let content = match self.saves {
+            Some(ref saves) if saves.is_empty() => html! {
+                <p>{ "No save found in the BioWare directory" }</p>
+            },
+            Some(ref saves) => self.view_saves(ctx, saves),
+            None => html! { <p>{ "Searching for saves..." }</p> },
+        };
+
+        let comparison = self.comparison.as_ref().map(|Comparison { title, changes }| {
+            html! {
+                <>
+                    <ChangeList title={title.clone()} changes={Rc::clone(changes)} />
+                    <hr class="border-t border-default-border" />
+                </>
+            }
+        });
+
+        let error = self.error.as_ref().map(|error| {
+            let chain = error.chain().map(|error| html! { <p>{ error.to_string() }</p> });
+            html! {
+                <>
+                    <hr class="border-t border-default-border" />
+                    { for chain }
+                </>
+            }
+        });
+
+        html! {
+            <div class="absolute w-screen h-[calc(100vh-28px)] grid place-content-center bg-white/30 z-40">
+                <div class="flex flex-col border border-default-border bg-default-bg w-[900px] max-h-[calc(100vh-60px)]">
+                    <div class="px-1 bg-theme-tab select-none">{"Browse saves"}</div>
+                    <div class="flex flex-col gap-1 p-1 pt-0.5 min-h-0 overflow-y-auto">
+                        { for comparison }
+                        { content }
+                        { for error }
+                    </div>
+                    <div class="p-1 pt-0">
+                        <hr class="mb-1 border-t border-default-border" />
+                        <button class="button w-12" onclick={ctx.props().onclose.reform(|_| ())}>
+                            {"Close"}
+                        </button>
+                    </div>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl SaveBrowser {
+    fn save(&self, idx: usize) -> Option<&BrowsedSave> {
+        self.saves.as_ref().and_then(|saves| saves.get(idx))
+    }
+
+    fn save_mut(&mut self, idx: usize) -> Option<&mut BrowsedSave> {
+        self.saves.as_mut().and_then(|saves| saves.get_mut(idx))
+    }
+
+    fn parse_save(&self, ctx: &Context<Self>, idx: usize) {
+        if let Some(save) = self.save(idx) {
+            let path = save.file.path.clone();
+            ctx.link().send_future(async move {
+                let parsed = match save_summary::open_summary(path).await {
+                    Ok(summary) => Parsed::Summary(summary),
+                    Err(_) => Parsed::Unreadable,
+                };
+                Msg::Parsed(idx, parsed)
+            });
+        }
+    }
+
+    fn name(save: &BrowsedSave) -> String {
+        save.file.path.file_name().map(OsStr::to_string_lossy).unwrap_or_default().into_owned()
+    }
+
+    fn view_saves(&self, ctx: &Context<Self>, saves: &[BrowsedSave]) -> Html {
+        // Game => Career => Saves
+        let mut games: IndexMap<&str, IndexMap<&str, Vec<usize>>> = IndexMap::new();
+        for (idx, save) in saves.iter().enumerate() {
+            let game = match save.parsed {
+                Parsed::Summary(ref summary) => summary.game,
+                Parsed::Loading => "Loading...",
+                Parsed::Unreadable => "Unreadable",
+            };
+            games.entry(game).or_default().entry(&save.career).or_default().push(idx);
+        }
+        games.sort_keys();
+
+        let games = games.into_iter().map(|(game, careers)| {
+            let careers = careers.into_iter().map(|(career, saves)| {
+                let saves = saves.into_iter().map(|idx| self.view_save(ctx, idx));
+                html! {
+                    <Table title={career.to_owned()}>
+                        { for saves }
+                    </Table>
+                }
+            });
+            html! {
+                <Table title={game.to_owned()}>
+                    { for careers }
+                </Table>
+            }
+        });
+
+        html! { for games }
+    }
+
+    fn view_save(&self, ctx: &Context<Self>, idx: usize) -> Html {
+        let save = match self.save(idx) {
+            Some(save) => save,
+            None => return Html::default(),
+        };
+        let link = ctx.link();
+
+        let summary = match save.parsed {
+            Parsed::Summary(ref summary) => {
+                let SaveSummary { character_name, class, level, base_level_name, .. } = summary;
+                let details = [
+                    // ME1 has no timestamp in its saves
+                    summary
+                        .timestamp
+                        .clone()
+                        .or_else(|| Some(save_summary::local_date(save.file.modified))),
+                    summary.played_time(),
+                    summary.difficulty.map(str::to_owned),
+                ];
+                html! {
+                    <div class="flex-1 flex flex-col">
+                        <span>{ format!("{} - {} - Level {}", character_name, class, level) }</span>
+                        <span>{ base_level_name }</span>
+                        <span>{ details.into_iter().flatten().collect::<Vec<_>>().join(" - ") }</span>
+                    </div>
+                }
+            }
+            Parsed::Loading => html! { <span class="flex-1">{ Self::name(save) }</span> },
+            Parsed::Unreadable => html! { <span class="flex-1">{ Self::name(save) }</span> },
+        };
+
+        let compare = match self.compare_with {
+            Some(compare_with) if compare_with == idx => "Cancel",
+            Some(_) => "Compare with selected",
+            None => "Compare",
+        };
+
+        html! {
+            <div class="flex items-center gap-1">
+                { summary }
+                <button class="button" onclick={link.callback(move |_| Msg::Open(idx))}>
+                    { "Open" }
+                </button>
+                <button class="button" onclick={link.callback(move |_| Msg::Backup(idx))}>
+                    { if save.backed_up { "Backed up" } else { "Backup" } }
+                </button>
+                <button class="button" onclick={link.callback(move |_| Msg::Compare(idx))}>
+                    { compare }
+                </button>
+            </div>
+        }
+    }
+}
@@ -1,6 +1,43 @@
+use std::rc::Rc;
+
 use gloo::utils;
 use web_sys::HtmlElement;
-use yew::prelude::*;
+use yew::{html::IntoPropValue, prelude::*};
+
+#[derive(Clone, PartialEq)]
+pub enum SelectOptions {
+    Static(&'static [&'static str]),
+    // For options that come from a database
+    Owned(Rc<Vec<String>>),
+}
+
+impl SelectOptions {
+    fn iter(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        match self {
+            SelectOptions::Static(options) => Box::new(options.iter().copied()),
+            SelectOptions::Owned(options) => Box::new(options.iter().map(String::as_str)),
+        }
+    }
+
+    fn get(&self, idx: usize) -> Option<&str> {
+        match self {
+            SelectOptions::Static(options) => options.get(idx).copied(),
+            SelectOptions::Owned(options) => options.get(idx).map(String::as_str),
+        }
+    }
+}
+
+impl IntoPropValue<SelectOptions> for &'static [&'static str] {
+    fn into_prop_value(self) -> SelectOptions {
+        SelectOptions::Static(self)
+    }
+}
+
+impl IntoPropValue<SelectOptions> for Vec<String> {
+    fn into_prop_value(self) -> SelectOptions {
+        SelectOptions::Owned(Rc::new(self))
+    }
+}
 
 pub enum Msg {
     Open,
@@ -11,11 +48,13 @@ pub enum Msg {
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
-    pub options: &'static [&'static str],
+    pub options: SelectOptions,
     pub current_idx: usize,
     pub onselect: Callback<usize>,
     #[prop_or(true)]
     pub sized: bool,
+    #[prop_or_default]
+    pub disabled: bool,
 }
 
 pub struct Select {
@@ -119,10 +158,13 @@ impl Component for Select {
 
         let size = if ctx.props().sized { "w-[200px]" } else { "min-w-[60px]" };
 
-        let onclick = if !self.opened {
-            ctx.link().callback(|_| Msg::Open)
+        let disabled = ctx.props().disabled;
+        let onclick = if disabled {
+            None
+        } else if !self.opened {
+            Some(ctx.link().callback(|_| Msg::Open))
         } else {
-            ctx.link().callback(|_| Msg::Blur)
+            Some(ctx.link().callback(|_| Msg::Blur))
         };
 
         html! {
@@ -138,16 +180,17 @@ impl Component for Select {
                 <a class={classes![
                         "block",
                         "bg-theme-bg",
-                        "hover:bg-theme-hover",
-                        "active:bg-theme-active",
+                        (!disabled).then(|| "hover:bg-theme-hover"),
+                        (!disabled).then(|| "active:bg-theme-active"),
                         "px-1",
-                        "cursor-pointer",
+                        if disabled { "cursor-default" } else { "cursor-pointer" },
+                        disabled.then(|| "opacity-50"),
                         "min-w-full",
                         "select-chevron",
                     ]}
                     {onclick}
                 >
-                    { ctx.props().options[self.current_idx] }
+                    { ctx.props().options.get(self.current_idx).unwrap_or_default() }
                 </a>
                 <div
                     class={classes![
@@ -1,26 +1,35 @@
-use std::cell::Ref;
+use std::{cell::Ref, rc::Rc};
 
-use yew::prelude::*;
+use yew::{context::ContextHandle, prelude::*};
 
 use crate::save_data::{
     mass_effect_1::{
         data::{Data, Property as DataProperty},
         player::Player,
     },
+    mass_effect_1_le::{
+        player::{ComplexTalent, Me1LeClass, SimpleTalent},
+        player_class_db::Me1LePlayerClassDb,
+    },
     shared::plot::PlotTable,
     List, RcCell,
 };
 use crate::{
     gui::{
-        components::{Select, Table},
+        components::{Helper, Select, Table},
         mass_effect_1::property::Property,
+        mass_effect_1_le::BonusTalents,
         raw_ui::RawUi,
     },
     save_data::{mass_effect_1::data::StructType, RcRef},
+    services::database::Databases,
 };
 
 pub enum Msg {
+    DatabaseLoaded(Databases),
     Difficulty(usize),
+    PlayerClass(usize),
+    BonusTalent(Option<i32>, RcRef<Vec<RcRef<SimpleTalent>>>, RcRef<Vec<RcRef<ComplexTalent>>>),
 }
 
 #[derive(Properties, PartialEq)]
@@ -39,18 +48,37 @@ impl Props {
     }
 }
 
-pub struct Me1General;
+pub struct Me1General {
+    _db_handle: ContextHandle<Databases>,
+    player_class_db: Option<Rc<Me1LePlayerClassDb>>,
+    generation: usize,
+    error: Option<String>,
+}
 
 impl Component for Me1General {
     type Message = Msg;
     type Properties = Props;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Me1General
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
+            .expect("no database provider");
+
+        Me1General {
+            _db_handle,
+            player_class_db: databases.get_me1_le_player_classes(),
+            generation: 0,
+            error: None,
+        }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.player_class_db = dbs.get_me1_le_player_classes();
+                true
+            }
             Msg::Difficulty(new_difficulty_idx) => {
                 let player = ctx.props().player();
 
@@ -58,36 +86,26 @@ impl Component for Me1General {
                 // Find current game
                 // Then find game options
                 // Then find difficulty option
-                let value = player
-                    .objects
-                    .iter()
-                    .enumerate()
-                    .find_map(|(i, object)| {
-                        let object_name = player.get_name(object.object_name_id);
-                        (object_name == "CurrentGame").then(|| player.get_data(i as i32 + 1))
-                    })
-                    .and_then(|current_game| {
-                        let m_game_options =
-                            Self::find_property(ctx, &current_game.properties, "m_GameOptions")?
-                                .borrow();
-                        match *m_game_options {
-                            DataProperty::Struct {
-                                struct_type: StructType::Properties(ref properties),
-                                ..
-                            } => Some(properties),
-                            _ => None,
-                        }
-                        .and_then(|properties| {
-                            Self::find_property(ctx, properties, "m_nCombatDifficulty").and_then(
-                                |p| match *p.borrow() {
-                                    DataProperty::Int { ref value, .. } => {
-                                        Some(RcCell::clone(value))
-                                    }
-                                    _ => None,
-                                },
-                            )
+                let value = player.current_game().and_then(|current_game| {
+                    let m_game_options =
+                        Self::find_property(ctx, &current_game.properties, "m_GameOptions")?
+                            .borrow();
+                    match *m_game_options {
+                        DataProperty::Struct {
+                            struct_type: StructType::Properties(ref properties),
+                            ..
+                        } => Some(properties),
+                        _ => None,
+                    }
+                    .and_then(|properties| {
+                        Self::find_property(ctx, properties, "m_nCombatDifficulty").and_then(|p| {
+                            match *p.borrow() {
+                                DataProperty::Int { ref value, .. } => Some(RcCell::clone(value)),
+                                _ => None,
+                            }
                         })
-                    });
+                    })
+                });
 
                 // Then set new difficulty
                 if let Some(value) = value {
@@ -96,22 +114,58 @@ impl Component for Me1General {
 
                 true
             }
+            Msg::PlayerClass(class_idx) => {
+                // Remount the inputs showing the old values
+                self.generation += 1;
+
+                let new_class = self.player_class_db.as_ref().and_then(|db| db.get(class_idx));
+                let mut player = ctx.props().player.borrow_mut();
+                let behavior = player.behaviors().first().map(|&(_, behavior)| behavior);
+                if let Some((new_class, behavior)) = new_class.zip(behavior) {
+                    self.error =
+                        player.change_class(behavior, new_class).err().map(|err| err.to_string());
+                }
+                true
+            }
+            Msg::BonusTalent(has_talent_points, simple_talents, complex_talents) => {
+                self.generation += 1;
+
+                let mut player = ctx.props().player.borrow_mut();
+                if let Some(&(_, behavior)) = player.behaviors().first() {
+                    let mut set_talents = || -> anyhow::Result<()> {
+                        player.set_simple_talents(behavior, &simple_talents.borrow())?;
+                        player.set_complex_talents(behavior, &complex_talents.borrow())?;
+                        if let Some(talent_points) = has_talent_points {
+                            player
+                                .int_property(behavior, "m_TalentPoints")?
+                                .update(|tp| tp + talent_points);
+                        }
+                        Ok(())
+                    };
+                    self.error = set_talents().err().map(|err| err.to_string());
+                }
+                true
+            }
         }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        html! { for Self::try_view(ctx) }
+        let error = self.error.as_ref().map(|error| html! { <p>{ error }</p> });
+        html! {
+            <>
+                { for error }
+                { for self.try_view(ctx) }
+            </>
+        }
     }
 }
 
 impl Me1General {
-    fn try_view(ctx: &Context<Self>) -> Option<Html> {
+    fn try_view(&self, ctx: &Context<Self>) -> Option<Html> {
+        let generation = self.generation;
         let player = ctx.props().player();
 
-        let current_game = player.objects.iter().enumerate().find_map(|(i, object)| {
-            let object_name = player.get_name(object.object_name_id);
-            (object_name == "CurrentGame").then(|| player.get_data(i as i32 + 1))
-        })?;
+        let current_game = player.current_game()?;
 
         let m_player = {
             let object_id = Self::find_object_id(ctx, &current_game.properties, "m_Player")?;
@@ -129,7 +183,7 @@ impl Me1General {
         };
 
         Some(html! {
-            <div class="flex divide-solid divide-x divide-default-border">
+            <div key={generation} class="flex divide-solid divide-x divide-default-border">
                 <div class="flex-1 pr-1 flex flex-col gap-1">
                     { Self::role_play(ctx, m_player) }
                     { Self::gameplay(ctx, m_player, m_squad) }
@@ -137,6 +191,7 @@ impl Me1General {
                 </div>
                 <div class="flex-1 pl-1 flex flex-col gap-1">
                     { for Self::general(ctx, &current_game.properties) }
+                    { for self.bonus_talents(ctx) }
                     { Self::resources(ctx, m_inventory) }
                 </div>
             </div>
@@ -164,18 +219,39 @@ impl Me1General {
     }
 
     fn gameplay(ctx: &Context<Self>, m_player: &Data, m_squad: &Data) -> Html {
-        let class = Self::find_property(ctx, &m_player.properties, "m_ClassBase")
-            .map(|p| Self::view_property(ctx, p, "Class"));
+        let class = ctx.props().player().behaviors().first().and_then(|&(_, behavior)| {
+            let current_class = ctx.props().player().class_base(behavior)?;
+            Some(html! {
+                <div class="flex items-center gap-1 cursor-default">
+                    <Select
+                        options={Me1LeClass::variants()}
+                        current_idx={current_class}
+                        onselect={ctx.link().callback(Msg::PlayerClass)}
+                    />
+                    { "Class" }
+                    <Helper text=
+                        "If you change your class: \n\
+                        • Your talent points will be reset\n\
+                        • Your specialization will be set to `None`\n\
+                        • Your bonus talents will be removed\n\
+                        • Your gear isn't changed, you may have to change it in the inventory"
+                    />
+                </div>
+            })
+        });
         let level = Self::find_property(ctx, &m_player.properties, "m_XPLevel")
             .map(|p| Self::view_property(ctx, p, "Level"));
         let curent_xp = Self::find_property(ctx, &m_squad.properties, "m_nSquadExperience")
             .map(|p| Self::view_property(ctx, p, "Current XP"));
+        let talent_points = Self::find_property(ctx, &m_player.properties, "m_TalentPoints")
+            .map(|p| Self::view_property(ctx, p, "Talent Points"));
 
         html! {
             <Table title="Gameplay">
                 { for class }
                 { for level }
                 { for curent_xp }
+                { for talent_points }
             </Table>
         }
     }
@@ -249,6 +325,47 @@ impl Me1General {
         }
     }
 
+    fn bonus_talents(&self, ctx: &Context<Self>) -> Option<Html> {
+        let player = ctx.props().player();
+        let &(_, behavior) = player.behaviors().first()?;
+        let player_class = player.class_base(behavior)?;
+
+        let talent_list = self
+            .player_class_db
+            .as_ref()?
+            .iter()
+            .find_map(|class| {
+                (usize::from(class.player_class.clone()) == player_class)
+                    .then(|| RcRef::clone(&class.bonus_talents))
+            })
+            .unwrap_or_default();
+        let simple_talents = RcRef::new(player.simple_talents(behavior));
+        let complex_talents = RcRef::new(player.complex_talents(behavior));
+
+        let onselect = {
+            let simple_talents = RcRef::clone(&simple_talents);
+            let complex_talents = RcRef::clone(&complex_talents);
+            ctx.link().callback(move |has_talent_points| {
+                Msg::BonusTalent(
+                    has_talent_points,
+                    RcRef::clone(&simple_talents),
+                    RcRef::clone(&complex_talents),
+                )
+            })
+        };
+
+        Some(html! {
+            <BonusTalents {talent_list} {simple_talents} {complex_talents} helper=
+                "You can use as many bonus powers as you want and customize your build \
+                to your liking.\n\
+                The only restriction is that the game will only allow you to use around \
+                5-6 offensive abilities for use in game, no matter how many abilities \
+                you add. So, don't add talent points in abilities you're not going to use."
+                {onselect}
+            />
+        })
+    }
+
     fn view_property(ctx: &Context<Self>, property: &RcRef<DataProperty>, label: &str) -> Html {
         let player = &ctx.props().player;
         html! {
@@ -263,32 +380,12 @@ impl Me1General {
     fn find_property<'a>(
         ctx: &Context<Self>, properties: &'a List<RcRef<DataProperty>>, property_name: &str,
     ) -> Option<&'a RcRef<DataProperty>> {
-        let player = ctx.props().player();
-        properties.iter().find_map(|property| match *property.borrow() {
-            DataProperty::Array { name_id, .. }
-            | DataProperty::Bool { name_id, .. }
-            | DataProperty::Byte { name_id, .. }
-            | DataProperty::Float { name_id, .. }
-            | DataProperty::Int { name_id, .. }
-            | DataProperty::Name { name_id, .. }
-            | DataProperty::Object { name_id, .. }
-            | DataProperty::Str { name_id, .. }
-            | DataProperty::StringRef { name_id, .. }
-            | DataProperty::Struct { name_id, .. }
-            | DataProperty::None { name_id, .. } => {
-                (player.get_name(name_id) == property_name).then(|| property)
-            }
-        })
+        ctx.props().player().find_property(properties, property_name)
     }
 
     fn find_object_id(
         ctx: &Context<Self>, properties: &List<RcRef<DataProperty>>, property_name: &str,
     ) -> Option<i32> {
-        Self::find_property(ctx, properties, property_name).and_then(|property| {
-            match *property.borrow() {
-                DataProperty::Object { object_id, .. } => Some(object_id),
-                _ => None,
-            }
-        })
+        ctx.props().player().find_object_id(properties, property_name)
     }
 }
@@ -0,0 +1,354 @@
+use std::{cell::Ref, rc::Rc};
+
+use anyhow::Result;
+use yew::{context::ContextHandle, prelude::*};
+
+use crate::{
+    gui::{
+        components::{Select, Table},
+        mass_effect_1_le::ItemSelect,
+    },
+    save_data::{
+        mass_effect_1::{
+            data::{ArrayType, Data, Property as DataProperty},
+            player::Player,
+        },
+        mass_effect_1_le::{
+            item_db::{DbItem, Me1ItemDb},
+            player::{ItemLevel, INVENTORY_CAP},
+        },
+        List, RcCell, RcRef,
+    },
+    services::database::Databases,
+};
+
+pub enum Msg {
+    DatabaseLoaded(Databases),
+    ChangeItem(i32, DbItem),
+    ChangeItemLevel(i32, usize),
+    RemoveItem(RcRef<DataProperty>, usize),
+    DuplicateItem(RcRef<DataProperty>, i32),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub player: RcRef<Player>,
+}
+
+impl Props {
+    fn player(&self) -> Ref<'_, Player> {
+        self.player.borrow()
+    }
+}
+
+pub struct Me1Inventory {
+    _db_handle: ContextHandle<Databases>,
+    item_db: Option<Rc<Me1ItemDb>>,
+    error: Option<String>,
+}
+
+impl Component for Me1Inventory {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
+            .expect("no database provider");
+
+        Me1Inventory { _db_handle, item_db: databases.get_me1_item_db(), error: None }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.item_db = dbs.get_me1_item_db();
+                true
+            }
+            Msg::ChangeItem(object_id, new_item) => {
+                if let Some((item_id, manufacturer_id, _)) = Self::item(ctx, object_id) {
+                    item_id.set(new_item.item_id);
+                    manufacturer_id.set(new_item.manufacturer_id);
+                }
+                false
+            }
+            Msg::ChangeItemLevel(object_id, item_level) => {
+                if let Some((.., sophistication)) = Self::item(ctx, object_id) {
+                    sophistication.set(item_level as u8);
+                }
+                false
+            }
+            Msg::RemoveItem(item_list, idx) => {
+                let mut item_list = item_list.borrow_mut();
+                if let DataProperty::Array { ref mut array, .. } = *item_list {
+                    array.remove(idx);
+                }
+                self.error = item_list.update_array_size().err().map(|err| err.to_string());
+                true
+            }
+            Msg::DuplicateItem(item_list, object_id) => {
+                let duplicate = || -> Result<()> {
+                    let new_id = ctx.props().player.borrow_mut().duplicate_item(object_id)?;
+                    let mut item_list = item_list.borrow_mut();
+                    if let DataProperty::Array { ref mut array, .. } = *item_list {
+                        array.push(ArrayType::Object(new_id));
+                    }
+                    item_list.update_array_size()
+                };
+                self.error = duplicate().err().map(|err| err.to_string());
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if self.item_db.is_some() {
+            let error = self.error.as_ref().map(|error| html! { <p>{ error }</p> });
+            html! {
+                <>
+                    { for error }
+                    { for self.try_view(ctx) }
+                </>
+            }
+        } else {
+            html! {
+                <>
+                    <p>{ "Loading database..." }</p>
+                    <hr class="border-t border-default-border" />
+                </>
+            }
+        }
+    }
+}
+
+impl Me1Inventory {
+    fn try_view(&self, ctx: &Context<Self>) -> Option<Html> {
+        let player = ctx.props().player();
+
+        let current_game = player.current_game()?;
+
+        let m_player = {
+            let object_id = player.find_object_id(&current_game.properties, "m_Player")?;
+            player.get_data(object_id)
+        };
+
+        let m_inventory = {
+            let m_squad = {
+                let object_id = player.find_object_id(&m_player.properties, "m_Squad")?;
+                player.get_data(object_id)
+            };
+            let object_id = player.find_object_id(&m_squad.properties, "m_Inventory")?;
+            player.get_data(object_id)
+        };
+
+        Some(html! {
+            <div class="flex divide-solid divide-x divide-default-border">
+                <div class="flex-1 pr-1 min-w-0">
+                    { self.player(ctx, m_player) }
+                </div>
+                <div class="flex-1 flex flex-col gap-1 pl-1 min-w-0">
+                    { self.inventory(ctx, m_inventory, &current_game.properties) }
+                </div>
+            </div>
+        })
+    }
+
+    // `(m_nID, m_manufacturer, m_eSophistication)` of an item object
+    fn item(ctx: &Context<Self>, object_id: i32) -> Option<(RcCell<i32>, RcCell<i32>, RcCell<u8>)> {
+        let player = ctx.props().player();
+        let properties = &player.get_data(object_id).properties;
+
+        let find_int = |property_name| {
+            player.find_property(properties, property_name).and_then(|p| match *p.borrow() {
+                DataProperty::Int { ref value, .. } => Some(RcCell::clone(value)),
+                _ => None,
+            })
+        };
+        let sophistication =
+            player.find_property(properties, "m_eSophistication").and_then(|p| {
+                match *p.borrow() {
+                    DataProperty::Byte { ref value, .. } => Some(RcCell::clone(value)),
+                    _ => None,
+                }
+            })?;
+
+        Some((find_int("m_nID")?, find_int("m_manufacturer")?, sophistication))
+    }
+
+    // The object ids of an item array
+    fn item_list(
+        ctx: &Context<Self>, properties: &List<RcRef<DataProperty>>, property_name: &str,
+    ) -> Option<(RcRef<DataProperty>, Vec<i32>)> {
+        let item_list = ctx.props().player().find_property(properties, property_name).cloned()?;
+        let object_ids = match *item_list.borrow() {
+            DataProperty::Array { ref array, .. } => array
+                .iter()
+                .filter_map(|item| match item {
+                    ArrayType::Object(object_id) => Some(*object_id),
+                    _ => None,
+                })
+                .collect(),
+            _ => return None,
+        };
+        Some((item_list, object_ids))
+    }
+
+    fn item_view(&self, ctx: &Context<Self>, object_id: i32) -> Html {
+        html! {
+            <div class="flex items-center gap-1 min-w-0">
+                {self.item_view_no_flex(ctx, object_id)}
+            </div>
+        }
+    }
+
+    fn item_view_no_flex(&self, ctx: &Context<Self>, object_id: i32) -> Html {
+        // 0 is an empty slot
+        let item = (object_id > 0).then(|| Self::item(ctx, object_id)).flatten();
+        let (item_id, manufacturer_id, sophistication) = match item {
+            Some(item) => item,
+            None => return html! { <span class="px-1">{ "Empty" }</span> },
+        };
+
+        let current_item =
+            DbItem { item_id: item_id.get(), manufacturer_id: manufacturer_id.get() };
+        let current_level = (sophistication.get() as usize).min(ItemLevel::variants().len() - 1);
+        let onselect_item =
+            ctx.link().callback(move |new_item| Msg::ChangeItem(object_id, new_item));
+        let onselect_level = ctx.link().callback(move |idx| Msg::ChangeItemLevel(object_id, idx));
+        html! {
+            <>
+                <ItemSelect
+                    item_db={Rc::clone(self.item_db.as_ref().unwrap())}
+                    {current_item}
+                    onselect={onselect_item}
+                />
+                <Select
+                    options={ItemLevel::variants()}
+                    current_idx={current_level}
+                    onselect={onselect_level}
+                    sized=false
+                />
+            </>
+        }
+    }
+
+    fn player(&self, ctx: &Context<Self>, m_player: &Data) -> Html {
+        let equipment = Self::item_list(ctx, &m_player.properties, "m_aEquipped")
+            .map(|(_, object_ids)| object_ids)
+            .unwrap_or_default();
+        let equipment = equipment.into_iter().map(|object_id| self.item_view(ctx, object_id));
+
+        let quick_slots = Self::item_list(ctx, &m_player.properties, "m_QuickSlotArray")
+            .map(|(_, object_ids)| object_ids)
+            .unwrap_or_default();
+        let quick_slots = quick_slots.into_iter().map(|object_id| self.item_view(ctx, object_id));
+        html! {
+            <div class="flex flex-col gap-1">
+                <div>
+                    <p>{"Player"}</p>
+                    <hr class="border-t border-default-border" />
+                </div>
+                <Table title="Equipement">
+                    { for equipment }
+                </Table>
+                <Table title="Quick slots">
+                    { for quick_slots }
+                </Table>
+            </div>
+        }
+    }
+
+    fn inventory(
+        &self, ctx: &Context<Self>, m_inventory: &Data, current_game: &List<RcRef<DataProperty>>,
+    ) -> Html {
+        let link = ctx.link();
+
+        let item_edit_view = |item_list: &RcRef<DataProperty>,
+                              idx,
+                              object_id: i32,
+                              can_duplicate: bool| {
+            let item = self.item_view_no_flex(ctx, object_id);
+            let remove = {
+                let item_list = RcRef::clone(item_list);
+                link.callback(move |_| Msg::RemoveItem(RcRef::clone(&item_list), idx))
+            };
+            let duplicate = {
+                let item_list = RcRef::clone(item_list);
+                link.callback(move |_| Msg::DuplicateItem(RcRef::clone(&item_list), object_id))
+            };
+
+            html! {
+                <div class="flex items-center gap-1 min-w-0">
+                    <div class="py-px">
+                        <a class={classes![
+                                "rounded-none",
+                                "select-none",
+                                "hover:bg-theme-hover",
+                                "active:bg-theme-active",
+                                "bg-theme-bg",
+                                "px-1",
+                                "py-0",
+                                "cursor-pointer",
+                            ]}
+                            onclick={remove}
+                        >
+                            {"remove"}
+                        </a>
+                    </div>
+                    <button class="rounded-none hover:bg-theme-hover active:bg-theme-active bg-theme-bg px-1"
+                        disabled={!can_duplicate || object_id <= 0}
+                        onclick={duplicate}
+                    >
+                        {"duplicate"}
+                    </button>
+                    { item }
+                </div>
+            }
+        };
+
+        let inventory = Self::item_list(ctx, &m_inventory.properties, "m_aItem").map(
+            |(item_list, object_ids)| {
+                let len = object_ids.len();
+                let is_full = len >= INVENTORY_CAP;
+                let items = object_ids.into_iter().enumerate().map(|(idx, object_id)| {
+                    item_edit_view(&item_list, idx, object_id, !is_full)
+                });
+                let cap_warning = is_full.then(|| {
+                    html! {
+                        <div class="flex items-center gap-1 cursor-default">
+                            { format!("The inventory is full, the game discards items past {}", INVENTORY_CAP) }
+                        </div>
+                    }
+                });
+                html! {
+                    <Table title={format!("Inventory ({}/{})", len, INVENTORY_CAP)}>
+                        { for items }
+                        { for cap_warning }
+                    </Table>
+                }
+            },
+        );
+
+        let buy_pack = Self::item_list(ctx, current_game, "m_savedBuybackItems").map(
+            |(item_list, object_ids)| {
+                let items = object_ids
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, object_id)| item_edit_view(&item_list, idx, object_id, true));
+                html! {
+                    <Table title="Buy pack items">
+                        { for items }
+                    </Table>
+                }
+            },
+        );
+
+        html! {
+            <>
+                { for inventory }
+                { for buy_pack }
+            </>
+        }
+    }
+}
@@ -1,6 +1,8 @@
 mod general;
+mod inventory;
 mod plot;
 mod raw_data;
 mod raw_plot;
+mod talents;
 
-pub use self::{general::*, plot::*, raw_data::*, raw_plot::*};
+pub use self::{general::*, inventory::*, plot::*, raw_data::*, raw_plot::*, talents::*};
@@ -0,0 +1,260 @@
+use std::rc::Rc;
+
+use anyhow::{anyhow, Result};
+use yew::{context::ContextHandle, prelude::*};
+
+use crate::{
+    gui::components::{CallbackType, Helper, InputNumber, NumberType, Select, Table},
+    save_data::{
+        mass_effect_1::player::Player,
+        mass_effect_1_le::{player::ComplexTalent, squad::henchman_name, talent_db::Me1LeTalentDb},
+        RcCell, RcRef,
+    },
+    services::database::Databases,
+};
+
+pub enum Msg {
+    DatabaseLoaded(Databases),
+    Character(usize),
+    TalentPoints(CallbackType),
+    Rank(usize, usize),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub player: RcRef<Player>,
+}
+
+pub struct Me1Talents {
+    _db_handle: ContextHandle<Databases>,
+    talent_db: Option<Rc<Me1LeTalentDb>>,
+    character_idx: usize,
+    error: Option<String>,
+}
+
+impl Component for Me1Talents {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
+            .expect("no database provider");
+
+        Me1Talents {
+            _db_handle,
+            talent_db: databases.get_me1_le_talents(),
+            character_idx: 0,
+            error: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.talent_db = dbs.get_me1_le_talents();
+                true
+            }
+            Msg::Character(idx) => {
+                self.character_idx = idx;
+                true
+            }
+            Msg::TalentPoints(CallbackType::Int(new_talent_points)) => {
+                if let Some(behavior) = self.behavior(ctx) {
+                    let mut player = ctx.props().player.borrow_mut();
+                    match player.int_property(behavior, "m_TalentPoints") {
+                        Ok(talent_points) => talent_points.set(new_talent_points),
+                        Err(err) => self.error = Some(err.to_string()),
+                    }
+                }
+                true
+            }
+            Msg::Rank(idx, rank) => {
+                if let Some(behavior) = self.behavior(ctx) {
+                    let mut player = ctx.props().player.borrow_mut();
+                    if let Err(err) = Self::respec(&mut player, behavior, idx, rank as i32) {
+                        self.error = Some(err.to_string());
+                    }
+                }
+                true
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if let Some(ref talent_db) = self.talent_db {
+            let link = ctx.link();
+            let player = ctx.props().player.borrow();
+            let behavior = match self.behavior(ctx) {
+                Some(behavior) => behavior,
+                None => return Html::default(),
+            };
+
+            let complex_talents = player.complex_talents(behavior);
+            let level = player.xp_level(behavior);
+            let spent: i32 =
+                complex_talents.iter().map(|talent| talent.borrow().current_rank()).sum();
+
+            let mut sorted: Vec<_> = complex_talents.iter().enumerate().collect();
+            sorted.sort_by_key(|(_, talent)| talent.borrow().visual_order());
+            let complex = sorted.into_iter().map(|(idx, talent)| {
+                self.complex_talent(ctx, talent_db, &complex_talents, level, idx, talent)
+            });
+
+            let simple_talents = player.simple_talents(behavior);
+            let simple = simple_talents.iter().map(|talent| {
+                let talent_id = talent.borrow().talent_id();
+                html! {
+                    <div class="cursor-default" title={Self::talent_description(talent_db, talent_id)}>
+                        { Self::talent_name(talent_db, talent_id) }
+                    </div>
+                }
+            });
+
+            let talent_points = player.talent_points(behavior).unwrap_or_default();
+            let error = self.error.as_ref().map(|error| html! { <p>{ error }</p> });
+
+            html! {
+                <div class="flex divide-solid divide-x divide-default-border">
+                    <div class="flex-1 pr-1 flex flex-col gap-1 min-w-0">
+                        { for error }
+                        <div class="flex items-center gap-1 cursor-default">
+                            <Select
+                                options={self.character_names(ctx)}
+                                current_idx={self.character_idx}
+                                onselect={link.callback(Msg::Character)}
+                                sized=false
+                            />
+                        </div>
+                        <Table title="Talents">
+                            { for complex }
+                        </Table>
+                    </div>
+                    <div class="flex-1 pl-1 flex flex-col gap-1 min-w-0">
+                        <Table title="Talent Points">
+                            <InputNumber
+                                label="Available"
+                                value={NumberType::Int(RcCell::new(talent_points))}
+                                onchange={link.callback(Msg::TalentPoints)}
+                            />
+                            <div class="flex items-center gap-1 cursor-default">
+                                { format!("Spent: {}", spent) }
+                                <Helper text=
+                                    "Each rank costs 1 point.\n\
+                                    Changing a rank refunds or spends the difference from the available points, \
+                                    a rank can't be raised past the available points, the level or the prerequisites. \
+                                    Lowering a rank resets the talents whose prerequisites are no longer met."
+                                />
+                            </div>
+                        </Table>
+                        <Table title="Passive Talents">
+                            { for simple }
+                        </Table>
+                    </div>
+                </div>
+            }
+        } else {
+            html! {
+                <>
+                    <p>{ "Loading database..." }</p>
+                    <hr class="border-t border-default-border" />
+                </>
+            }
+        }
+    }
+}
+
+impl Me1Talents {
+    // The rank is gated on the `m_XPLevel` of the behavior
+    fn respec(player: &mut Player, behavior: i32, idx: usize, rank: i32) -> Result<()> {
+        let level = player
+            .xp_level(behavior)
+            .ok_or_else(|| anyhow!("No `m_XPLevel` for this character"))?;
+        let talent_points = player.int_property(behavior, "m_TalentPoints")?;
+        let complex_talents = player.complex_talents(behavior);
+        let refund = ComplexTalent::respec(&complex_talents, idx, rank, level, talent_points.get());
+        talent_points.update(|tp| tp + refund);
+        Ok(())
+    }
+
+    fn character_names(&self, ctx: &Context<Self>) -> Vec<String> {
+        ctx.props()
+            .player
+            .borrow()
+            .behaviors()
+            .into_iter()
+            .map(|(tag, _)| henchman_name(&tag).map(str::to_owned).unwrap_or(tag))
+            .collect()
+    }
+
+    // The player is the first behavior
+    fn behavior(&self, ctx: &Context<Self>) -> Option<i32> {
+        let behaviors = ctx.props().player.borrow().behaviors();
+        behaviors.get(self.character_idx).map(|&(_, behavior)| behavior)
+    }
+
+    fn talent_name(talent_db: &Me1LeTalentDb, talent_id: i32) -> String {
+        match talent_db.get(&talent_id) {
+            Some(talent) => talent.name.clone(),
+            None => format!("Talent {}", talent_id),
+        }
+    }
+
+    fn talent_description(talent_db: &Me1LeTalentDb, talent_id: i32) -> Option<String> {
+        talent_db.get(&talent_id).map(|talent| talent.description.clone())
+    }
+
+    fn complex_talent(
+        &self, ctx: &Context<Self>, talent_db: &Me1LeTalentDb, talents: &[RcRef<ComplexTalent>],
+        level: Option<i32>, idx: usize, talent: &RcRef<ComplexTalent>,
+    ) -> Html {
+        let talent = talent.borrow();
+        let talent_id = talent.talent_id();
+
+        let options: Vec<_> = (0..=talent.max_rank().max(talent.current_rank()))
+            .map(|rank| rank.to_string())
+            .collect();
+        let current_idx = talent.current_rank().max(0) as usize;
+
+        let prereq_ranks = talent.prereq_talent_ranks();
+        let prerequisites = (!talent.prerequisites_met(talents)).then(|| {
+            let prerequisites: Vec<_> = talent
+                .prereq_talent_ids()
+                .iter()
+                .zip(prereq_ranks.iter())
+                .map(|(id, rank)| {
+                    format!("{} rank {}", Self::talent_name(talent_db, id.get()), rank.get())
+                })
+                .collect();
+            html! {
+                <span class="truncate">{ format!("Requires {}", prerequisites.join(", ")) }</span>
+            }
+        });
+        let level_cap = level
+            .map(|level| (level, talent.max_rank_at(level)))
+            .filter(|&(_, max_rank)| max_rank < talent.max_rank())
+            .map(|(level, max_rank)| {
+                html! {
+                    <span class="truncate">{ format!("Up to rank {} at level {}", max_rank, level) }</span>
+                }
+            });
+
+        html! {
+            <div class="flex items-center gap-1 cursor-default"
+                title={Self::talent_description(talent_db, talent_id)}
+            >
+                <Select
+                    {options}
+                    {current_idx}
+                    onselect={ctx.link().callback(move |rank| Msg::Rank(idx, rank))}
+                    sized=false
+                />
+                { Self::talent_name(talent_db, talent_id) }
+                { for prerequisites }
+                { for level_cap }
+            </div>
+        }
+    }
+}
@@ -14,9 +14,9 @@ use crate::{
     },
     save_data::{
         mass_effect_1_le::{
-            player::{Item, Me1LeClass, Player},
+            player::{ComplexTalent, Inventory, Item, Me1LeClass, Player},
             player_class_db::{Me1LePlayerClass, Me1LePlayerClassDb},
-            squad::Henchman,
+            squad::{henchman_name, Henchman},
             Me1LeSaveData,
         },
         shared::{
@@ -113,7 +113,7 @@ pub enum Msg {
     Notoriety(usize),
     Difficulty(usize),
     TalentPoints(CallbackType),
-    ResetTalents(Option<&'static str>),
+    ResetTalents(Option<String>),
     PlayerClass(usize),
     PlayerSpecialization(usize),
     BonusTalent(Option<i32>),
@@ -137,6 +137,7 @@ impl Props {
 pub struct Me1LeGeneral {
     _db_handle: ContextHandle<Databases>,
     player_class_db: Option<Rc<Me1LePlayerClassDb>>,
+    class_error: Option<String>,
 }
 
 impl Component for Me1LeGeneral {
@@ -149,7 +150,11 @@ impl Component for Me1LeGeneral {
             .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
             .expect("no database provider");
 
-        Me1LeGeneral { _db_handle, player_class_db: databases.get_me1_le_player_classes() }
+        Me1LeGeneral {
+            _db_handle,
+            player_class_db: databases.get_me1_le_player_classes(),
+            class_error: None,
+        }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
@@ -216,10 +221,8 @@ impl Component for Me1LeGeneral {
                     (RcCell::clone(&player.talent_points), RcRef::clone(&player.complex_talents))
                 };
 
-                for talent in complex_talents.borrow_mut().iter_mut() {
-                    talent_points.update(|tp| tp + talent.borrow().current_rank());
-                    talent.borrow_mut().set_current_rank(0);
-                }
+                let refund = ComplexTalent::reset_ranks(&complex_talents.borrow());
+                talent_points.update(|tp| tp + refund);
                 true
             }
             Msg::TalentPoints(CallbackType::Int(talent_points)) => {
@@ -241,6 +244,17 @@ impl Component for Me1LeGeneral {
                             bonus_talents: _,
                         } = new_class_data;
 
+                        // The replaced gear and its mods go to the inventory, refuse if they don't fit
+                        let unequipped = Self::unequipped_count(&player.inventory());
+                        if !player.inventory().has_room(unequipped) {
+                            self.class_error = Some(format!(
+                                "The inventory has no room for the {} unequipped items",
+                                unequipped
+                            ));
+                            return true;
+                        }
+                        self.class_error = None;
+
                         *player.player_class_mut() = player_class.clone();
                         player.set_specialization_bonus_id(-1);
                         player.set_localized_class_name(*localized_class_name);
@@ -312,12 +326,15 @@ impl Component for Me1LeGeneral {
                                 for detached_mod in base_item.attached_mods_mut().drain(..) {
                                     let detached_mod = detached_mod.borrow();
 
-                                    let mut item = Item::default();
-                                    item.item_id = detached_mod.item_id.clone();
-                                    item.item_level = detached_mod.item_level.clone();
-                                    item.manufacturer_id = detached_mod.manufacturer_id.clone();
-                                    item.plot_conditional_id =
-                                        detached_mod.plot_conditional_id.clone();
+                                    let mut item = Item {
+                                        item_id: detached_mod.item_id.clone(),
+                                        item_level: detached_mod.item_level.clone(),
+                                        manufacturer_id: detached_mod.manufacturer_id.clone(),
+                                        plot_conditional_id: detached_mod
+                                            .plot_conditional_id
+                                            .clone(),
+                                        ..Default::default()
+                                    };
                                     item.set_new_item(true);
 
                                     unequipped_items.push(item.into());
@@ -354,11 +371,8 @@ impl Component for Me1LeGeneral {
                                 unequip_item_and_mods(old_bio_amp);
                             }
 
-                            // Move the unequipped gear to the inventory
-                            {
-                                let mut inventory = inventory.inventory.borrow_mut();
-                                inventory.append(&mut unequipped_items);
-                            }
+                            // Move the unequipped gear to the inventory, the room was checked above
+                            inventory.add_items(&mut unequipped_items);
                         }
                     }
                 }
@@ -417,7 +431,7 @@ impl Component for Me1LeGeneral {
                 <div class="flex divide-solid divide-x divide-default-border">
                     <div class="flex-1 pr-1 flex flex-col gap-1">
                         { Self::role_play(ctx, save_game.player()) }
-                        { Self::gameplay(ctx, save_game.player()) }
+                        { self.gameplay(ctx, save_game.player()) }
                         { Self::bonus_talents(ctx, player_class_db, save_game.player()) }
                     </div>
                     <div class="flex-1 pl-1 flex flex-col gap-1">
@@ -481,7 +495,7 @@ impl Me1LeGeneral {
         }
     }
 
-    fn gameplay(ctx: &Context<Self>, player: Ref<'_, Player>) -> Html {
+    fn gameplay(&self, ctx: &Context<Self>, player: Ref<'_, Player>) -> Html {
         let Player { level, current_xp, player_class, specialization_bonus_id, .. } = &*player;
 
         let player_class = player_class.borrow();
@@ -521,6 +535,7 @@ impl Me1LeGeneral {
                         • You will have to put them back manually after that"
                     />
                 </div>
+                { for self.class_error.as_ref().map(|error| html! { <p>{ error }</p> }) }
                 <div class="flex items-center gap-1 cursor-default">
                     <Select
                         options={spec_variants}
@@ -613,25 +628,21 @@ impl Me1LeGeneral {
     }
 
     fn squad(ctx: &Context<Self>, squad: Ref<'_, Vec<RcRef<Henchman>>>) -> Html {
-        let characters = [
-            ("hench_humanfemale", "Ashley"),
-            ("hench_turian", "Garrus"),
-            ("hench_humanmale", "Kaidan"),
-            ("hench_asari", "Liara"),
-            ("hench_quarian", "Tali"),
-            ("hench_krogan", "Wrex"),
-        ];
-
-        let characters = squad.iter().filter_map(|character| {
-            characters.iter().find_map(|&(tag, name)| {
-                (*character.borrow().tag() == tag).then(|| {
-                    html! {
-                        <button class="button" onclick={ctx.link().callback(move |_| Msg::ResetTalents(Some(tag)))}>
-                            { format!("Reset {}'s talents", name) }
-                        </button>
-                    }
-                })
+        let mut characters: Vec<_> = squad
+            .iter()
+            .filter_map(|character| {
+                let tag = character.borrow().tag().clone();
+                henchman_name(&tag).map(|name| (name, tag))
             })
+            .collect();
+        characters.sort_unstable();
+
+        let characters = characters.into_iter().map(|(name, tag)| {
+            html! {
+                <button class="button" onclick={ctx.link().callback(move |_| Msg::ResetTalents(Some(tag.clone())))}>
+                    { format!("Reset {}'s talents", name) }
+                </button>
+            }
         });
 
         html! {
@@ -640,4 +651,21 @@ impl Me1LeGeneral {
             </Table>
         }
     }
+
+    // Armor, Omni-Tool and Biotic Amp with their mods, unequipped by a class change
+    fn unequipped_count(inventory: &Inventory) -> usize {
+        let equipment = inventory.equipment();
+        [1, 3, 4]
+            .iter()
+            .filter_map(|&idx| equipment.get(idx))
+            .map(|item| {
+                let item = item.borrow();
+                if item.item_id() == 0 {
+                    0
+                } else {
+                    1 + item.attached_mods().len()
+                }
+            })
+            .sum()
+    }
 }
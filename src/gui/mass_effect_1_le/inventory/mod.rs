@@ -7,7 +7,7 @@ use crate::{
     save_data::{
         mass_effect_1_le::{
             item_db::{DbItem, Me1ItemDb},
-            player::{Inventory, Item, ItemLevel, Player},
+            player::{Inventory, Item, ItemLevel, Player, INVENTORY_CAP},
             squad::Henchman,
         },
         RcRef,
@@ -24,6 +24,7 @@ pub enum Msg {
     ChangeItemLevel(RcRef<Item>, usize),
     RemoveItem(RcRef<Vec<RcRef<Item>>>, usize),
     AddItem(RcRef<Vec<RcRef<Item>>>),
+    AddInventoryItem,
 }
 
 #[derive(Properties, PartialEq)]
@@ -60,7 +61,7 @@ impl Component for Me1LeInventory {
         Me1LeInventory { _db_handle, item_db: databases.get_me1_item_db() }
     }
 
-    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             Msg::DatabaseLoaded(dbs) => {
                 self.item_db = dbs.get_me1_item_db();
@@ -85,6 +86,11 @@ impl Component for Me1LeInventory {
                 item_list.borrow_mut().push(Default::default());
                 true
             }
+            Msg::AddInventoryItem => {
+                let player = ctx.props().player();
+                player.inventory().add_items(&mut vec![Default::default()]);
+                true
+            }
         }
     }
 
@@ -179,15 +185,7 @@ impl Me1LeInventory {
         let squad = inventory.iter().map(|henchman| {
             let henchman = henchman.borrow();
 
-            let name = match henchman.tag().as_str() {
-                "hench_asari" => "Liara",
-                "hench_humanfemale" => "Ashley",
-                "hench_humanmale" => "Kaidan",
-                "hench_krogan" => "Wrex",
-                "hench_quarian" => "Tali",
-                "hench_turian" => "Garrus",
-                _ => "Jenkins",
-            };
+            let name = henchman.name();
 
             let equipment = henchman.equipment();
             let equipment = equipment.iter().map(|item| self.item_view(ctx, item));
@@ -215,10 +213,7 @@ impl Me1LeInventory {
 
     fn inventory(&self, ctx: &Context<Self>, player_inventory: Ref<'_, Inventory>) -> Html {
         let link = ctx.link();
-        let inventory_add = {
-            let inventory = RcRef::clone(&player_inventory.inventory);
-            link.callback(move |_| Msg::AddItem(RcRef::clone(&inventory)))
-        };
+        let inventory_add = link.callback(|_| Msg::AddInventoryItem);
         let buy_pack_add = {
             let buy_pack = RcRef::clone(&player_inventory.buy_pack);
             link.callback(move |_| Msg::AddItem(RcRef::clone(&buy_pack)))
@@ -256,14 +251,25 @@ impl Me1LeInventory {
         });
 
         let inventory2 = player_inventory.inventory();
+        let len = inventory2.len();
         let inventory = inventory2.iter().enumerate().map(|(idx, item)| {
             item_remove_view(RcRef::clone(&player_inventory.inventory), idx, item)
         });
+        let is_full = player_inventory.is_full();
+        let cap_warning = is_full.then(|| {
+            html! {
+                <div class="flex items-center gap-1 cursor-default">
+                    { format!("The inventory is full, the game discards items past {}", INVENTORY_CAP) }
+                </div>
+            }
+        });
         html! {
             <>
-                <Table title="Inventory">
+                <Table title={format!("Inventory ({}/{})", len, INVENTORY_CAP)}>
                     { for inventory }
+                    { for cap_warning }
                     <button class="rounded-none hover:bg-theme-hover active:bg-theme-active bg-theme-bg px-1"
+                        disabled={is_full}
                         onclick={inventory_add}
                     >
                         {"add"}
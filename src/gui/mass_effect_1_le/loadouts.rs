@@ -0,0 +1,296 @@
+use std::rc::Rc;
+
+use gloo::storage::{LocalStorage, Storage};
+use ron::ser::PrettyConfig;
+use web_sys::{HtmlInputElement, HtmlTextAreaElement};
+use yew::{context::ContextHandle, prelude::*};
+
+use crate::{
+    gui::components::{Helper, Select, Table},
+    save_data::{
+        mass_effect_1_le::{
+            item_db::{DbItem, Me1ItemDb},
+            loadout::{Me1LeLoadout, Wearer},
+            player::{Item, Player},
+            squad::Henchman,
+        },
+        RcRef,
+    },
+    services::database::Databases,
+};
+
+const LOADOUTS_KEY: &str = "me1_le_loadouts";
+
+// The templates are kept in RON so they can be edited by hand
+fn load_loadouts() -> Vec<Me1LeLoadout> {
+    LocalStorage::get::<String>(LOADOUTS_KEY)
+        .ok()
+        .and_then(|loadouts| ron::from_str(&loadouts).ok())
+        .unwrap_or_default()
+}
+
+fn to_ron(loadouts: &[Me1LeLoadout]) -> String {
+    ron::ser::to_string_pretty(loadouts, PrettyConfig::new().new_line(String::from('\n')))
+        .unwrap_or_default()
+}
+
+pub enum Msg {
+    DatabaseLoaded(Databases),
+    Character(usize),
+    Name(InputEvent),
+    SaveCurrent,
+    Apply(usize),
+    Remove(usize),
+    Ron(InputEvent),
+    LoadRon,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub player: RcRef<Player>,
+    pub squad: RcRef<Vec<RcRef<Henchman>>>,
+}
+
+pub struct Me1LeLoadouts {
+    _db_handle: ContextHandle<Databases>,
+    item_db: Option<Rc<Me1ItemDb>>,
+    loadouts: Vec<Me1LeLoadout>,
+    character_idx: usize,
+    name: String,
+    ron: String,
+    ron_error: Option<String>,
+    apply_error: Option<String>,
+}
+
+impl Component for Me1LeLoadouts {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
+            .expect("no database provider");
+
+        let loadouts = load_loadouts();
+        let ron = to_ron(&loadouts);
+        Me1LeLoadouts {
+            _db_handle,
+            item_db: databases.get_me1_item_db(),
+            loadouts,
+            character_idx: 0,
+            name: String::new(),
+            ron,
+            ron_error: None,
+            apply_error: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.item_db = dbs.get_me1_item_db();
+                true
+            }
+            Msg::Character(idx) => {
+                self.character_idx = idx;
+                true
+            }
+            Msg::Name(event) => {
+                if let Some(input) = event.target_dyn_into::<HtmlInputElement>() {
+                    self.name = input.value();
+                }
+                true
+            }
+            Msg::SaveCurrent => {
+                let (_, equipment, quick_slots) = self.character(ctx);
+                let name = match self.name.trim() {
+                    "" => self.character_names(ctx)[self.character_idx].clone(),
+                    name => name.to_owned(),
+                };
+                let loadout = Me1LeLoadout::from_gear(name, &equipment, &quick_slots);
+                self.loadouts.push(loadout);
+                self.name.clear();
+                self.save();
+                true
+            }
+            Msg::Apply(idx) => {
+                if let Some(loadout) = self.loadouts.get(idx) {
+                    let (_, equipment, quick_slots) = self.character(ctx);
+
+                    // The squad shares the player's inventory
+                    let player = ctx.props().player.borrow();
+                    let applied = loadout.apply(&equipment, &quick_slots, &player.inventory());
+                    self.apply_error = (!applied).then(|| {
+                        format!(
+                            "The inventory has no room for the gear replaced by `{}`",
+                            loadout.name
+                        )
+                    });
+                }
+                true
+            }
+            Msg::Remove(idx) => {
+                self.loadouts.remove(idx);
+                self.save();
+                true
+            }
+            Msg::Ron(event) => {
+                if let Some(textarea) = event.target_dyn_into::<HtmlTextAreaElement>() {
+                    self.ron = textarea.value();
+                }
+                false
+            }
+            Msg::LoadRon => {
+                match ron::from_str(&self.ron) {
+                    Ok(loadouts) => {
+                        self.loadouts = loadouts;
+                        self.save();
+                    }
+                    Err(err) => self.ron_error = Some(err.to_string()),
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if let Some(ref item_db) = self.item_db {
+            let link = ctx.link();
+            let (wearer, ..) = self.character(ctx);
+
+            let loadouts = self.loadouts.iter().enumerate().map(|(idx, loadout)| {
+                let errors = loadout.validate(item_db, wearer);
+                let is_valid = errors.is_empty();
+                let gear = loadout
+                    .equipment
+                    .iter()
+                    .chain(&loadout.quick_slots)
+                    .filter(|item| item.item_id() != 0)
+                    .map(|item| Self::item_name(item_db, item))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let errors = errors.iter().map(|error| {
+                    html! { <p class="pl-2">{ error }</p> }
+                });
+
+                html! {
+                    <div class="flex flex-col">
+                        <div class="flex items-center gap-1 cursor-default">
+                            <button class="button" disabled={!is_valid}
+                                onclick={link.callback(move |_| Msg::Apply(idx))}
+                            >
+                                { "Apply" }
+                            </button>
+                            <button class="button" onclick={link.callback(move |_| Msg::Remove(idx))}>
+                                { "Remove" }
+                            </button>
+                            <span class="truncate" title={gear}>{ &loadout.name }</span>
+                        </div>
+                        { for errors }
+                    </div>
+                }
+            });
+
+            let ron_error = self.ron_error.as_ref().map(|error| html! { <p>{ error }</p> });
+            let apply_error = self.apply_error.as_ref().map(|error| html! { <p>{ error }</p> });
+
+            html! {
+                <div class="flex divide-solid divide-x divide-default-border">
+                    <div class="flex-1 flex flex-col gap-1 pr-1 min-w-0">
+                        <div class="flex items-center gap-1 cursor-default">
+                            <Select
+                                options={self.character_names(ctx)}
+                                current_idx={self.character_idx}
+                                onselect={link.callback(Msg::Character)}
+                                sized=false
+                            />
+                            <Helper text=
+                                "Templates are checked against the race and the armor weight of the selected character.\n\
+                                Applying a template moves the replaced gear to the inventory, it is refused if the inventory has no room for it."
+                            />
+                        </div>
+                        <div class="flex items-center gap-1">
+                            <input type="text" class="flex-auto input" placeholder="<template name>"
+                                value={self.name.clone()}
+                                oninput={link.callback(Msg::Name)}
+                            />
+                            <button class="button" onclick={link.callback(|_| Msg::SaveCurrent)}>
+                                { "Save current gear as template" }
+                            </button>
+                        </div>
+                        { for apply_error }
+                        <Table title="Templates">
+                            { for loadouts }
+                        </Table>
+                    </div>
+                    <div class="flex-1 flex flex-col gap-1 pl-1 min-w-0">
+                        <div class="flex items-center gap-1 cursor-default">
+                            <button class="button" onclick={link.callback(|_| Msg::LoadRon)}>
+                                { "Load templates" }
+                            </button>
+                            <Helper text=
+                                "Edit the templates in RON, then load them.\n\
+                                The item ids are the ones of the item database."
+                            />
+                        </div>
+                        { for ron_error }
+                        <textarea class="flex-auto input min-h-[24rem]"
+                            value={self.ron.clone()}
+                            oninput={link.callback(Msg::Ron)}
+                        />
+                    </div>
+                </div>
+            }
+        } else {
+            html! {
+                <>
+                    <p>{ "Loading database..." }</p>
+                    <hr class="border-t border-default-border" />
+                </>
+            }
+        }
+    }
+}
+
+impl Me1LeLoadouts {
+    fn save(&mut self) {
+        self.ron = to_ron(&self.loadouts);
+        self.ron_error = None;
+        let _ = LocalStorage::set(LOADOUTS_KEY, &self.ron);
+    }
+
+    fn character_names(&self, ctx: &Context<Self>) -> Vec<String> {
+        let squad = ctx.props().squad.borrow();
+        let squad = squad.iter().map(|henchman| henchman.borrow().name());
+        Some(String::from("Player")).into_iter().chain(squad).collect()
+    }
+
+    fn character(
+        &self, ctx: &Context<Self>,
+    ) -> (Option<Wearer>, Vec<RcRef<Item>>, Vec<RcRef<Item>>) {
+        let squad = ctx.props().squad.borrow();
+        match self.character_idx.checked_sub(1).and_then(|idx| squad.get(idx)) {
+            Some(henchman) => {
+                let henchman = henchman.borrow();
+                let wearer = Wearer::henchman(&henchman.tag());
+                let equipment = henchman.equipment().clone();
+                let quick_slots = henchman.quick_slots().clone();
+                (wearer, equipment, quick_slots)
+            }
+            None => {
+                let player = ctx.props().player.borrow();
+                let wearer = Wearer::player(&player.player_class());
+                let inventory = player.inventory();
+                let equipment = inventory.equipment().clone();
+                let quick_slots = inventory.quick_slots().clone();
+                (Some(wearer), equipment, quick_slots)
+            }
+        }
+    }
+
+    fn item_name(item_db: &Me1ItemDb, item: &Item) -> String {
+        let db_item = DbItem { item_id: item.item_id(), manufacturer_id: item.manufacturer_id() };
+        item_db.get(&db_item).cloned().unwrap_or_else(|| format!("Unknown item {}", item.item_id()))
+    }
+}
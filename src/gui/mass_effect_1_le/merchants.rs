@@ -0,0 +1,232 @@
+use std::rc::Rc;
+
+use yew::{context::ContextHandle, prelude::*};
+
+use super::ItemSelect;
+use crate::{
+    gui::components::{Helper, Select, Table},
+    save_data::{
+        mass_effect_1_le::{
+            item_db::{DbItem, Me1ItemDb},
+            legacy::{
+                inventory::{Inventory, Shop},
+                LevelShop, Object,
+            },
+            player::ItemLevel,
+            NoExportData,
+        },
+        RcRef,
+    },
+    services::database::Databases,
+};
+
+pub enum Msg {
+    DatabaseLoaded(Databases),
+    ChangeItem(RcRef<Shop>, RcRef<Inventory>, usize, DbItem),
+    ChangeItemLevel(RcRef<ItemLevel>, usize),
+    AddItem(RcRef<Shop>, RcRef<Inventory>, DbItem),
+    RemoveItem(RcRef<Inventory>, usize),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub no_export: RcRef<NoExportData>,
+}
+
+pub struct Me1LeMerchants {
+    _db_handle: ContextHandle<Databases>,
+    item_db: Option<Rc<Me1ItemDb>>,
+}
+
+impl Component for Me1LeMerchants {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
+            .expect("no database provider");
+
+        Me1LeMerchants { _db_handle, item_db: databases.get_me1_item_db() }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.item_db = dbs.get_me1_item_db();
+                true
+            }
+            Msg::ChangeItem(shop, stock, idx, new_item) => {
+                if let Some(name) = self.item_name(&new_item) {
+                    shop.borrow().set_item(&stock, idx, new_item, &name);
+                }
+                true
+            }
+            Msg::ChangeItemLevel(item_level, idx) => {
+                *item_level.borrow_mut() = ItemLevel::from(idx);
+                false
+            }
+            Msg::AddItem(shop, stock, new_item) => {
+                if let Some(name) = self.item_name(&new_item) {
+                    shop.borrow().add_item(&stock, new_item, &name);
+                }
+                true
+            }
+            Msg::RemoveItem(stock, idx) => {
+                stock.borrow_mut().items_mut().remove(idx);
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if let Some(ref item_db) = self.item_db {
+            let no_export = ctx.props().no_export.borrow();
+            let legacy_maps = no_export.legacy_maps();
+            let maps = legacy_maps.iter().filter_map(|(map_name, map)| {
+                let shops: Vec<_> = map
+                    .borrow()
+                    .shops()
+                    .into_iter()
+                    .filter(|level_shop| !level_shop.shop.borrow().stocks().is_empty())
+                    .collect();
+
+                (!shops.is_empty()).then(|| {
+                    let shops = shops.iter().map(|level_shop| self.shop(ctx, item_db, level_shop));
+                    html! {
+                        <div class="flex flex-col gap-1">
+                            <div>
+                                <p>{ map_name }</p>
+                                <hr class="border-t border-default-border" />
+                            </div>
+                            { for shops }
+                        </div>
+                    }
+                })
+            });
+
+            html! {
+                <div class="flex flex-col gap-1">
+                    <div class="flex items-center gap-1 cursor-default">
+                        {"Merchants"}
+                        <Helper text=
+                            "A merchant has no stock until it has been opened in game, those aren't listed.\n\
+                            Grenades and grenade mods can't be added."
+                        />
+                    </div>
+                    { for maps }
+                </div>
+            }
+        } else {
+            html! {
+                <>
+                    <p>{ "Loading database..." }</p>
+                    <hr class="border-t border-default-border" />
+                </>
+            }
+        }
+    }
+}
+
+impl Me1LeMerchants {
+    fn item_name(&self, item: &DbItem) -> Option<String> {
+        self.item_db.as_ref().and_then(|item_db| item_db.get(item).cloned())
+    }
+
+    fn shop(&self, ctx: &Context<Self>, item_db: &Rc<Me1ItemDb>, level_shop: &LevelShop) -> Html {
+        let LevelShop { level_name, owner_name, shop } = level_shop;
+        // `BIOA_STA60_08A_DSG.TheWorld:PersistentLevel` => `BIOA_STA60_08A_DSG`
+        let level_name = level_name.split('.').next().unwrap_or_default();
+        let title = format!("{} - {}", level_name, owner_name);
+
+        let stocks = shop.borrow().stocks();
+        let stocks = stocks.iter().enumerate().map(|(stock_idx, stock)| {
+            let items = stock.borrow().items().clone();
+            let items = items.iter().enumerate().map(|(idx, item)| {
+                let item = item.borrow();
+                let key = item.owner_name().clone();
+                let (current_item, item_level) = match item._object {
+                    Object::Item(ref item) => {
+                        let item = item.borrow();
+                        let current_item = DbItem {
+                            item_id: item.item_id(),
+                            manufacturer_id: item.manufacturer_id(),
+                        };
+                        (current_item, RcRef::clone(&item.item_level))
+                    }
+                    Object::ItemMod(ref item_mod) => {
+                        let item_mod = item_mod.borrow();
+                        let current_item = DbItem {
+                            item_id: item_mod.item_id(),
+                            manufacturer_id: item_mod.manufacturer_id(),
+                        };
+                        (current_item, RcRef::clone(&item_mod.item_level))
+                    }
+                    _ => return Html::default(),
+                };
+                let current_level = *item_level.borrow() as usize;
+
+                let onselect_item = {
+                    let (shop, stock) = (RcRef::clone(shop), RcRef::clone(stock));
+                    ctx.link().callback(move |new_item| {
+                        Msg::ChangeItem(RcRef::clone(&shop), RcRef::clone(&stock), idx, new_item)
+                    })
+                };
+                let onselect_level = ctx
+                    .link()
+                    .callback(move |level| Msg::ChangeItemLevel(RcRef::clone(&item_level), level));
+                let onclick = {
+                    let stock = RcRef::clone(stock);
+                    ctx.link().callback(move |_| Msg::RemoveItem(RcRef::clone(&stock), idx))
+                };
+
+                html! {
+                    <div {key} class="flex items-center gap-1 min-w-0">
+                        <ItemSelect
+                            item_db={Rc::clone(item_db)}
+                            {current_item}
+                            onselect={onselect_item}
+                        />
+                        <Select
+                            options={ItemLevel::variants()}
+                            current_idx={current_level}
+                            onselect={onselect_level}
+                            sized=false
+                        />
+                        <button class="button" {onclick}>
+                            {"Remove"}
+                        </button>
+                    </div>
+                }
+            });
+
+            let onselect_new = {
+                let (shop, stock) = (RcRef::clone(shop), RcRef::clone(stock));
+                ctx.link().callback(move |new_item| {
+                    Msg::AddItem(RcRef::clone(&shop), RcRef::clone(&stock), new_item)
+                })
+            };
+
+            html! {
+                <Table title={format!("Stock {}", stock_idx + 1)}>
+                    { for items }
+                    <div class="flex items-center gap-1 min-w-0 cursor-default">
+                        {"Add"}
+                        <ItemSelect
+                            item_db={Rc::clone(item_db)}
+                            current_item={DbItem { item_id: 0, manufacturer_id: 0 }}
+                            onselect={onselect_new}
+                        />
+                    </div>
+                </Table>
+            }
+        });
+
+        html! {
+            <Table {title} opened=false>
+                { for stocks }
+            </Table>
+        }
+    }
+}
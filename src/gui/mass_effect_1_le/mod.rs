@@ -17,17 +17,29 @@ use crate::{
 mod bonus_talents;
 mod general;
 mod inventory;
+mod loadouts;
+mod merchants;
+mod talents;
+mod world_objects;
 
-pub use self::{general::*, inventory::*};
+pub use self::{
+    bonus_talents::*, general::*, inventory::*, loadouts::*, merchants::*, talents::*,
+    world_objects::*,
+};
 
 impl RawUi for RcRef<Me1LeSaveData> {
     fn view(&self, _: &str) -> yew::Html {
-        let no_export = self
-            .borrow()
-            .no_export()
+        let save_data = self.borrow();
+        let no_export = save_data.no_export();
+        let no_export = no_export
             .as_ref()
             .map(|no_export_data| no_export_data.children())
-            .unwrap_or_else(|| vec![html! { "Export Save" }])
+            .unwrap_or_else(|| match no_export.unknown_error() {
+                Some(error) => {
+                    vec![html! { format!("Unreadable world data, kept unchanged: {}", error) }]
+                }
+                None => vec![html! { "Export Save" }],
+            })
             .into_iter();
 
         let children = self.children();
@@ -0,0 +1,276 @@
+use std::rc::Rc;
+
+use yew::{context::ContextHandle, prelude::*};
+
+use crate::{
+    gui::components::{Helper, InputNumber, NumberType, Select, Table},
+    save_data::{
+        mass_effect_1_le::{
+            player::{ComplexTalent, Player, SimpleTalent},
+            squad::Henchman,
+            talent_db::Me1LeTalentDb,
+        },
+        RcCell, RcRef,
+    },
+    services::database::Databases,
+};
+
+struct Character {
+    simple_talents: RcRef<Vec<RcRef<SimpleTalent>>>,
+    complex_talents: RcRef<Vec<RcRef<ComplexTalent>>>,
+    talent_points: RcCell<i32>,
+    level: i32,
+}
+
+pub enum Msg {
+    DatabaseLoaded(Databases),
+    Character(usize),
+    TalentPoints,
+    Rank(usize, usize),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub player: RcRef<Player>,
+    pub squad: RcRef<Vec<RcRef<Henchman>>>,
+}
+
+pub struct Me1LeTalents {
+    _db_handle: ContextHandle<Databases>,
+    talent_db: Option<Rc<Me1LeTalentDb>>,
+    character_idx: usize,
+    generation: usize,
+}
+
+impl Component for Me1LeTalents {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
+            .expect("no database provider");
+
+        Me1LeTalents {
+            _db_handle,
+            talent_db: databases.get_me1_le_talents(),
+            character_idx: 0,
+            generation: 0,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.talent_db = dbs.get_me1_le_talents();
+                true
+            }
+            Msg::Character(idx) => {
+                self.character_idx = idx;
+                true
+            }
+            Msg::TalentPoints => {
+                // Points past the ranks left to buy can never be spent
+                let Character { complex_talents, talent_points, .. } = self.character(ctx);
+                let ranks_left: i32 = complex_talents
+                    .borrow()
+                    .iter()
+                    .map(|talent| {
+                        let talent = talent.borrow();
+                        (talent.max_rank() - talent.current_rank()).max(0)
+                    })
+                    .sum();
+                let clamped = talent_points.get().clamp(0, ranks_left);
+                if clamped != talent_points.get() {
+                    talent_points.set(clamped);
+                    // Remount the input showing the typed value
+                    self.generation += 1;
+                }
+                true
+            }
+            Msg::Rank(idx, rank) => {
+                let Character { complex_talents, talent_points, level, .. } = self.character(ctx);
+                let refund = ComplexTalent::respec(
+                    &complex_talents.borrow(),
+                    idx,
+                    rank as i32,
+                    level,
+                    talent_points.get(),
+                );
+                talent_points.update(|tp| tp + refund);
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if let Some(ref talent_db) = self.talent_db {
+            let link = ctx.link();
+            let Character { simple_talents, complex_talents, talent_points, level } =
+                self.character(ctx);
+
+            let complex_talents = complex_talents.borrow();
+            let spent: i32 =
+                complex_talents.iter().map(|talent| talent.borrow().current_rank()).sum();
+
+            let mut sorted: Vec<_> = complex_talents.iter().enumerate().collect();
+            sorted.sort_by_key(|(_, talent)| talent.borrow().visual_order());
+            let complex = sorted.into_iter().map(|(idx, talent)| {
+                self.complex_talent(ctx, talent_db, &complex_talents, level, idx, talent)
+            });
+
+            let simple_talents = simple_talents.borrow();
+            let simple = simple_talents.iter().map(|talent| {
+                let talent_id = talent.borrow().talent_id();
+                html! {
+                    <div class="cursor-default" title={Self::talent_description(talent_db, talent_id)}>
+                        { Self::talent_name(talent_db, talent_id) }
+                    </div>
+                }
+            });
+
+            html! {
+                <div class="flex divide-solid divide-x divide-default-border">
+                    <div class="flex-1 pr-1 flex flex-col gap-1 min-w-0">
+                        <div class="flex items-center gap-1 cursor-default">
+                            <Select
+                                options={self.character_names(ctx)}
+                                current_idx={self.character_idx}
+                                onselect={link.callback(Msg::Character)}
+                                sized=false
+                            />
+                        </div>
+                        <Table title="Talents">
+                            { for complex }
+                        </Table>
+                    </div>
+                    <div class="flex-1 pl-1 flex flex-col gap-1 min-w-0">
+                        <Table title="Talent Points">
+                            <div key={self.generation}>
+                                <InputNumber
+                                    label="Available"
+                                    value={NumberType::Int(talent_points)}
+                                    onchange={link.callback(|_| Msg::TalentPoints)}
+                                    helper="Between 0 and the ranks left to buy"
+                                />
+                            </div>
+                            <div class="flex items-center gap-1 cursor-default">
+                                { format!("Spent: {}", spent) }
+                                <Helper text=
+                                    "Each rank costs 1 point.\n\
+                                    Changing a rank refunds or spends the difference from the available points, \
+                                    a rank can't be raised past the available points, the level or the prerequisites. \
+                                    Lowering a rank resets the talents whose prerequisites are no longer met."
+                                />
+                            </div>
+                        </Table>
+                        <Table title="Passive Talents">
+                            { for simple }
+                        </Table>
+                    </div>
+                </div>
+            }
+        } else {
+            html! {
+                <>
+                    <p>{ "Loading database..." }</p>
+                    <hr class="border-t border-default-border" />
+                </>
+            }
+        }
+    }
+}
+
+impl Me1LeTalents {
+    fn character_names(&self, ctx: &Context<Self>) -> Vec<String> {
+        let squad = ctx.props().squad.borrow();
+        let squad = squad.iter().map(|henchman| henchman.borrow().name().to_owned());
+        Some(String::from("Player")).into_iter().chain(squad).collect()
+    }
+
+    fn character(&self, ctx: &Context<Self>) -> Character {
+        let squad = ctx.props().squad.borrow();
+        match self.character_idx.checked_sub(1).and_then(|idx| squad.get(idx)) {
+            Some(henchman) => {
+                let henchman = henchman.borrow();
+                Character {
+                    simple_talents: RcRef::clone(&henchman.simple_talents),
+                    complex_talents: RcRef::clone(&henchman.complex_talents),
+                    talent_points: RcCell::clone(&henchman.talent_points),
+                    level: henchman.level(),
+                }
+            }
+            None => {
+                let player = ctx.props().player.borrow();
+                Character {
+                    simple_talents: RcRef::clone(&player.simple_talents),
+                    complex_talents: RcRef::clone(&player.complex_talents),
+                    talent_points: RcCell::clone(&player.talent_points),
+                    level: player.level(),
+                }
+            }
+        }
+    }
+
+    fn talent_name(talent_db: &Me1LeTalentDb, talent_id: i32) -> String {
+        match talent_db.get(&talent_id) {
+            Some(talent) => talent.name.clone(),
+            None => format!("Talent {}", talent_id),
+        }
+    }
+
+    fn talent_description(talent_db: &Me1LeTalentDb, talent_id: i32) -> Option<String> {
+        talent_db.get(&talent_id).map(|talent| talent.description.clone())
+    }
+
+    fn complex_talent(
+        &self, ctx: &Context<Self>, talent_db: &Me1LeTalentDb, talents: &[RcRef<ComplexTalent>],
+        level: i32, idx: usize, talent: &RcRef<ComplexTalent>,
+    ) -> Html {
+        let talent = talent.borrow();
+        let talent_id = talent.talent_id();
+
+        let options: Vec<_> = (0..=talent.max_rank().max(talent.current_rank()))
+            .map(|rank| rank.to_string())
+            .collect();
+        let current_idx = talent.current_rank().max(0) as usize;
+
+        let prereq_ranks = talent.prereq_talent_ranks();
+        let prerequisites = (!talent.prerequisites_met(talents)).then(|| {
+            let prerequisites: Vec<_> = talent
+                .prereq_talent_ids()
+                .iter()
+                .zip(prereq_ranks.iter())
+                .map(|(id, rank)| {
+                    format!("{} rank {}", Self::talent_name(talent_db, id.get()), rank.get())
+                })
+                .collect();
+            html! {
+                <span class="truncate">{ format!("Requires {}", prerequisites.join(", ")) }</span>
+            }
+        });
+        let max_rank = talent.max_rank_at(level);
+        let level_cap = (max_rank < talent.max_rank()).then(|| {
+            html! {
+                <span class="truncate">{ format!("Up to rank {} at level {}", max_rank, level) }</span>
+            }
+        });
+
+        html! {
+            <div class="flex items-center gap-1 cursor-default"
+                title={Self::talent_description(talent_db, talent_id)}
+            >
+                <Select
+                    {options}
+                    {current_idx}
+                    onselect={ctx.link().callback(move |rank| Msg::Rank(idx, rank))}
+                    sized=false
+                />
+                { Self::talent_name(talent_db, talent_id) }
+                { for prerequisites }
+                { for level_cap }
+            </div>
+        }
+    }
+}
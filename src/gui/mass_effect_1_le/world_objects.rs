@@ -0,0 +1,211 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{
+    gui::components::{CheckBox, Helper, InputNumber, NumberType, Select},
+    save_data::{
+        mass_effect_1_le::{
+            legacy::{LevelObject, Object},
+            NoExportData,
+        },
+        RcCell, RcRef,
+    },
+};
+
+const CLASSES: &[&str] = &[
+    "All classes",
+    "BioPawnBehaviorSaveObject",
+    "BioPawnSaveObject",
+    "BioBaseSquadSaveObject",
+    "BioShopSaveObject",
+    "BioInventorySaveObject",
+    "BioItemXModdableSaveObject",
+    "BioItemXModSaveObject",
+    "BioArtPlaceableBehaviorSaveObject",
+    "BioArtPlaceableSaveObject",
+    "BioVehicleBehaviorSaveObject",
+    "BioVehicleSaveObject",
+    "BioWorldInfoSaveObject",
+];
+
+// Rendering every object of a save at once is too slow
+const MAX_ROWS: usize = 200;
+
+struct Row {
+    map_name: String,
+    level_object: LevelObject,
+}
+
+pub enum Msg {
+    Filter(InputEvent),
+    Class(usize),
+    ReviveAll,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub no_export: RcRef<NoExportData>,
+}
+
+pub struct Me1LeWorldObjects {
+    filter: String,
+    class_idx: usize,
+    generation: usize,
+}
+
+impl Component for Me1LeWorldObjects {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Me1LeWorldObjects { filter: String::new(), class_idx: 0, generation: 0 }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Filter(event) => {
+                if let Some(input) = event.target_dyn_into::<HtmlInputElement>() {
+                    self.filter = input.value();
+                }
+            }
+            Msg::Class(idx) => self.class_idx = idx,
+            Msg::ReviveAll => {
+                for row in self.rows(ctx) {
+                    if let Object::PawnBehavior(ref behavior) =
+                        row.level_object.object.borrow()._object
+                    {
+                        behavior.borrow().revive();
+                    }
+                }
+                // Props are compared by pointer, remount the rows after an action
+                self.generation += 1;
+            }
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let rows = self.rows(ctx);
+        let dead_pawns = rows
+            .iter()
+            .filter(|row| match row.level_object.object.borrow()._object {
+                Object::PawnBehavior(ref behavior) => behavior.borrow().is_dead(),
+                _ => false,
+            })
+            .count();
+        let len = rows.len();
+        let rows = rows.iter().take(MAX_ROWS).map(Self::row);
+
+        html! {
+            <div class="flex-auto flex flex-col gap-1">
+                <div class="flex items-center gap-3">
+                    <label class="flex-auto flex items-center gap-1">
+                        <input type="text" class="flex-auto input" placeholder="<empty>"
+                            value={self.filter.clone()}
+                            oninput={ctx.link().callback(Msg::Filter)}
+                        />
+                        { "Filter" }
+                    </label>
+                    <Select
+                        options={CLASSES}
+                        current_idx={self.class_idx}
+                        onselect={ctx.link().callback(Msg::Class)}
+                        sized=false
+                    />
+                    <button class="button" onclick={ctx.link().callback(|_| Msg::ReviveAll)}>
+                        { format!("Revive matching pawns ({})", dead_pawns) }
+                    </button>
+                    <Helper text=
+                        "The filter matches the map, level, class and owner names.\n\
+                        Nested objects like the inventory of a pawn are listed under the level of their owner."
+                    />
+                </div>
+                <div class="cursor-default">
+                    { format!("{} objects", len) }
+                    { for (len > MAX_ROWS).then(|| format!(", showing the first {}", MAX_ROWS)) }
+                </div>
+                <hr class="border-t border-default-border" />
+                <div key={self.generation} class="flex flex-col gap-1">
+                    { for rows }
+                </div>
+            </div>
+        }
+    }
+}
+
+impl Me1LeWorldObjects {
+    fn rows(&self, ctx: &Context<Self>) -> Vec<Row> {
+        let filter = self.filter.to_lowercase();
+        let class_name = CLASSES.get(self.class_idx).filter(|_| self.class_idx != 0);
+
+        let no_export = ctx.props().no_export.borrow();
+        let legacy_maps = no_export.legacy_maps();
+        legacy_maps
+            .iter()
+            .flat_map(|(map_name, map)| {
+                map.borrow()
+                    .objects()
+                    .into_iter()
+                    .map(move |level_object| Row { map_name: map_name.clone(), level_object })
+            })
+            .filter(|row| {
+                let object = row.level_object.object.borrow();
+                let class_matches = class_name.map_or(true, |&name| object._class_name == name);
+                class_matches
+                    && [
+                        row.map_name.as_str(),
+                        row.level_object.level_name.as_str(),
+                        object._class_name.as_str(),
+                        object.owner_name().as_str(),
+                    ]
+                    .iter()
+                    .any(|field| field.to_lowercase().contains(&filter))
+            })
+            .collect()
+    }
+
+    fn row(row: &Row) -> Html {
+        let Row { map_name, level_object: LevelObject { level_name, object } } = row;
+        let object = object.borrow();
+        // `Level BIOA_STA60_08A_DSG.TheWorld:PersistentLevel` => `BIOA_STA60_08A_DSG`
+        let level_name =
+            level_name.trim_start_matches("Level ").split('.').next().unwrap_or_default();
+        let owner_name = object.owner_name().clone();
+        let key = format!("{}.{}.{}", map_name, level_name, owner_name);
+
+        let fields = match object._object {
+            Object::PawnBehavior(ref behavior) => {
+                let behavior = behavior.borrow();
+                html! { <>
+                    <CheckBox label="Dead" value={RcCell::clone(&behavior.is_dead)} />
+                    <InputNumber label="Health" value={NumberType::Float(RcCell::clone(&behavior.health))} />
+                    <InputNumber label="Level" value={NumberType::Int(RcCell::clone(&behavior.level))} />
+                </> }
+            }
+            Object::ArtPlaceableBehavior(ref behavior) => {
+                let behavior = behavior.borrow();
+                html! { <>
+                    <CheckBox label="Dead" value={RcCell::clone(&behavior.is_dead)} />
+                    <CheckBox label="Destroyed" value={RcCell::clone(&behavior.is_destroyed)} />
+                    <InputNumber label="Health" value={NumberType::Float(RcCell::clone(&behavior.current_health))} />
+                </> }
+            }
+            Object::Vehicle(ref vehicle) => html! {
+                <InputNumber label="Health" value={NumberType::Float(RcCell::clone(&vehicle.borrow().health))} />
+            },
+            _ => Html::default(),
+        };
+
+        html! {
+            <div {key} class="flex items-center gap-2 cursor-default">
+                <span class="w-1/12 truncate">{ map_name }</span>
+                <span class="w-1/6 truncate">{ level_name }</span>
+                <span class="w-1/6 truncate">{ &object._class_name }</span>
+                <span class="w-1/4 truncate" title={owner_name.clone()}>{ owner_name }</span>
+                <div class="flex-1 flex items-center gap-2 min-w-0">
+                    { fields }
+                </div>
+            </div>
+        }
+    }
+}
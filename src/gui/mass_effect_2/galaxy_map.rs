@@ -0,0 +1,155 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use indexmap::IndexSet;
+use yew::{context::ContextHandle, prelude::*};
+
+use crate::{
+    gui::components::{CheckBox, Helper, Table},
+    save_data::{
+        mass_effect_2::galaxy_map::{GalaxyMap, Planet},
+        shared::galaxy_map_db::GalaxyMapDb,
+        RcCell, RcRef,
+    },
+    services::database::Databases,
+};
+
+pub enum Msg {
+    DatabaseLoaded(Databases),
+    VisitAll,
+    RemoveAllProbes,
+    RemoveProbes(RcRef<Planet>),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub galaxy_map: RcRef<GalaxyMap>,
+}
+
+pub struct Me2GalaxyMap {
+    _db_handle: ContextHandle<Databases>,
+    galaxy_map_db: Option<Rc<GalaxyMapDb>>,
+    generation: usize,
+}
+
+impl Component for Me2GalaxyMap {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
+            .expect("no database provider");
+
+        Me2GalaxyMap { _db_handle, galaxy_map_db: databases.get_me2_galaxy_map(), generation: 0 }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let galaxy_map = ctx.props().galaxy_map.borrow();
+        match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.galaxy_map_db = dbs.get_me2_galaxy_map();
+                return true;
+            }
+            Msg::RemoveProbes(planet) => {
+                planet.borrow_mut().probes_mut().clear();
+                return true;
+            }
+            Msg::VisitAll => {
+                galaxy_map.visit_all();
+            }
+            Msg::RemoveAllProbes => {
+                galaxy_map.remove_probes();
+            }
+        }
+        // Props are compared by pointer, remount the list after a bulk action
+        self.generation += 1;
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let galaxy_map = ctx.props().galaxy_map.borrow();
+
+        // The game doesn't save the systems, the planets are grouped with their id
+        let mut systems: BTreeMap<i32, Vec<&RcRef<Planet>>> = BTreeMap::new();
+        let planets = galaxy_map.planets();
+        for planet in planets.iter() {
+            let system_id = planet.borrow().system_id();
+            systems.entry(system_id).or_default().push(planet);
+        }
+        let system_list = systems.into_iter().map(|(id, planets)| self.system(ctx, id, &planets));
+
+        html! {
+            <div class="flex divide-solid divide-x divide-default-border">
+                <div class="flex-1 pr-1 flex flex-col gap-1 min-w-0">
+                    <div key={self.generation} class="flex flex-col gap-1">
+                        { for system_list }
+                    </div>
+                </div>
+                <div class="flex-1 pl-1 flex flex-col gap-1 min-w-0">
+                    <Table title="Bulk actions">
+                        <div class="flex items-center gap-1 cursor-default">
+                            <button class="button" onclick={ctx.link().callback(|_| Msg::VisitAll)}>
+                                {"Mark all visited"}
+                            </button>
+                            <Helper text="Only the planets already in the save are changed." />
+                        </div>
+                        <div class="flex items-center gap-1 cursor-default">
+                            <button class="button" onclick={ctx.link().callback(|_| Msg::RemoveAllProbes)}>
+                                {"Remove all probes"}
+                            </button>
+                        </div>
+                    </Table>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl Me2GalaxyMap {
+    fn system(&self, ctx: &Context<Self>, id: i32, planets: &[&RcRef<Planet>]) -> Html {
+        // Ids missing from the reference saves are flagged, not removed
+        let unknown = |id: i32, ids: fn(&GalaxyMapDb) -> &IndexSet<i32>| {
+            self.galaxy_map_db.as_ref().filter(|db| !ids(db).contains(&id)).map(|_| {
+                html! {
+                    <div class="flex items-center gap-1">
+                        {"Unknown id"}
+                        <Helper text="Not seen in the reference saves.\n\
+                            It may still be valid, or have been left by another editor." />
+                    </div>
+                }
+            })
+        };
+
+        let planets = planets.iter().map(|planet| {
+            let onclick = {
+                let planet = RcRef::clone(planet);
+                ctx.link().callback(move |_| Msg::RemoveProbes(RcRef::clone(&planet)))
+            };
+            let planet = planet.borrow();
+            let probes = planet.probes().len();
+            let remove_probes = (probes > 0).then(|| {
+                html! {
+                    <button class="button" {onclick}>
+                        { format!("Remove probes ({})", probes) }
+                    </button>
+                }
+            });
+            html! {
+                <div class="flex items-center gap-2 cursor-default">
+                    <span class="flex-1 min-w-0 truncate">{ format!("Planet {}", planet.id()) }</span>
+                    { for unknown(planet.id(), |db| &db.planets) }
+                    <CheckBox label="Visited" value={RcCell::clone(&planet.visited)} />
+                    { for remove_probes }
+                </div>
+            }
+        });
+
+        html! {
+            <Table title={format!("System {}", id)} opened=false>
+                { for unknown(id, |db| &db.systems) }
+                { for planets }
+            </Table>
+        }
+    }
+}
@@ -1,22 +1,30 @@
-use std::cell::Ref;
+use std::{cell::Ref, rc::Rc};
 
-use yew::prelude::*;
+use yew::{context::ContextHandle, prelude::*};
 
 use crate::{
     gui::{
         components::{Helper, InputText, Select, Table},
         raw_ui::RawUi,
-        shared::{BonusPowerType, BonusPowers},
+        shared::{BonusPowerType, BonusPowers, SetLevel},
     },
     save_data::{
-        mass_effect_2::{player::Player, Difficulty},
+        mass_effect_2::{
+            player::{Player, Power},
+            squad::Henchman,
+            Difficulty,
+        },
         shared::{
             player::{Notoriety, Origin},
+            player_class_db::{self, ClassPlayer, PlayerClassDb},
             plot::PlotTable,
+            squad_db::SquadDb,
+            xp::XpTable,
             EndGameState,
         },
-        RcRef,
+        RcCell, RcRef,
     },
+    services::database::Databases,
 };
 
 use super::Me2Type;
@@ -45,10 +53,13 @@ impl Me2Class {
 }
 
 pub enum Msg {
+    DatabaseLoaded(Databases),
     Gender(usize),
     Origin(usize),
     Notoriety(usize),
     PlayerClass(usize),
+    ResetTalents(Option<String>),
+    SetLevel((i32, bool)),
 }
 
 #[derive(Properties, PartialEq)]
@@ -56,31 +67,63 @@ pub struct Props {
     pub save_game: Me2Type,
 }
 
-pub struct Me2General;
+pub struct Me2General {
+    _db_handle: ContextHandle<Databases>,
+    player_class_db: Option<Rc<PlayerClassDb>>,
+    squad_db: Option<Rc<SquadDb>>,
+    points_warnings: Vec<String>,
+    generation: usize,
+}
 
 impl Component for Me2General {
     type Message = Msg;
     type Properties = Props;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Me2General
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
+            .expect("no database provider");
+
+        Me2General {
+            _db_handle,
+            player_class_db: databases.clone().get_me2_player_classes(),
+            squad_db: databases.get_me2_squad(),
+            points_warnings: Vec::new(),
+            generation: 0,
+        }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
-        let (player, me1_plot, plot) = match ctx.props().save_game {
+        let (player, me1_plot, plot, squad) = match ctx.props().save_game {
             Me2Type::Vanilla(ref me2) => {
                 let me2 = me2.borrow();
-                (RcRef::clone(&me2.player), RcRef::clone(&me2.me1_plot), RcRef::clone(&me2.plot))
+                (
+                    RcRef::clone(&me2.player),
+                    RcRef::clone(&me2.me1_plot),
+                    RcRef::clone(&me2.plot),
+                    RcRef::clone(&me2.squad),
+                )
             }
             Me2Type::Legendary(ref me2) => {
                 let me2 = me2.borrow();
-                (RcRef::clone(&me2.player), RcRef::clone(&me2.me1_plot), RcRef::clone(&me2.plot))
+                (
+                    RcRef::clone(&me2.player),
+                    RcRef::clone(&me2.me1_plot),
+                    RcRef::clone(&me2.plot),
+                    RcRef::clone(&me2.squad),
+                )
             }
         };
         let (mut player, mut me1_plot, mut plot) =
             (player.borrow_mut(), me1_plot.borrow_mut(), plot.borrow_mut());
 
         match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.player_class_db = dbs.clone().get_me2_player_classes();
+                self.squad_db = dbs.get_me2_squad();
+                true
+            }
             Msg::Gender(gender) => {
                 let gender = gender != 0;
 
@@ -198,14 +241,143 @@ impl Component for Me2General {
                 false
             }
             Msg::PlayerClass(class_idx) => {
-                *player.class_name_mut() = Me2Class::names()[class_idx].to_owned();
-                false
+                let player_class_db = match self.player_class_db {
+                    Some(ref player_class_db) => player_class_db,
+                    None => return false,
+                };
+
+                let old_powers: Vec<String> = player
+                    .powers()
+                    .iter()
+                    .map(|power| power.borrow().power_class_name().clone())
+                    .collect();
+                let class_player = ClassPlayer {
+                    class_name: &player.class_name,
+                    localized_class_name: &player.localized_class_name,
+                    talent_points: &player.talent_points,
+                    powers: &player.powers,
+                    mapped_powers: [
+                        &player.mapped_power_1,
+                        &player.mapped_power_2,
+                        &player.mapped_power_3,
+                    ],
+                };
+                if !player_class_db.set_player_class(&class_player, Me2Class::names()[class_idx]) {
+                    return false;
+                }
+
+                // Hotkeys reference the player's powers by index
+                // Follow the kept powers and remove the ones of removed powers
+                let new_ids: Vec<Option<i32>> = old_powers
+                    .iter()
+                    .map(|old_power| {
+                        player.powers().iter().position(|power| {
+                            power.borrow().power_class_name().eq_ignore_ascii_case(old_power)
+                        })
+                    })
+                    .map(|new_id| new_id.map(|new_id| new_id as i32))
+                    .collect();
+                player.hotkeys_mut().retain(|hotkey| {
+                    let mut hotkey = hotkey.borrow_mut();
+                    if *hotkey.pawn_name() != "Player" {
+                        return true;
+                    }
+                    let new_id = usize::try_from(hotkey.power_id())
+                        .ok()
+                        .and_then(|old_id| new_ids.get(old_id).copied().flatten());
+                    match new_id {
+                        Some(new_id) => {
+                            hotkey.set_power_id(new_id);
+                            true
+                        }
+                        None => false,
+                    }
+                });
+                true
+            }
+            Msg::ResetTalents(tag) => {
+                let (player_class_db, squad_db) = match (&self.player_class_db, &self.squad_db) {
+                    (Some(player_class_db), Some(squad_db)) => (player_class_db, squad_db),
+                    _ => return false,
+                };
+
+                let (talent_points, powers, base_ranks) = if let Some(tag) = tag {
+                    // Squad mate
+                    let squad = squad.borrow();
+                    let character = squad.iter().find(|character| *character.borrow().tag() == tag);
+                    let character = match character {
+                        Some(character) => character.borrow(),
+                        None => return false,
+                    };
+                    (
+                        RcCell::clone(&character.talent_points),
+                        RcRef::clone(&character.powers),
+                        squad_db.base_ranks(&tag),
+                    )
+                } else {
+                    // Player
+                    (
+                        RcCell::clone(&player.talent_points),
+                        RcRef::clone(&player.powers),
+                        player_class_db.base_ranks(&player.class_name()),
+                    )
+                };
+
+                player_class_db::reset_talents(&talent_points, &powers, &base_ranks);
+                true
+            }
+            Msg::SetLevel((level, with_squad)) => {
+                let (player_class_db, squad_db) = match (&self.player_class_db, &self.squad_db) {
+                    (Some(player_class_db), Some(squad_db)) => (player_class_db, squad_db),
+                    _ => return false,
+                };
+
+                // Remount the inputs showing the old values
+                self.generation += 1;
+
+                let xp_table = &XpTable::ME2;
+                self.points_warnings.clear();
+
+                // Player
+                let earned = xp_table.player_points(player.level(), level);
+                let powers = RcRef::clone(&player.powers);
+                let base_ranks = player_class_db.base_ranks(&player.class_name());
+                let (talent_points, warning) =
+                    XpTable::earn_points(player.talent_points(), earned, || {
+                        Power::reset_ranks(&mut powers.borrow_mut(), &base_ranks)
+                    });
+                self.points_warnings.extend(warning.map(|warning| format!("Player: {}", warning)));
+                player.set_talent_points(talent_points);
+                player.set_level(level);
+                player.set_current_xp(xp_table.xp(level) as f32);
+
+                // Squad
+                if with_squad {
+                    for henchman in squad.borrow().iter() {
+                        let mut henchman = henchman.borrow_mut();
+                        let earned = xp_table.squad_points(henchman.character_level(), level);
+                        let powers = RcRef::clone(&henchman.powers);
+                        let base_ranks = squad_db.base_ranks(&henchman.tag());
+                        let (talent_points, warning) =
+                            XpTable::earn_points(henchman.talent_points(), earned, || {
+                                Power::reset_ranks(&mut powers.borrow_mut(), &base_ranks)
+                            });
+                        self.points_warnings.extend(warning.map(|warning| {
+                            let tag = henchman.tag().clone();
+                            let name = squad_db.get(&tag).map(|henchman| henchman.name.clone());
+                            format!("{}: {}", name.unwrap_or(tag), warning)
+                        }));
+                        henchman.set_talent_points(talent_points);
+                        henchman.set_character_level(level);
+                    }
+                }
+                true
             }
         }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let (difficulty, end_game_state, player, plot) = match ctx.props().save_game {
+        let (difficulty, end_game_state, player, plot, squad) = match ctx.props().save_game {
             Me2Type::Vanilla(ref me2) => {
                 let me2 = me2.borrow();
                 (
@@ -213,6 +385,7 @@ impl Component for Me2General {
                     RcRef::clone(&me2.end_game_state),
                     RcRef::clone(&me2.player),
                     RcRef::clone(&me2.plot),
+                    RcRef::clone(&me2.squad),
                 )
             }
             Me2Type::Legendary(ref me2) => {
@@ -222,21 +395,23 @@ impl Component for Me2General {
                     RcRef::clone(&me2.end_game_state),
                     RcRef::clone(&me2.player),
                     RcRef::clone(&me2.plot),
+                    RcRef::clone(&me2.squad),
                 )
             }
         };
 
         html! {
-            <div class="flex divide-solid divide-x divide-default-border">
+            <div key={self.generation} class="flex divide-solid divide-x divide-default-border">
                 <div class="flex-1 pr-1 flex flex-col gap-1">
                     { Self::role_play(ctx, player.borrow()) }
                     { Self::morality(plot.borrow()) }
-                    { Self::gameplay(ctx, player.borrow()) }
+                    { self.gameplay(ctx, player.borrow()) }
                     { Self::resources(player.borrow()) }
                 </div>
                 <div class="flex-1 pl-1 flex flex-col gap-1">
                     { Self::general(difficulty, end_game_state) }
                     { Self::bonus_powers(player.borrow()) }
+                    { self.squad(ctx, squad.borrow()) }
                 </div>
             </div>
         }
@@ -297,7 +472,9 @@ impl Me2General {
         }
     }
 
-    fn gameplay(ctx: &Context<Self>, player: Ref<'_, Player>) -> Html {
+    fn gameplay(&self, ctx: &Context<Self>, player: Ref<'_, Player>) -> Html {
+        let class_db_loaded = self.player_class_db.is_some();
+        let dbs_loaded = class_db_loaded && self.squad_db.is_some();
         let Player { level, current_xp, talent_points, credits, medigel, .. } = &*player;
 
         let class_idx = Me2Class::names()
@@ -305,6 +482,7 @@ impl Me2General {
             .enumerate()
             .find_map(|(i, name)| player.class_name().eq_ignore_ascii_case(name).then(|| i))
             .unwrap_or_default();
+        let xp_table = &XpTable::ME2;
 
         html! {
             <Table title="Gameplay">
@@ -313,12 +491,25 @@ impl Me2General {
                         options={Me2Class::variants()}
                         current_idx={class_idx}
                         onselect={ctx.link().callback(Msg::PlayerClass)}
+                        disabled={!class_db_loaded}
                     />
                     {"Class"}
                 </div>
                 { level.view("Level") }
                 { current_xp.view("Current XP") }
                 { talent_points.view("Talent Points") }
+                <SetLevel
+                    level={level.get()}
+                    max_level={xp_table.max_level()}
+                    warning={xp_table.warning(level.get(), current_xp.get())}
+                    onset={ctx.link().callback(Msg::SetLevel)}
+                />
+                { for self.points_warnings.iter().map(|warning| html! { <p>{ warning }</p> }) }
+                <button class="button" disabled={!dbs_loaded}
+                    onclick={ctx.link().callback(|_| Msg::ResetTalents(None))}
+                >
+                    { "Reset player's talents" }
+                </button>
                 { credits.view("Credits") }
                 { medigel.view("Medigel") }
             </Table>
@@ -402,4 +593,34 @@ impl Me2General {
             />
         }
     }
+
+    fn squad(&self, ctx: &Context<Self>, squad: Ref<'_, Vec<RcRef<Henchman>>>) -> Html {
+        let dbs_loaded = self.player_class_db.is_some() && self.squad_db.is_some();
+
+        // Other characters (Kenson, etc.) are in the squad too
+        let characters =
+            squad.iter().filter(|character| character.borrow().tag().starts_with("hench_"));
+        let characters = characters.map(|character| {
+            let tag = character.borrow().tag().clone();
+            let name = self
+                .squad_db
+                .as_ref()
+                .and_then(|squad_db| squad_db.get(&tag))
+                .map(|henchman| henchman.name.clone())
+                .unwrap_or_else(|| tag.clone());
+            html! {
+                <button class="button" disabled={!dbs_loaded}
+                    onclick={ctx.link().callback(move |_| Msg::ResetTalents(Some(tag.clone())))}
+                >
+                    { format!("Reset {}'s talents", name) }
+                </button>
+            }
+        });
+
+        html! {
+            <Table title="Squad">
+                { for characters }
+            </Table>
+        }
+    }
 }
@@ -1,8 +1,10 @@
+mod galaxy_map;
 mod general;
 mod plot;
 mod raw_plot;
+mod squad;
 
-pub use self::{general::*, plot::*, raw_plot::*};
+pub use self::{galaxy_map::*, general::*, plot::*, raw_plot::*, squad::*};
 
 use crate::save_data::{
     mass_effect_2::{Me2LeSaveGame, Me2SaveGame},
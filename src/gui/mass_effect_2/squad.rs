@@ -0,0 +1,114 @@
+use std::{
+    cell::{Ref, RefMut},
+    rc::Rc,
+};
+
+use yew::prelude::*;
+
+use crate::{
+    gui::{
+        components::{Helper, InputNumber, NumberType, Select},
+        raw_ui::RawUi,
+        shared::{Squad, SquadMate},
+    },
+    save_data::{
+        mass_effect_2::{player::Power, squad::Henchman},
+        shared::squad_db::SquadDb,
+        RcCell, RcRef,
+    },
+    services::database::Databases,
+};
+
+pub type Me2Squad = Squad<Henchman>;
+
+impl SquadMate for Henchman {
+    type Power = Power;
+
+    fn squad_db(databases: Databases) -> Option<Rc<SquadDb>> {
+        databases.get_me2_squad()
+    }
+
+    fn tag(&self) -> Ref<'_, String> {
+        Henchman::tag(self)
+    }
+
+    fn powers(&self) -> Ref<'_, Vec<RcRef<Power>>> {
+        Henchman::powers(self)
+    }
+
+    fn powers_mut(&mut self) -> RefMut<'_, Vec<RcRef<Power>>> {
+        Henchman::powers_mut(self)
+    }
+
+    fn mapped_power(&self) -> Ref<'_, String> {
+        Henchman::mapped_power(self)
+    }
+
+    fn mapped_power_mut(&mut self) -> RefMut<'_, String> {
+        Henchman::mapped_power_mut(self)
+    }
+
+    fn talent_points(&self) -> i32 {
+        Henchman::talent_points(self)
+    }
+
+    fn set_talent_points(&mut self, talent_points: i32) {
+        Henchman::set_talent_points(self, talent_points)
+    }
+
+    fn general(&self, onchange: Callback<()>) -> Html {
+        let spent: i32 =
+            self.powers().iter().map(|power| Power::rank_cost(power.borrow().rank() as i32)).sum();
+
+        html! {
+            <>
+                { self.character_level.view("Level") }
+                <InputNumber
+                    label="Talent Points"
+                    value={NumberType::Int(RcCell::clone(&self.talent_points))}
+                    onchange={onchange.reform(|_| ())}
+                />
+                <div class="flex items-center gap-1 cursor-default">
+                    { format!("Spent: {}", spent) }
+                    <Helper text=
+                        "Rank 1 costs 1 point, rank 2 costs 2 points, etc.\n\
+                        Changing a rank refunds or spends the difference from the talent points."
+                    />
+                </div>
+            </>
+        }
+    }
+
+    fn power_rank(character: &RcRef<Self>, power: &RcRef<Power>, onchange: Callback<()>) -> Html {
+        const RANKS: &[&str] = &["0", "1", "2", "3", "4"];
+
+        let rank = power.borrow().rank() as usize;
+        let onselect = {
+            let character = RcRef::clone(character);
+            let power = RcRef::clone(power);
+            Callback::from(move |rank: usize| {
+                let mut power = power.borrow_mut();
+                let old_rank = power.rank() as i32;
+                power.set_rank(rank as f32);
+
+                let refund = Power::rank_cost(old_rank) - Power::rank_cost(rank as i32);
+                let mut character = character.borrow_mut();
+                let talent_points = character.talent_points();
+                character.set_talent_points(talent_points + refund);
+                onchange.emit(());
+            })
+        };
+        html! {
+            <Select
+                options={RANKS}
+                current_idx={rank.min(RANKS.len() - 1)}
+                {onselect}
+                sized=false
+            />
+        }
+    }
+
+    fn details(&self) -> Html {
+        self.weapon_loadout.view("Loadout")
+    }
+}
@@ -1,6 +1,9 @@
-use std::cell::{Ref, RefMut};
+use std::{
+    cell::{Ref, RefMut},
+    rc::Rc,
+};
 
-use yew::prelude::*;
+use yew::{context::ContextHandle, prelude::*};
 
 use crate::{
     gui::{
@@ -9,10 +12,15 @@ use crate::{
         shared::{BonusPowerType, BonusPowers},
     },
     save_data::{
-        mass_effect_3::{player::Player, plot::PlotTable, Me3SaveGame},
-        shared::player::{Notoriety, Origin},
-        RcRef,
+        mass_effect_3::{player::Player, plot::PlotTable, squad::Henchman, Me3SaveGame},
+        shared::{
+            player::{Notoriety, Origin},
+            player_class_db::{self, ClassPlayer, PlayerClassDb},
+            squad_db::SquadDb,
+        },
+        RcCell, RcRef,
     },
+    services::database::Databases,
 };
 
 #[derive(Clone, RawUi)]
@@ -51,10 +59,12 @@ impl Me3Class {
 }
 
 pub enum Msg {
+    DatabaseLoaded(Databases),
     Gender(usize),
     Origin(usize),
     Notoriety(usize),
     PlayerClass(usize),
+    ResetTalents(Option<String>),
 }
 
 #[derive(Properties, PartialEq)]
@@ -72,20 +82,38 @@ impl Props {
     }
 }
 
-pub struct Me3General;
+pub struct Me3General {
+    _db_handle: ContextHandle<Databases>,
+    player_class_db: Option<Rc<PlayerClassDb>>,
+    squad_db: Option<Rc<SquadDb>>,
+}
 
 impl Component for Me3General {
     type Message = Msg;
     type Properties = Props;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Me3General
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
+            .expect("no database provider");
+
+        Me3General {
+            _db_handle,
+            player_class_db: databases.clone().get_me3_player_classes(),
+            squad_db: databases.get_me3_squad(),
+        }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
-        let Me3SaveGame { player, plot, .. } = &mut *ctx.props().save_game_mut();
+        let Me3SaveGame { player, plot, squad, .. } = &mut *ctx.props().save_game_mut();
         let (mut player, mut plot) = (player.borrow_mut(), plot.borrow_mut());
         match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.player_class_db = dbs.clone().get_me3_player_classes();
+                self.squad_db = dbs.get_me3_squad();
+                true
+            }
             Msg::Gender(gender) => {
                 let gender = gender != 0;
 
@@ -217,8 +245,65 @@ impl Component for Me3General {
                 false
             }
             Msg::PlayerClass(class_idx) => {
-                *player.class_name_mut() = Me3Class::names()[class_idx].to_owned();
-                false
+                let player_class_db = match self.player_class_db {
+                    Some(ref player_class_db) => player_class_db,
+                    None => return false,
+                };
+
+                let class_player = ClassPlayer {
+                    class_name: &player.class_name,
+                    localized_class_name: &player.localized_class_name,
+                    talent_points: &player.talent_points,
+                    powers: &player.powers,
+                    mapped_powers: [
+                        &player.mapped_power_1,
+                        &player.mapped_power_2,
+                        &player.mapped_power_3,
+                    ],
+                };
+                if !player_class_db.set_player_class(&class_player, Me3Class::names()[class_idx]) {
+                    return false;
+                }
+
+                // Hotkeys of removed powers
+                let power_names: Vec<String> =
+                    player.powers().iter().map(|power| power.borrow().name().clone()).collect();
+                player.hotkeys_mut().retain(|hotkey| {
+                    let hotkey = hotkey.borrow();
+                    *hotkey.pawn_name() != "Player" || power_names.contains(&hotkey.power_name())
+                });
+                true
+            }
+            Msg::ResetTalents(tag) => {
+                let (player_class_db, squad_db) = match (&self.player_class_db, &self.squad_db) {
+                    (Some(player_class_db), Some(squad_db)) => (player_class_db, squad_db),
+                    _ => return false,
+                };
+
+                let (talent_points, powers, base_ranks) = if let Some(tag) = tag {
+                    // Squad mate
+                    let squad = squad.borrow();
+                    let character = squad.iter().find(|character| *character.borrow().tag() == tag);
+                    let character = match character {
+                        Some(character) => character.borrow(),
+                        None => return false,
+                    };
+                    (
+                        RcCell::clone(&character.talent_points),
+                        RcRef::clone(&character.powers),
+                        squad_db.base_ranks(&tag),
+                    )
+                } else {
+                    // Player
+                    (
+                        RcCell::clone(&player.talent_points),
+                        RcRef::clone(&player.powers),
+                        player_class_db.base_ranks(&player.class_name()),
+                    )
+                };
+
+                player_class_db::reset_talents(&talent_points, &powers, &base_ranks);
+                true
             }
         }
     }
@@ -231,11 +316,12 @@ impl Component for Me3General {
                 <div class="flex-1 pr-1 flex flex-col gap-1">
                     { Self::role_play(ctx, save_game.player()) }
                     { Self::morality(save_game.plot()) }
-                    { Self::gameplay(ctx, save_game.player()) }
+                    { self.gameplay(ctx, save_game.player()) }
                 </div>
                 <div class="flex-1 pl-1 flex flex-col gap-1">
                     { Self::general(&save_game) }
                     { Self::bonus_powers(save_game.player()) }
+                    { self.squad(ctx, save_game.squad()) }
                 </div>
             </div>
         }
@@ -298,7 +384,9 @@ impl Me3General {
         }
     }
 
-    fn gameplay(ctx: &Context<Self>, player: Ref<'_, Player>) -> Html {
+    fn gameplay(&self, ctx: &Context<Self>, player: Ref<'_, Player>) -> Html {
+        let class_db_loaded = self.player_class_db.is_some();
+        let dbs_loaded = class_db_loaded && self.squad_db.is_some();
         let Player {
             level,
             current_xp,
@@ -323,12 +411,18 @@ impl Me3General {
                         options={Me3Class::variants()}
                         current_idx={class_idx}
                         onselect={ctx.link().callback(Msg::PlayerClass)}
+                        disabled={!class_db_loaded}
                     />
                     {"Class"}
                 </div>
                 { level.view("Level") }
                 { current_xp.view("Current XP") }
                 { talent_points.view("Talent Points") }
+                <button class="button" disabled={!dbs_loaded}
+                    onclick={ctx.link().callback(|_| Msg::ResetTalents(None))}
+                >
+                    { "Reset player's talents" }
+                </button>
                 { credits.view("Credits") }
                 { medigel.view("Medigel") }
                 { grenades.view("Grenades") }
@@ -398,4 +492,34 @@ impl Me3General {
             />
         }
     }
+
+    fn squad(&self, ctx: &Context<Self>, squad: Ref<'_, Vec<RcRef<Henchman>>>) -> Html {
+        let dbs_loaded = self.player_class_db.is_some() && self.squad_db.is_some();
+
+        // Other characters (`global_anderson`, etc.) are in the squad too
+        let characters =
+            squad.iter().filter(|character| character.borrow().tag().starts_with("hench_"));
+        let characters = characters.map(|character| {
+            let tag = character.borrow().tag().clone();
+            let name = self
+                .squad_db
+                .as_ref()
+                .and_then(|squad_db| squad_db.get(&tag))
+                .map(|henchman| henchman.name.clone())
+                .unwrap_or_else(|| tag.clone());
+            html! {
+                <button class="button" disabled={!dbs_loaded}
+                    onclick={ctx.link().callback(move |_| Msg::ResetTalents(Some(tag.clone())))}
+                >
+                    { format!("Reset {}'s talents", name) }
+                </button>
+            }
+        });
+
+        html! {
+            <Table title="Squad">
+                { for characters }
+            </Table>
+        }
+    }
 }
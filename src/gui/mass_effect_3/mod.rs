@@ -1,9 +1,19 @@
+mod galaxy_map;
 mod general;
+mod player_variables;
 mod plot;
 mod plot_variable;
+mod powers;
 mod raw_plot;
+mod squad;
+mod treasures;
+mod war_assets;
+mod weapons;
 
-pub use self::{general::*, plot::*, plot_variable::*, raw_plot::*};
+pub use self::{
+    galaxy_map::*, general::*, player_variables::*, plot::*, plot_variable::*, powers::*,
+    raw_plot::*, squad::*, treasures::*, war_assets::*, weapons::*,
+};
 
 use yew::prelude::*;
 
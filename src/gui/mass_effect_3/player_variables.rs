@@ -0,0 +1,183 @@
+use std::rc::Rc;
+
+use indexmap::IndexMap;
+use yew::{context::ContextHandle, prelude::*};
+
+use crate::{
+    gui::components::{Helper, InputNumber, NumberType, Select, Table},
+    save_data::{mass_effect_3::player_variable_db::Me3PlayerVariableDb, RcCell, RcRef},
+    services::database::Databases,
+};
+
+pub enum Msg {
+    DatabaseLoaded(Databases),
+    SelectNewVariable(usize),
+    Add,
+    Remove(String),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub variables: RcRef<IndexMap<String, RcCell<i32>>>,
+}
+
+pub struct Me3PlayerVariables {
+    _db_handle: ContextHandle<Databases>,
+    player_variable_db: Option<Rc<Me3PlayerVariableDb>>,
+    new_variable_idx: usize,
+}
+
+impl Component for Me3PlayerVariables {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
+            .expect("no database provider");
+
+        Me3PlayerVariables {
+            _db_handle,
+            player_variable_db: databases.get_me3_player_variables(),
+            new_variable_idx: 0,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.player_variable_db = dbs.get_me3_player_variables();
+                true
+            }
+            Msg::SelectNewVariable(idx) => {
+                self.new_variable_idx = idx;
+                false
+            }
+            Msg::Add => {
+                if let Some(ref player_variable_db) = self.player_variable_db {
+                    let variables = &ctx.props().variables;
+                    let new_key = Self::missing_keys(player_variable_db, &variables.borrow())
+                        .get(self.new_variable_idx)
+                        .map(|&key| key.to_owned());
+                    if let Some(key) = new_key {
+                        variables.borrow_mut().insert(key, Default::default());
+                        self.new_variable_idx = 0;
+                    }
+                }
+                true
+            }
+            Msg::Remove(key) => {
+                ctx.props().variables.borrow_mut().shift_remove(&key);
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if let Some(ref player_variable_db) = self.player_variable_db {
+            let variables = ctx.props().variables.borrow();
+
+            let (known, unknown): (Vec<_>, Vec<_>) = variables
+                .iter()
+                .map(|(key, value)| (key, value, player_variable_db.get(key)))
+                .partition(|(_, _, db_variable)| db_variable.is_some());
+
+            let known = known.into_iter().filter_map(|(key, value, db_variable)| {
+                db_variable.map(|db_variable| {
+                    html! {
+                        <div key={key.clone()} title={db_variable.description}>
+                            <InputNumber
+                                label={db_variable.label}
+                                value={NumberType::Int(RcCell::clone(value))}
+                            />
+                        </div>
+                    }
+                })
+            });
+
+            let unknown = unknown.into_iter().map(|(key, value, _)| {
+                let onclick = {
+                    let key = key.clone();
+                    ctx.link().callback(move |_| Msg::Remove(key.clone()))
+                };
+                html! {
+                    <div key={key.clone()} class="flex items-center gap-1">
+                        <InputNumber label={key.clone()} value={NumberType::Int(RcCell::clone(value))} />
+                        <button class="button ml-auto" {onclick}>
+                            {"Remove"}
+                        </button>
+                    </div>
+                }
+            });
+
+            let missing_keys = Self::missing_keys(player_variable_db, &variables);
+            let new_variable = (!missing_keys.is_empty()).then(|| {
+                let options: Vec<_> = missing_keys
+                    .iter()
+                    .filter_map(|key| player_variable_db.keys.get(*key))
+                    .map(|db_variable| db_variable.label.clone())
+                    .collect();
+                html! {
+                    <div class="flex items-center gap-1">
+                        <Select
+                            {options}
+                            current_idx={self.new_variable_idx}
+                            onselect={ctx.link().callback(Msg::SelectNewVariable)}
+                            sized=false
+                        />
+                        <button class="button ml-auto" onclick={ctx.link().callback(|_| Msg::Add)}>
+                            {"Add"}
+                        </button>
+                    </div>
+                }
+            });
+
+            html! {
+                <div class="flex divide-solid divide-x divide-default-border">
+                    <div class="flex-1 pr-1 flex flex-col gap-1 min-w-0">
+                        <Table title="Known variables">
+                            { for known }
+                        </Table>
+                    </div>
+                    <div class="flex-1 pl-1 flex flex-col gap-1 min-w-0">
+                        <Table title="Add a variable">
+                            { for new_variable }
+                        </Table>
+                        <Table title="Unknown variables">
+                            <div class="flex items-center gap-1 cursor-default">
+                                {"Keys that aren't in the database"}
+                                <Helper text=
+                                    "They are often added by mods.\n\
+                                    Removing a variable that the game or a mod still uses can break a quest or an unlock."
+                                />
+                            </div>
+                            { for unknown }
+                        </Table>
+                    </div>
+                </div>
+            }
+        } else {
+            html! {
+                <>
+                    <p>{ "Loading database..." }</p>
+                    <hr class="border-t border-default-border" />
+                </>
+            }
+        }
+    }
+}
+
+impl Me3PlayerVariables {
+    // Keys of the database that aren't in the save
+    fn missing_keys<'a>(
+        player_variable_db: &'a Me3PlayerVariableDb, variables: &IndexMap<String, RcCell<i32>>,
+    ) -> Vec<&'a str> {
+        player_variable_db
+            .keys
+            .keys()
+            .filter(|db_key| !variables.keys().any(|key| db_key.eq_ignore_ascii_case(key)))
+            .map(String::as_str)
+            .collect()
+    }
+}
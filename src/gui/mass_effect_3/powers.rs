@@ -0,0 +1,159 @@
+use std::rc::Rc;
+
+use yew::{context::ContextHandle, prelude::*};
+
+use crate::{
+    gui::components::{Helper, InputNumber, NumberType, Select, Table},
+    save_data::{
+        mass_effect_3::{player::Power, power_db::Me3PowerDb},
+        RcCell, RcRef,
+    },
+    services::database::Databases,
+};
+
+pub enum Msg {
+    DatabaseLoaded(Databases),
+    Changed,
+    Rank(RcRef<Power>, usize),
+    Evolution(RcRef<Power>, usize, usize),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub powers: RcRef<Vec<RcRef<Power>>>,
+    pub talent_points: RcCell<i32>,
+}
+
+pub struct Me3Powers {
+    _db_handle: ContextHandle<Databases>,
+    power_db: Option<Rc<Me3PowerDb>>,
+}
+
+impl Component for Me3Powers {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
+            .expect("no database provider");
+
+        Me3Powers { _db_handle, power_db: databases.get_me3_powers() }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.power_db = dbs.get_me3_powers();
+                true
+            }
+            Msg::Changed => true,
+            Msg::Rank(power, rank) => {
+                let refund = power.borrow_mut().respec(rank as i32);
+                ctx.props().talent_points.update(|tp| tp + refund);
+                true
+            }
+            Msg::Evolution(power, tier, choice) => {
+                power.borrow_mut().set_evolution(tier, Some(choice));
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if let Some(ref power_db) = self.power_db {
+            let powers = ctx.props().powers.borrow();
+
+            let spent: i32 =
+                powers.iter().map(|power| Power::rank_cost(power.borrow().rank() as i32)).sum();
+            let powers = powers.iter().map(|power| self.power(ctx, power_db, power));
+
+            html! {
+                <div class="flex divide-solid divide-x divide-default-border">
+                    <div class="flex-1 pr-1 flex flex-col gap-1 min-w-0">
+                        { for powers }
+                    </div>
+                    <div class="flex-1 pl-1 flex flex-col gap-1 min-w-0">
+                        <Table title="Talent Points">
+                            <InputNumber
+                                label="Available"
+                                value={NumberType::Int(RcCell::clone(&ctx.props().talent_points))}
+                                onchange={ctx.link().callback(|_| Msg::Changed)}
+                            />
+                            <div class="flex items-center gap-1 cursor-default">
+                                { format!("Spent: {}", spent) }
+                                <Helper text=
+                                    "Rank 1 costs 1 point, rank 2 costs 2 points, etc.\n\
+                                    Changing a rank refunds or spends the difference from the available points. \
+                                    Ranks given for free by the game are counted as spent."
+                                />
+                            </div>
+                        </Table>
+                    </div>
+                </div>
+            }
+        } else {
+            html! {
+                <>
+                    <p>{ "Loading database..." }</p>
+                    <hr class="border-t border-default-border" />
+                </>
+            }
+        }
+    }
+}
+
+impl Me3Powers {
+    fn power(&self, ctx: &Context<Self>, power_db: &Me3PowerDb, power: &RcRef<Power>) -> Html {
+        const RANKS: &[&str] = &["0", "1", "2", "3", "4", "5", "6"];
+
+        let db_power = power_db.get_power(&power.borrow().power_class_name());
+        let title = match db_power {
+            Some(db_power) => db_power.name.clone(),
+            None => power.borrow().name().to_owned(),
+        };
+
+        let rank = power.borrow().rank() as i32;
+        let evolutions = (0..Power::EVOLUTION_TIERS).filter_map(|tier| {
+            let tier_rank = tier as i32 + 4;
+            (rank >= tier_rank).then(|| {
+                let options: Vec<_> = match db_power.and_then(|power| power.evolutions.get(tier)) {
+                    Some((first, second)) => vec![first.clone(), second.clone()],
+                    None => vec!["Evolution 1".to_owned(), "Evolution 2".to_owned()],
+                };
+                let current_idx = power.borrow().evolution(tier).unwrap_or_default();
+                let onselect = {
+                    let power = RcRef::clone(power);
+                    ctx.link()
+                        .callback(move |choice| Msg::Evolution(RcRef::clone(&power), tier, choice))
+                };
+                html! {
+                    <div class="flex items-center gap-1 cursor-default">
+                        <Select {options} {current_idx} {onselect} />
+                        { format!("Rank {}", tier_rank) }
+                    </div>
+                }
+            })
+        });
+
+        let onselect = {
+            let power = RcRef::clone(power);
+            ctx.link().callback(move |rank| Msg::Rank(RcRef::clone(&power), rank))
+        };
+
+        html! {
+            <Table {title}>
+                <div class="flex items-center gap-1 cursor-default">
+                    <Select
+                        options={RANKS}
+                        current_idx={rank.clamp(0, Power::MAX_RANK) as usize}
+                        {onselect}
+                    />
+                    {"Rank"}
+                </div>
+                { for evolutions }
+            </Table>
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::{
+    cell::{Ref, RefMut},
+    rc::Rc,
+};
+
+use yew::prelude::*;
+
+use super::{Me3Powers, Me3Weapons};
+use crate::{
+    gui::{
+        raw_ui::RawUi,
+        shared::{Squad, SquadMate},
+    },
+    save_data::{
+        mass_effect_3::{player::Power, squad::Henchman},
+        shared::squad_db::SquadDb,
+        RcCell, RcRef,
+    },
+    services::database::Databases,
+};
+
+pub type Me3Squad = Squad<Henchman>;
+
+impl SquadMate for Henchman {
+    type Power = Power;
+
+    fn squad_db(databases: Databases) -> Option<Rc<SquadDb>> {
+        databases.get_me3_squad()
+    }
+
+    fn tag(&self) -> Ref<'_, String> {
+        Henchman::tag(self)
+    }
+
+    fn powers(&self) -> Ref<'_, Vec<RcRef<Power>>> {
+        Henchman::powers(self)
+    }
+
+    fn powers_mut(&mut self) -> RefMut<'_, Vec<RcRef<Power>>> {
+        Henchman::powers_mut(self)
+    }
+
+    fn mapped_power(&self) -> Ref<'_, String> {
+        Henchman::mapped_power(self)
+    }
+
+    fn mapped_power_mut(&mut self) -> RefMut<'_, String> {
+        Henchman::mapped_power_mut(self)
+    }
+
+    fn talent_points(&self) -> i32 {
+        Henchman::talent_points(self)
+    }
+
+    fn set_talent_points(&mut self, talent_points: i32) {
+        Henchman::set_talent_points(self, talent_points)
+    }
+
+    fn general(&self, _: Callback<()>) -> Html {
+        html! {
+            <>
+                { self.character_level.view("Level") }
+                { self.grenades.view("Grenades") }
+            </>
+        }
+    }
+
+    // The ranks are in the powers below
+    fn power_rank(_: &RcRef<Self>, _: &RcRef<Power>, _: Callback<()>) -> Html {
+        Html::default()
+    }
+
+    fn details(&self) -> Html {
+        html! {
+            <>
+                <Me3Powers
+                    powers={RcRef::clone(&self.powers)}
+                    talent_points={RcCell::clone(&self.talent_points)}
+                />
+                <hr class="border-t border-default-border" />
+                <Me3Weapons
+                    weapons={RcRef::clone(&self.weapons)}
+                    weapons_mods={RcRef::clone(&self.weapon_mods)}
+                    weapons_loadout={RcRef::clone(&self.weapon_loadout)}
+                />
+            </>
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use std::rc::Rc;
+
+use yew::{context::ContextHandle, prelude::*};
+
+use crate::{
+    gui::components::{Helper, InputNumber, NumberType, Select, Table},
+    save_data::{
+        mass_effect_3::{weapon_db::Me3WeaponDb, LevelTreasure, Me3SaveGame},
+        RcCell, RcRef,
+    },
+    services::database::Databases,
+};
+
+pub enum Msg {
+    DatabaseLoaded(Databases),
+    Changed,
+    AddItem(RcRef<LevelTreasure>, usize),
+    RemoveItem(RcRef<LevelTreasure>, usize),
+    ClaimAll,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub save_game: RcRef<Me3SaveGame>,
+}
+
+pub struct Me3Treasures {
+    _db_handle: ContextHandle<Databases>,
+    weapon_db: Option<Rc<Me3WeaponDb>>,
+}
+
+impl Component for Me3Treasures {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
+            .expect("no database provider");
+
+        Me3Treasures { _db_handle, weapon_db: databases.get_me3_weapons() }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.weapon_db = dbs.get_me3_weapons();
+                true
+            }
+            Msg::Changed => true,
+            Msg::AddItem(treasure, idx) => {
+                // The first option is the placeholder
+                let class_name = self.weapon_db.as_ref().and_then(|weapon_db| {
+                    Self::known_items(weapon_db).nth(idx.checked_sub(1)?).cloned()
+                });
+                if let Some(class_name) = class_name {
+                    treasure.borrow_mut().items_mut().push(class_name.into());
+                }
+                true
+            }
+            Msg::RemoveItem(treasure, idx) => {
+                treasure.borrow_mut().items_mut().remove(idx);
+                true
+            }
+            Msg::ClaimAll => {
+                if let Some(ref weapon_db) = self.weapon_db {
+                    ctx.props().save_game.borrow().claim_treasures(weapon_db);
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if let Some(ref weapon_db) = self.weapon_db {
+            let save_game = ctx.props().save_game.borrow();
+            let treasures = save_game.treasures();
+
+            let (credits, xp) = treasures.iter().fold((0, 0), |(credits, xp), treasure| {
+                let treasure = treasure.borrow();
+                (credits + treasure.credits(), xp + treasure.xp())
+            });
+
+            let known_items: Vec<_> = std::iter::once("Add an item...".to_owned())
+                .chain(
+                    Self::known_items(weapon_db)
+                        .map(|class_name| Self::item_name(weapon_db, class_name)),
+                )
+                .collect();
+
+            let treasures = treasures
+                .iter()
+                .map(|treasure| self.treasure(ctx, weapon_db, treasure, known_items.clone()));
+
+            html! {
+                <div class="flex flex-col gap-1">
+                    <div class="flex items-center gap-1 cursor-default">
+                        { format!("Pending: {} credits, {} XP", credits, xp) }
+                        <button class="button" onclick={ctx.link().callback(|_| Msg::ClaimAll)}>
+                            {"Claim all"}
+                        </button>
+                        <Helper text=
+                            "Adds the credits and the XP of every level to the player and removes the entries.\n\
+                            Weapons the player doesn't have yet are added to the inventory, mods aren't given."
+                        />
+                    </div>
+                    { for treasures }
+                </div>
+            }
+        } else {
+            html! {
+                <>
+                    <p>{ "Loading database..." }</p>
+                    <hr class="border-t border-default-border" />
+                </>
+            }
+        }
+    }
+}
+
+impl Me3Treasures {
+    fn known_items(weapon_db: &Me3WeaponDb) -> impl Iterator<Item = &String> {
+        weapon_db.weapons.keys().chain(weapon_db.mods.keys())
+    }
+
+    fn item_name(weapon_db: &Me3WeaponDb, class_name: &str) -> String {
+        match weapon_db.weapons.get(class_name) {
+            Some(weapon) => weapon.name.clone(),
+            None => match weapon_db.mods.get(class_name) {
+                Some(weapon_mod) => format!("{} ({})", weapon_mod.name, weapon_mod.category),
+                None => class_name.to_owned(),
+            },
+        }
+    }
+
+    fn treasure(
+        &self, ctx: &Context<Self>, weapon_db: &Me3WeaponDb, treasure: &RcRef<LevelTreasure>,
+        known_items: Vec<String>,
+    ) -> Html {
+        let items = treasure
+            .borrow()
+            .items()
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| {
+                let onclick = {
+                    let treasure = RcRef::clone(treasure);
+                    ctx.link().callback(move |_| Msg::RemoveItem(RcRef::clone(&treasure), idx))
+                };
+                html! {
+                    <div class="flex items-center gap-1">
+                        { Self::item_name(weapon_db, &item.borrow()) }
+                        <button class="button ml-auto" {onclick}>
+                            {"Remove"}
+                        </button>
+                    </div>
+                }
+            })
+            .collect::<Html>();
+
+        let onselect = {
+            let treasure = RcRef::clone(treasure);
+            ctx.link().callback(move |idx| Msg::AddItem(RcRef::clone(&treasure), idx))
+        };
+
+        let treasure = treasure.borrow();
+        html! {
+            <Table title={treasure.level_name().clone()}>
+                <InputNumber
+                    label="Credits"
+                    value={NumberType::Int(RcCell::clone(&treasure.credits))}
+                    onchange={ctx.link().callback(|_| Msg::Changed)}
+                />
+                <InputNumber
+                    label="XP"
+                    value={NumberType::Int(RcCell::clone(&treasure.xp))}
+                    onchange={ctx.link().callback(|_| Msg::Changed)}
+                />
+                { items }
+                <Select options={known_items} current_idx=0 {onselect} sized=false />
+            </Table>
+        }
+    }
+}
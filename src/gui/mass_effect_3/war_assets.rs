@@ -0,0 +1,211 @@
+use std::{
+    cell::{Ref, RefMut},
+    rc::Rc,
+};
+
+use indexmap::IndexMap;
+use yew::{context::ContextHandle, prelude::*};
+
+use crate::{
+    gui::components::{InputNumber, NumberType, Table},
+    save_data::{
+        mass_effect_3::{
+            player::Player,
+            war_asset::WarAsset,
+            war_asset_db::{DbWarAsset, Me3WarAssetDb, WarAssetKind},
+        },
+        RcCell, RcRef,
+    },
+    services::database::Databases,
+};
+
+pub enum Msg {
+    DatabaseLoaded(Box<Databases>),
+    Changed,
+    Add(WarAsset),
+    Remove(WarAsset),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub player: RcRef<Player>,
+}
+
+impl Props {
+    fn player(&self) -> Ref<'_, Player> {
+        self.player.borrow()
+    }
+
+    fn player_mut(&self) -> RefMut<'_, Player> {
+        self.player.borrow_mut()
+    }
+}
+
+pub struct Me3WarAssets {
+    _db_handle: ContextHandle<Databases>,
+    war_asset_db: Option<Rc<Me3WarAssetDb>>,
+}
+
+impl Component for Me3WarAssets {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(|dbs| Msg::DatabaseLoaded(Box::new(dbs))))
+            .expect("no database provider");
+
+        Me3WarAssets { _db_handle, war_asset_db: databases.get_me3_war_assets() }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.war_asset_db = dbs.get_me3_war_assets();
+                true
+            }
+            Msg::Changed => true,
+            Msg::Add(war_asset) => {
+                let db_war_asset =
+                    self.war_asset_db.as_ref().and_then(|db| db.get(&war_asset.0).cloned());
+
+                if let Some(DbWarAsset { strength, .. }) = db_war_asset {
+                    let strength = strength.unwrap_or_default();
+                    ctx.props().player_mut().war_assets_mut().insert(war_asset, strength.into());
+                }
+                true
+            }
+            Msg::Remove(war_asset) => {
+                ctx.props().player_mut().war_assets_mut().shift_remove(&war_asset);
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if let Some(ref war_asset_db) = self.war_asset_db {
+            let player = ctx.props().player();
+            let war_assets = player.war_assets();
+
+            // Category => Assets
+            let mut categories: IndexMap<&str, Vec<(WarAsset, RcCell<i32>)>> = IndexMap::new();
+            for (war_asset, strength) in war_assets.iter() {
+                let category = war_asset_db
+                    .get(&war_asset.0)
+                    .map(|db_war_asset| db_war_asset.category.as_str())
+                    .unwrap_or("Unknown");
+                categories.entry(category).or_default().push((*war_asset, RcCell::clone(strength)));
+            }
+            categories.sort_keys();
+
+            // Only the military assets add to the military strength
+            let is_military = |war_asset: &WarAsset| {
+                war_asset_db
+                    .get(&war_asset.0)
+                    .map(|db_war_asset| db_war_asset.kind == WarAssetKind::Military)
+                    .unwrap_or_default()
+            };
+            let military_strength = |assets: &[(WarAsset, RcCell<i32>)]| -> i32 {
+                assets
+                    .iter()
+                    .filter(|(war_asset, _)| is_military(war_asset))
+                    .map(|(_, strength)| strength.get())
+                    .sum()
+            };
+
+            let totals = categories.iter().map(|(category, assets)| {
+                let total = military_strength(assets);
+                html! {
+                    <div class="flex gap-1">
+                        <span class="w-1/2">{ category }</span>
+                        <span class="w-1/2">{ total }</span>
+                    </div>
+                }
+            });
+            let total: i32 = categories.values().map(|assets| military_strength(assets)).sum();
+
+            let categories = categories.iter().map(|(category, assets)| {
+                let assets = assets.iter().map(|(war_asset, strength)| {
+                    let war_asset = *war_asset;
+                    html! {
+                        <div class="flex items-center gap-1">
+                            <InputNumber
+                                label={Self::label(war_asset_db, war_asset)}
+                                value={NumberType::Int(RcCell::clone(strength))}
+                                onchange={ctx.link().callback(|_| Msg::Changed)}
+                            />
+                            <button class="button ml-auto" onclick={ctx.link().callback(move |_| Msg::Remove(war_asset))}>
+                                {"Remove"}
+                            </button>
+                        </div>
+                    }
+                });
+                html! {
+                    <Table title={category.to_string()}>
+                        { for assets }
+                    </Table>
+                }
+            });
+
+            let missing = war_asset_db
+                .keys()
+                .map(|&id| WarAsset(id))
+                .filter(|war_asset| !war_assets.contains_key(war_asset));
+            let missing = missing.map(|war_asset| {
+                html! {
+                    <div class="flex items-center gap-1">
+                        <span>{ Self::label(war_asset_db, war_asset) }</span>
+                        <button class="button ml-auto" onclick={ctx.link().callback(move |_| Msg::Add(war_asset))}>
+                            {"Add"}
+                        </button>
+                    </div>
+                }
+            });
+
+            html! {
+                <div class="flex divide-solid divide-x divide-default-border">
+                    <div class="flex-1 pr-1 flex flex-col gap-1 min-w-0">
+                        { for categories }
+                    </div>
+                    <div class="flex-1 pl-1 flex flex-col gap-1 min-w-0">
+                        <Table title="Total military strength" helper=
+                            "Sum of the strength of the military assets, the other kinds don't add to it.\n\
+                            The effective military strength is this total multiplied by the galaxy readiness."
+                        >
+                            { for totals }
+                            <div class="flex gap-1">
+                                <span class="w-1/2">{ "Total" }</span>
+                                <span class="w-1/2">{ total }</span>
+                            </div>
+                        </Table>
+                        <Table title="Missing war assets" helper=
+                            "War assets the game knows about but that are not in your save.\n\
+                            Useful when a bugged mission did not give you an asset.\n\
+                            The asset is added with the strength it had in a save, or 0 when it isn't known.\n\
+                            The plots that grant the asset are not set, the game may take it back on load."
+                        >
+                            { for missing }
+                        </Table>
+                    </div>
+                </div>
+            }
+        } else {
+            html! {
+                <>
+                    <p>{ "Loading database..." }</p>
+                    <hr class="border-t border-default-border" />
+                </>
+            }
+        }
+    }
+}
+
+impl Me3WarAssets {
+    fn label(war_asset_db: &Me3WarAssetDb, war_asset: WarAsset) -> String {
+        match war_asset_db.get(&war_asset.0) {
+            Some(DbWarAsset { name, kind, .. }) => format!("{} - {} ({})", name, kind, *war_asset),
+            None => format!("War asset {}", *war_asset),
+        }
+    }
+}
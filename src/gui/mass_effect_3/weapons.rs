@@ -0,0 +1,394 @@
+use std::{
+    cell::{Ref, RefMut},
+    rc::Rc,
+};
+
+use yew::{context::ContextHandle, prelude::*};
+
+use crate::{
+    gui::components::{Helper, Select, Table},
+    save_data::{
+        mass_effect_3::{
+            player::{Weapon, WeaponMod},
+            weapon_db::{DbWeapon, Me3WeaponDb, WeaponCategory},
+        },
+        shared::player::WeaponLoadout,
+        RcRef,
+    },
+    services::database::Databases,
+};
+
+// The game stores `None` for an empty loadout slot
+const EMPTY_SLOT: &str = "None";
+const MOD_SLOTS: usize = 2;
+
+pub enum Msg {
+    DatabaseLoaded(Databases),
+    SelectNewWeapon(usize),
+    AddWeapon,
+    RemoveWeapon(usize),
+    ChangeMod(String, usize, Option<String>),
+    RemoveWeaponMods(usize),
+    ChangeLoadout(WeaponCategory, Option<String>),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub weapons: RcRef<Vec<RcRef<Weapon>>>,
+    pub weapons_mods: RcRef<Vec<RcRef<WeaponMod>>>,
+    pub weapons_loadout: RcRef<WeaponLoadout>,
+}
+
+pub struct Me3Weapons {
+    _db_handle: ContextHandle<Databases>,
+    weapon_db: Option<Rc<Me3WeaponDb>>,
+    new_weapon_idx: usize,
+}
+
+impl Component for Me3Weapons {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
+            .expect("no database provider");
+
+        Me3Weapons { _db_handle, weapon_db: databases.get_me3_weapons(), new_weapon_idx: 0 }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.weapon_db = dbs.get_me3_weapons();
+                true
+            }
+            Msg::SelectNewWeapon(idx) => {
+                self.new_weapon_idx = idx;
+                false
+            }
+            Msg::AddWeapon => {
+                let class_name = self
+                    .weapon_db
+                    .as_ref()
+                    .and_then(|db| db.weapons.get_index(self.new_weapon_idx))
+                    .map(|(class_name, _)| class_name.clone());
+
+                if let Some(class_name) = class_name {
+                    let mut weapon = Weapon::default();
+                    *weapon.class_name_mut() = class_name;
+                    ctx.props().weapons.borrow_mut().push(RcRef::new(weapon));
+                }
+                true
+            }
+            Msg::RemoveWeapon(idx) => {
+                ctx.props().weapons.borrow_mut().remove(idx);
+                true
+            }
+            Msg::ChangeMod(weapon_class_name, slot, mod_class_name) => {
+                let mut weapons_mods = ctx.props().weapons_mods.borrow_mut();
+
+                let existing = weapons_mods
+                    .iter()
+                    .find(|weapon_mod| {
+                        *weapon_mod.borrow().weapon_class_name() == weapon_class_name
+                    })
+                    .cloned();
+                let weapon_mod = existing.unwrap_or_else(|| {
+                    let mut weapon_mod = WeaponMod::default();
+                    *weapon_mod.weapon_class_name_mut() = weapon_class_name;
+                    let weapon_mod = RcRef::new(weapon_mod);
+                    weapons_mods.push(RcRef::clone(&weapon_mod));
+                    weapon_mod
+                });
+
+                let mut weapon_mod = weapon_mod.borrow_mut();
+                let mut mod_class_names: Vec<_> = weapon_mod
+                    .weapon_mod_class_names()
+                    .iter()
+                    .map(|m| Some(m.borrow().clone()))
+                    .collect();
+                mod_class_names.resize(MOD_SLOTS.max(mod_class_names.len()), None);
+                mod_class_names[slot] = mod_class_name;
+
+                *weapon_mod.weapon_mod_class_names_mut() =
+                    mod_class_names.into_iter().flatten().map(RcRef::new).collect();
+                true
+            }
+            Msg::RemoveWeaponMods(idx) => {
+                ctx.props().weapons_mods.borrow_mut().remove(idx);
+                true
+            }
+            Msg::ChangeLoadout(category, class_name) => {
+                let mut loadout = ctx.props().weapons_loadout.borrow_mut();
+                *Self::loadout_slot_mut(&mut loadout, category) =
+                    class_name.unwrap_or_else(|| EMPTY_SLOT.to_owned());
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if let Some(ref weapon_db) = self.weapon_db {
+            html! {
+                <div class="flex divide-solid divide-x divide-default-border">
+                    <div class="flex-1 pr-1 flex flex-col gap-1 min-w-0">
+                        { self.loadout(ctx, weapon_db) }
+                        { self.weapons(ctx, weapon_db) }
+                    </div>
+                    <div class="flex-1 pl-1 flex flex-col gap-1 min-w-0">
+                        { self.weapons_mods(ctx, weapon_db) }
+                    </div>
+                </div>
+            }
+        } else {
+            html! {
+                <>
+                    <p>{ "Loading database..." }</p>
+                    <hr class="border-t border-default-border" />
+                </>
+            }
+        }
+    }
+}
+
+impl Me3Weapons {
+    fn loadout(&self, ctx: &Context<Self>, weapon_db: &Me3WeaponDb) -> Html {
+        let loadout = ctx.props().weapons_loadout.borrow();
+
+        let slots = WeaponCategory::all().into_iter().map(|category| {
+            let current = Self::loadout_slot(&loadout, category).clone();
+            let current = (current != EMPTY_SLOT && !current.is_empty()).then(|| current);
+            let choices = weapon_db.weapons_of(category).map(|(class_name, _)| class_name.clone());
+            let (options, choices, current_idx) = Self::options(weapon_db, choices, current);
+
+            html! {
+                <div class="flex items-center gap-1 cursor-default">
+                    <Select
+                        {options}
+                        {current_idx}
+                        onselect={ctx.link().callback(move |idx: usize| {
+                            Msg::ChangeLoadout(category, choices.get(idx).cloned().flatten())
+                        })}
+                    />
+                    { category.to_string() }
+                </div>
+            }
+        });
+
+        html! {
+            <Table title="Loadout">
+                { for slots }
+            </Table>
+        }
+    }
+
+    fn weapons(&self, ctx: &Context<Self>, weapon_db: &Me3WeaponDb) -> Html {
+        let weapons = ctx.props().weapons.borrow();
+
+        let weapons = weapons.iter().enumerate().map(|(idx, weapon)| {
+            let weapon = weapon.borrow();
+            let class_name = weapon.class_name();
+            let label = match weapon_db.weapons.get(&*class_name) {
+                Some(DbWeapon { name, category }) => html! { format!("{} - {}", name, category) },
+                None => Self::unknown(&class_name),
+            };
+            html! {
+                <div class="flex items-center gap-1">
+                    { label }
+                    <button class="button ml-auto" onclick={ctx.link().callback(move |_| Msg::RemoveWeapon(idx))}>
+                        {"Remove"}
+                    </button>
+                </div>
+            }
+        });
+
+        let new_weapons: Vec<_> = weapon_db
+            .weapons
+            .values()
+            .map(|DbWeapon { name, category }| format!("{} - {}", name, category))
+            .collect();
+
+        html! {
+            <Table title="Weapons">
+                { for weapons }
+                <div class="flex items-center gap-1">
+                    <Select
+                        options={new_weapons}
+                        current_idx={self.new_weapon_idx}
+                        onselect={ctx.link().callback(Msg::SelectNewWeapon)}
+                        sized=false
+                    />
+                    <button class="button ml-auto" onclick={ctx.link().callback(|_| Msg::AddWeapon)}>
+                        {"Add"}
+                    </button>
+                </div>
+            </Table>
+        }
+    }
+
+    fn weapons_mods(&self, ctx: &Context<Self>, weapon_db: &Me3WeaponDb) -> Html {
+        let weapons_mods = ctx.props().weapons_mods.borrow();
+
+        let current_mods = |weapon_class_name: &str| {
+            weapons_mods
+                .iter()
+                .find(|weapon_mod| *weapon_mod.borrow().weapon_class_name() == weapon_class_name)
+                .map(|weapon_mod| {
+                    weapon_mod
+                        .borrow()
+                        .weapon_mod_class_names()
+                        .iter()
+                        .map(|mod_class_name| mod_class_name.borrow().clone())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        };
+
+        let categories = WeaponCategory::all().into_iter().filter_map(|category| {
+            let weapons = weapon_db.weapons_of(category).map(|(weapon_class_name, weapon)| {
+                let current_mods = current_mods(weapon_class_name);
+                let compatible_mods = weapon_db.compatible_mods(weapon_class_name);
+
+                let slots = (0..MOD_SLOTS).map(|slot| {
+                    let choices =
+                        compatible_mods.iter().map(|(class_name, _)| (*class_name).clone());
+                    let current = current_mods.get(slot).cloned();
+                    let (options, choices, current_idx) =
+                        Self::options(weapon_db, choices, current);
+
+                    let weapon_class_name = weapon_class_name.clone();
+                    html! {
+                        <Select
+                            {options}
+                            {current_idx}
+                            onselect={ctx.link().callback(move |idx: usize| {
+                                let mod_class_name = choices.get(idx).cloned().flatten();
+                                Msg::ChangeMod(weapon_class_name.clone(), slot, mod_class_name)
+                            })}
+                            sized=false
+                        />
+                    }
+                });
+
+                html! {
+                    <div class="flex items-center gap-1 cursor-default">
+                        <span class="w-48 truncate">{ &weapon.name }</span>
+                        { for slots }
+                    </div>
+                }
+            });
+
+            let has_mods =
+                weapon_db.mods.values().any(|weapon_mod| weapon_mod.category == category);
+            has_mods.then(|| {
+                html! {
+                    <Table title={category.to_string()}>
+                        { for weapons }
+                    </Table>
+                }
+            })
+        });
+
+        // Mods of weapons the game doesn't know, they are dropped on load
+        let unknown = weapons_mods.iter().enumerate().filter_map(|(idx, weapon_mod)| {
+            let weapon_mod = weapon_mod.borrow();
+            let weapon_class_name = weapon_mod.weapon_class_name();
+            (!weapon_db.weapons.contains_key(&*weapon_class_name)).then(|| {
+                html! {
+                    <div class="flex items-center gap-1">
+                        { Self::unknown(&weapon_class_name) }
+                        <button class="button ml-auto" onclick={ctx.link().callback(move |_| Msg::RemoveWeaponMods(idx))}>
+                            {"Remove"}
+                        </button>
+                    </div>
+                }
+            })
+        });
+        let unknown: Vec<_> = unknown.collect();
+        let unknown = (!unknown.is_empty()).then(|| {
+            html! {
+                <Table title="Unknown weapons">
+                    { for unknown }
+                </Table>
+            }
+        });
+
+        html! {
+            <>
+                { for unknown }
+                <div class="flex items-center gap-1 cursor-default">
+                    {"Weapon mods"}
+                    <Helper text=
+                        "A weapon can have up to 2 mods of its own category.\n\
+                        The game doesn't check if you unlocked the mod."
+                    />
+                </div>
+                { for categories }
+            </>
+        }
+    }
+
+    // Options with `None` first and an extra one if the current class name is not in the database
+    fn options(
+        weapon_db: &Me3WeaponDb, choices: impl Iterator<Item = String>, current: Option<String>,
+    ) -> (Vec<String>, Rc<Vec<Option<String>>>, usize) {
+        let mut choices: Vec<_> = std::iter::once(None).chain(choices.map(Some)).collect();
+        let current_idx = match choices.iter().position(|choice| *choice == current) {
+            Some(idx) => idx,
+            None => {
+                choices.push(current);
+                choices.len() - 1
+            }
+        };
+
+        let options = choices
+            .iter()
+            .map(|choice| match choice {
+                Some(class_name) => weapon_db
+                    .weapons
+                    .get(class_name)
+                    .or_else(|| weapon_db.mods.get(class_name))
+                    .map(|db_weapon| db_weapon.name.clone())
+                    .unwrap_or_else(|| format!("Unknown: {}", class_name)),
+                None => EMPTY_SLOT.to_owned(),
+            })
+            .collect();
+
+        (options, Rc::new(choices), current_idx)
+    }
+
+    fn unknown(class_name: &str) -> Html {
+        html! {
+            <span class="truncate">
+                { format!("Unknown: {}", class_name) }
+                <Helper text="This class name is not in the database, if it is a typo the game will drop it." />
+            </span>
+        }
+    }
+
+    fn loadout_slot(loadout: &WeaponLoadout, category: WeaponCategory) -> Ref<'_, String> {
+        match category {
+            WeaponCategory::AssaultRifle => loadout.assault_rifle(),
+            WeaponCategory::Shotgun => loadout.shotgun(),
+            WeaponCategory::SniperRifle => loadout.sniper_rifle(),
+            WeaponCategory::SubmachineGun => loadout.submachine_gun(),
+            WeaponCategory::Pistol => loadout.pistol(),
+            WeaponCategory::HeavyWeapon => loadout.heavy_weapon(),
+        }
+    }
+
+    fn loadout_slot_mut(
+        loadout: &mut WeaponLoadout, category: WeaponCategory,
+    ) -> RefMut<'_, String> {
+        match category {
+            WeaponCategory::AssaultRifle => loadout.assault_rifle_mut(),
+            WeaponCategory::Shotgun => loadout.shotgun_mut(),
+            WeaponCategory::SniperRifle => loadout.sniper_rifle_mut(),
+            WeaponCategory::SubmachineGun => loadout.submachine_gun_mut(),
+            WeaponCategory::Pistol => loadout.pistol_mut(),
+            WeaponCategory::HeavyWeapon => loadout.heavy_weapon_mut(),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use yew::prelude::*;
+
+use crate::{
+    gui::{
+        components::{Select, Table},
+        raw_ui::RawUi,
+    },
+    save_data::{
+        shared::appearance::{Appearance as DataAppearance, PlayerAppearanceType},
+        RcRef,
+    },
+};
+
+pub enum Msg {
+    CombatAppearance(usize),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub appearance: RcRef<DataAppearance>,
+}
+
+pub struct Appearance;
+
+impl Component for Appearance {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Appearance
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::CombatAppearance(idx) => {
+                *ctx.props().appearance.borrow_mut().combat_appearance_mut() =
+                    PlayerAppearanceType::from(idx);
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let appearance = ctx.props().appearance.borrow();
+        let combat_appearance = *appearance.combat_appearance();
+
+        // The game only reads the pieces of the current combat appearance
+        let armor = match combat_appearance {
+            PlayerAppearanceType::Parts => html! {
+                <>
+                    { appearance.torso_id.view("Torso") }
+                    { appearance.shoulder_id.view("Shoulders") }
+                    { appearance.arm_id.view("Arms") }
+                    { appearance.leg_id.view("Legs") }
+                </>
+            },
+            PlayerAppearanceType::Full => appearance.full_body_id.view("Full body"),
+        };
+
+        html! {
+            <div class="flex divide-solid divide-x divide-default-border">
+                <div class="flex-1 pr-1 flex flex-col gap-1 min-w-0">
+                    <Table title="Armor" helper=
+                        "Only the pieces of the current combat appearance are shown, the game ignores the others.\n\
+                        The ids are edited as is, there is no database of the armor names."
+                    >
+                        <div class="flex items-center gap-1 cursor-default">
+                            <Select
+                                options={PlayerAppearanceType::variants()}
+                                current_idx={combat_appearance as usize}
+                                onselect={ctx.link().callback(Msg::CombatAppearance)}
+                            />
+                            {"Combat appearance"}
+                        </div>
+                        { armor }
+                        { appearance.helmet_id.view("Helmet") }
+                        { appearance.casual_id.view("Casual") }
+                    </Table>
+                </div>
+                <div class="flex-1 pl-1 flex flex-col gap-1 min-w-0">
+                    <Table title="Colors">
+                        { appearance.specular_id.view("Specular") }
+                        { appearance.tint1_id.view("Tint 1") }
+                        { appearance.tint2_id.view("Tint 2") }
+                        { appearance.tint3_id.view("Tint 3") }
+                        { appearance.pattern_id.view("Pattern") }
+                        { appearance.pattern_color_id.view("Pattern color") }
+                    </Table>
+                </div>
+            </div>
+        }
+    }
+}
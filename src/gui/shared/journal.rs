@@ -0,0 +1,244 @@
+use std::rc::Rc;
+
+use yew::{context::ContextHandle, prelude::*};
+
+use crate::{
+    gui::{
+        components::{CheckBox, Table},
+        raw_ui::RawUi,
+    },
+    save_data::{
+        mass_effect_3::plot::Journal as Me3Journal,
+        shared::{
+            codex_db::CodexDb,
+            plot::{Codex, Journal as SharedJournal},
+        },
+        RcCell, RcRef,
+    },
+    services::database::Databases,
+};
+
+#[derive(Clone)]
+pub enum JournalType {
+    Me1Le(RcRef<SharedJournal>),
+    Me2(RcRef<SharedJournal>),
+    Me3(RcRef<Me3Journal>),
+}
+
+impl PartialEq for JournalType {
+    fn eq(&self, other: &JournalType) -> bool {
+        match (self, other) {
+            (JournalType::Me1Le(journal), JournalType::Me1Le(other)) => journal == other,
+            (JournalType::Me2(journal), JournalType::Me2(other)) => journal == other,
+            (JournalType::Me3(journal), JournalType::Me3(other)) => journal == other,
+            _ => false,
+        }
+    }
+}
+
+pub enum Msg {
+    DatabaseLoaded(Databases),
+    RemoveQuest(usize),
+    MarkAllRead,
+    AddCodexEntry(i32),
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub journal: JournalType,
+    pub codex: RcRef<Codex>,
+}
+
+pub struct Journal {
+    _db_handle: ContextHandle<Databases>,
+    codex_db: Option<Rc<CodexDb>>,
+    generation: usize,
+}
+
+impl Component for Journal {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
+            .expect("no database provider");
+
+        Journal { _db_handle, codex_db: Self::codex_db(ctx, databases), generation: 0 }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.codex_db = Self::codex_db(ctx, dbs);
+                return true;
+            }
+            Msg::RemoveQuest(idx) => match ctx.props().journal {
+                JournalType::Me1Le(ref journal) | JournalType::Me2(ref journal) => {
+                    journal.borrow_mut().remove_quest(idx)
+                }
+                JournalType::Me3(ref journal) => journal.borrow_mut().remove_quest(idx),
+            },
+            Msg::MarkAllRead => ctx.props().codex.borrow().mark_all_read(),
+            Msg::AddCodexEntry(id) => {
+                if let Some(entry) = self.codex_db.as_ref().and_then(|codex_db| codex_db.get(&id)) {
+                    ctx.props().codex.borrow_mut().add_entry(id, entry.pages.keys().copied());
+                }
+            }
+        }
+        // Props are compared by pointer, remount the lists after an action
+        self.generation += 1;
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let quests = match ctx.props().journal {
+            JournalType::Me1Le(ref journal) | JournalType::Me2(ref journal) => {
+                let journal = journal.borrow();
+                let (ids, progress) = (journal.quest_ids(), journal.quest_progress());
+                ids.iter()
+                    .zip(progress.iter())
+                    .enumerate()
+                    .map(|(idx, (id, quest))| {
+                        let quest = quest.borrow();
+                        self.quest(ctx, idx, id.get(), &quest.quest_updated, None, &quest.history)
+                    })
+                    .collect::<Html>()
+            }
+            JournalType::Me3(ref journal) => {
+                let journal = journal.borrow();
+                let (ids, progress) = (journal.quest_ids(), journal.quest_progress());
+                ids.iter()
+                    .zip(progress.iter())
+                    .enumerate()
+                    .map(|(idx, (id, quest))| {
+                        let quest = quest.borrow();
+                        self.quest(
+                            ctx,
+                            idx,
+                            id.get(),
+                            &quest.quest_updated,
+                            Some(&quest.active_goal),
+                            &quest.history,
+                        )
+                    })
+                    .collect::<Html>()
+            }
+        };
+
+        let codex = ctx.props().codex.borrow();
+        let (ids, codex_entries) = (codex.codex_ids(), codex.codex_entries());
+        let codex_db = self.codex_db.as_deref();
+        let entries = ids.iter().zip(codex_entries.iter()).map(|(id, entry)| {
+            let entry = entry.borrow();
+            let pages = entry.pages();
+            let new = pages.iter().filter(|page| page.borrow().is_new()).count();
+            let name = codex_db
+                .and_then(|codex_db| codex_db.get(&id.get()))
+                .map(|db_entry| db_entry.name.clone())
+                .unwrap_or_else(|| format!("Entry {}", id.get()));
+            html! {
+                <div class="flex items-center gap-2 cursor-default">
+                    <span class="flex-1 min-w-0 truncate">{ name }</span>
+                    { format!("{} pages, {} new", pages.len(), new) }
+                </div>
+            }
+        });
+
+        // Entries of the database the save lacks, or lacks pages of
+        let missing = codex_db.map(|codex_db| {
+            let missing = codex_db.iter().filter_map(|(&id, db_entry)| {
+                let idx = ids.iter().position(|codex_id| codex_id.get() == id);
+                let missing_pages = match idx {
+                    Some(idx) => {
+                        let entry = codex_entries[idx].borrow();
+                        let pages = entry.pages();
+                        let has_page = |page: i32| pages.iter().any(|p| p.borrow().page() == page);
+                        db_entry.pages.keys().filter(|&&page| !has_page(page)).count()
+                    }
+                    None => db_entry.pages.len(),
+                };
+                (missing_pages > 0).then(|| {
+                    html! {
+                        <div class="flex items-center gap-2 cursor-default">
+                            <span class="flex-1 min-w-0 truncate">{ &db_entry.name }</span>
+                            { format!("{} pages missing", missing_pages) }
+                            <button class="button" onclick={ctx.link().callback(move |_| Msg::AddCodexEntry(id))}>
+                                {"Add"}
+                            </button>
+                        </div>
+                    }
+                })
+            });
+            html! {
+                <Table title="Missing codex entries" opened=false helper=
+                    "Adds the entry and its missing pages as new.\n\
+                    The plots of the pages are not set, use the Plot tab to set them."
+                >
+                    <div key={self.generation} class="flex flex-col gap-1">
+                        { for missing }
+                    </div>
+                </Table>
+            }
+        });
+
+        html! {
+            <div class="flex divide-solid divide-x divide-default-border">
+                <div class="flex-1 pr-1 flex flex-col gap-1 min-w-0">
+                    <Table title="Quests" helper=
+                        "Whether a quest is done is read from the plot, the journal only keeps what was shown to the player.\n\
+                        Use the Plot tab to change the outcome of a quest."
+                    >
+                        <div key={self.generation} class="flex flex-col gap-1">
+                            { quests }
+                        </div>
+                    </Table>
+                </div>
+                <div class="flex-1 pl-1 flex flex-col gap-1 min-w-0">
+                    <Table title="Codex">
+                        <div class="flex items-center gap-1 cursor-default">
+                            <button class="button" onclick={ctx.link().callback(|_| Msg::MarkAllRead)}>
+                                {"Mark all read"}
+                            </button>
+                        </div>
+                        <div key={self.generation} class="flex flex-col gap-1">
+                            { for entries }
+                        </div>
+                    </Table>
+                    { for missing }
+                </div>
+            </div>
+        }
+    }
+}
+
+impl Journal {
+    fn codex_db(ctx: &Context<Self>, dbs: Databases) -> Option<Rc<CodexDb>> {
+        match ctx.props().journal {
+            JournalType::Me1Le(_) => dbs.get_me1_le_codex(),
+            JournalType::Me2(_) => dbs.get_me2_codex(),
+            JournalType::Me3(_) => dbs.get_me3_codex(),
+        }
+    }
+
+    fn quest(
+        &self, ctx: &Context<Self>, idx: usize, id: i32, quest_updated: &RcCell<bool>,
+        active_goal: Option<&RcCell<i32>>, history: &RcRef<Vec<RcCell<i32>>>,
+    ) -> Html {
+        let active_goal = active_goal.map(|active_goal| active_goal.view("Active goal"));
+
+        html! {
+            <Table title={format!("Quest {}", id)} opened=false>
+                <CheckBox label="Updated" value={RcCell::clone(quest_updated)} />
+                { for active_goal }
+                { history.view("History") }
+                <div class="flex items-center gap-1">
+                    <button class="button" onclick={ctx.link().callback(move |_| Msg::RemoveQuest(idx))}>
+                        {"Remove"}
+                    </button>
+                </div>
+            </Table>
+        }
+    }
+}
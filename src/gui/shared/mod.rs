@@ -1,10 +1,17 @@
+mod appearance;
 mod bonus_powers;
 mod head_morph;
+mod journal;
 mod link;
 mod plot_category;
 mod raw_plot;
+mod set_level;
+mod squad;
 
-pub use self::{bonus_powers::*, head_morph::*, link::*, plot_category::*, raw_plot::*};
+pub use self::{
+    appearance::*, bonus_powers::*, head_morph::*, journal::*, link::*, plot_category::*,
+    raw_plot::*, set_level::*, squad::*,
+};
 
 use indexmap::IndexMap;
 use yew::prelude::*;
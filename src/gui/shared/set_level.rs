@@ -0,0 +1,78 @@
+use yew::prelude::*;
+
+use crate::{
+    gui::components::{CheckBox, InputNumber, NumberType},
+    save_data::RcCell,
+};
+
+pub enum Msg {
+    SetLevel,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub level: i32,
+    pub max_level: i32,
+    pub warning: Option<String>,
+    pub onset: Callback<(i32, bool)>,
+}
+
+pub struct SetLevel {
+    level: RcCell<i32>,
+    with_squad: RcCell<bool>,
+}
+
+impl Component for SetLevel {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let level = ctx.props().level.clamp(1, ctx.props().max_level);
+        SetLevel { level: level.into(), with_squad: Default::default() }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::SetLevel => {
+                let level = self.level.get().clamp(1, ctx.props().max_level);
+                self.level.set(level);
+                ctx.props().onset.emit((level, self.with_squad.get()));
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Props { max_level, warning, .. } = ctx.props();
+
+        let warning = warning.as_ref().map(|warning| {
+            html! {
+                <div class="flex items-center gap-1 cursor-default">
+                    { format!("Inconsistent level: {}", warning) }
+                </div>
+            }
+        });
+
+        html! {
+            <>
+                { for warning }
+                <InputNumber
+                    label={format!("New Level (1 - {})", max_level)}
+                    value={NumberType::Int(RcCell::clone(&self.level))}
+                    helper=
+                        "Sets the level, the XP to the start of this level and adds or removes \
+                        the talent points earned between the two levels.\n\
+                        If you lower the level and the talent points can't be removed, \
+                        the talents are reset first.\n\
+                        With `Squad` checked, the squad mates get the same level and their talent points."
+                />
+                <div class="flex items-center gap-1 cursor-default">
+                    <button class="button" onclick={ctx.link().callback(|_| Msg::SetLevel)}>
+                        { "Set level" }
+                    </button>
+                    <CheckBox label="Squad" value={RcCell::clone(&self.with_squad)} />
+                </div>
+            </>
+        }
+    }
+}
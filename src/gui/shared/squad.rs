@@ -0,0 +1,326 @@
+use std::{
+    cell::{Ref, RefMut},
+    marker::PhantomData,
+    rc::Rc,
+};
+
+use yew::{context::ContextHandle, prelude::*};
+
+use crate::{
+    gui::components::{Helper, Select, Table},
+    save_data::{
+        shared::{
+            player_class_db::{ClassPower, DbClassPower},
+            squad_db::{DbHenchman, SquadDb},
+        },
+        RcRef,
+    },
+    services::database::Databases,
+};
+
+// The game stores `None` when no power is mapped
+const NO_POWER: &str = "None";
+
+// ME2 and ME3 squad mates, for the squad tab
+pub trait SquadMate: Clone + 'static {
+    type Power: ClassPower;
+
+    fn squad_db(databases: Databases) -> Option<Rc<SquadDb>>;
+    fn tag(&self) -> Ref<'_, String>;
+    fn powers(&self) -> Ref<'_, Vec<RcRef<Self::Power>>>;
+    fn powers_mut(&mut self) -> RefMut<'_, Vec<RcRef<Self::Power>>>;
+    fn mapped_power(&self) -> Ref<'_, String>;
+    fn mapped_power_mut(&mut self) -> RefMut<'_, String>;
+    fn talent_points(&self) -> i32;
+    fn set_talent_points(&mut self, talent_points: i32);
+
+    // Game specific rows of the `General` table
+    fn general(&self, onchange: Callback<()>) -> Html;
+    // Shown before the power name
+    fn power_rank(
+        character: &RcRef<Self>, power: &RcRef<Self::Power>, onchange: Callback<()>,
+    ) -> Html;
+    // Shown below the character
+    fn details(&self) -> Html;
+}
+
+pub enum Msg {
+    DatabaseLoaded(Databases),
+    Changed,
+    SelectCharacter(usize),
+    SelectNewPower(usize),
+    AddPower,
+    RemovePower(usize),
+    MappedPower(usize),
+}
+
+#[derive(Properties)]
+pub struct Props<H>
+where
+    H: SquadMate,
+{
+    pub squad: RcRef<Vec<RcRef<H>>>,
+}
+
+impl<H> PartialEq for Props<H>
+where
+    H: SquadMate,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.squad == other.squad
+    }
+}
+
+pub struct Squad<H>
+where
+    H: SquadMate,
+{
+    _marker: PhantomData<H>,
+    _db_handle: ContextHandle<Databases>,
+    squad_db: Option<Rc<SquadDb>>,
+    character_idx: usize,
+    new_power_idx: usize,
+}
+
+impl<H> Component for Squad<H>
+where
+    H: SquadMate,
+{
+    type Message = Msg;
+    type Properties = Props<H>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (databases, _db_handle) = ctx
+            .link()
+            .context::<Databases>(ctx.link().callback(Msg::DatabaseLoaded))
+            .expect("no database provider");
+
+        Squad {
+            _marker: PhantomData,
+            _db_handle,
+            squad_db: H::squad_db(databases),
+            character_idx: 0,
+            new_power_idx: 0,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::DatabaseLoaded(dbs) => {
+                self.squad_db = H::squad_db(dbs);
+                true
+            }
+            Msg::Changed => true,
+            Msg::SelectCharacter(idx) => {
+                self.character_idx = idx;
+                self.new_power_idx = 0;
+                true
+            }
+            Msg::SelectNewPower(idx) => {
+                self.new_power_idx = idx;
+                false
+            }
+            Msg::AddPower => {
+                if let Some(character) = self.selected_character(ctx) {
+                    let mut character = character.borrow_mut();
+                    let new_power = self
+                        .squad_db
+                        .as_ref()
+                        .and_then(|squad_db| squad_db.get(&*character.tag()))
+                        .and_then(|henchman| {
+                            Self::new_powers(henchman, &character)
+                                .get(self.new_power_idx)
+                                .map(|new_power| H::Power::from_db(new_power))
+                        });
+
+                    if let Some(power) = new_power {
+                        character.powers_mut().push(power.into());
+                        self.new_power_idx = 0;
+                    }
+                }
+                true
+            }
+            Msg::RemovePower(idx) => {
+                if let Some(character) = self.selected_character(ctx) {
+                    let mut character = character.borrow_mut();
+                    let power = character.powers_mut().remove(idx);
+                    let refund = power.borrow().spent_points();
+                    let talent_points = character.talent_points();
+                    character.set_talent_points(talent_points + refund);
+
+                    // Unmap the removed power if no other power has its name (evolved passives)
+                    let name = power.borrow().name().clone();
+                    let still_has_power =
+                        character.powers().iter().any(|power| *power.borrow().name() == name);
+                    if *character.mapped_power() == name && !still_has_power {
+                        *character.mapped_power_mut() = NO_POWER.to_owned();
+                    }
+                }
+                true
+            }
+            Msg::MappedPower(idx) => {
+                if let Some(character) = self.selected_character(ctx) {
+                    let mut character = character.borrow_mut();
+                    let mapped_power = match idx.checked_sub(1) {
+                        Some(idx) => character.powers()[idx].borrow().name().clone(),
+                        None => NO_POWER.to_owned(),
+                    };
+                    *character.mapped_power_mut() = mapped_power;
+                }
+                true
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if let Some(ref squad_db) = self.squad_db {
+            let squad = ctx.props().squad.borrow();
+            let names: Vec<_> = squad
+                .iter()
+                .map(|character| {
+                    let tag = character.borrow().tag().clone();
+                    squad_db.get(&tag).map(|henchman| henchman.name.clone()).unwrap_or(tag)
+                })
+                .collect();
+
+            let character = squad.get(self.character_idx).map(|character| {
+                // Props are compared by pointer, remount when the power list changes
+                let key = format!("{}-{}", self.character_idx, character.borrow().powers().len());
+                html! {
+                    <div {key} class="flex flex-col gap-1">
+                        { self.character(ctx, squad_db, character) }
+                        <hr class="border-t border-default-border" />
+                        { character.borrow().details() }
+                    </div>
+                }
+            });
+
+            html! {
+                <div class="flex flex-col gap-1">
+                    <div class="flex items-center gap-1 cursor-default">
+                        <Select
+                            options={names}
+                            current_idx={self.character_idx}
+                            onselect={ctx.link().callback(Msg::SelectCharacter)}
+                        />
+                        {"Squad mate"}
+                    </div>
+                    { for character }
+                </div>
+            }
+        } else {
+            html! {
+                <>
+                    <p>{ "Loading database..." }</p>
+                    <hr class="border-t border-default-border" />
+                </>
+            }
+        }
+    }
+}
+
+impl<H> Squad<H>
+where
+    H: SquadMate,
+{
+    fn selected_character(&self, ctx: &Context<Self>) -> Option<RcRef<H>> {
+        ctx.props().squad.borrow().get(self.character_idx).cloned()
+    }
+
+    // Powers of the database the character doesn't have yet
+    fn new_powers<'a>(henchman: &'a DbHenchman, character: &H) -> Vec<&'a DbClassPower> {
+        let powers = character.powers();
+        henchman
+            .powers
+            .iter()
+            .filter(|new_power| {
+                !powers.iter().any(|power| {
+                    power
+                        .borrow()
+                        .power_class_name()
+                        .eq_ignore_ascii_case(&new_power.power_class_name)
+                })
+            })
+            .collect()
+    }
+
+    fn character(&self, ctx: &Context<Self>, squad_db: &SquadDb, character: &RcRef<H>) -> Html {
+        let onchange = ctx.link().callback(|_| Msg::Changed);
+        let power_list: Vec<_> = character
+            .borrow()
+            .powers()
+            .iter()
+            .enumerate()
+            .map(|(idx, power)| {
+                html! {
+                    <div class="flex items-center gap-1 cursor-default">
+                        { H::power_rank(character, power, onchange.clone()) }
+                        { power.borrow().name().clone() }
+                        <button class="button ml-auto" onclick={ctx.link().callback(move |_| Msg::RemovePower(idx))}>
+                            {"Remove"}
+                        </button>
+                    </div>
+                }
+            })
+            .collect();
+
+        let character = character.borrow();
+        let powers = character.powers();
+
+        let mapped_power_idx = powers
+            .iter()
+            .position(|power| *power.borrow().name() == *character.mapped_power())
+            .map(|idx| idx + 1)
+            .unwrap_or_default();
+        let mapped_power_options: Vec<_> = std::iter::once(NO_POWER.to_owned())
+            .chain(powers.iter().map(|power| power.borrow().name().clone()))
+            .collect();
+
+        let new_powers = squad_db.get(&*character.tag()).and_then(|henchman| {
+            let options: Vec<_> = Self::new_powers(henchman, &character)
+                .into_iter()
+                .map(|new_power| new_power.name.clone())
+                .collect();
+            (!options.is_empty()).then(|| {
+                html! {
+                    <div class="flex items-center gap-1">
+                        <Select
+                            {options}
+                            current_idx={self.new_power_idx}
+                            onselect={ctx.link().callback(Msg::SelectNewPower)}
+                            sized=false
+                        />
+                        <button class="button ml-auto" onclick={ctx.link().callback(|_| Msg::AddPower)}>
+                            {"Add"}
+                        </button>
+                    </div>
+                }
+            })
+        });
+
+        html! {
+            <div class="flex divide-solid divide-x divide-default-border">
+                <div class="flex-1 pr-1 flex flex-col gap-1 min-w-0">
+                    <Table title="General">
+                        { character.general(onchange) }
+                        <div class="flex items-center gap-1 cursor-default">
+                            <Select
+                                options={mapped_power_options}
+                                current_idx={mapped_power_idx}
+                                onselect={ctx.link().callback(Msg::MappedPower)}
+                            />
+                            {"Mapped power"}
+                            <Helper text="Power mapped to this squad mate's shortcut." />
+                        </div>
+                    </Table>
+                </div>
+                <div class="flex-1 pl-1 flex flex-col gap-1 min-w-0">
+                    <Table title="Power list">
+                        { for power_list }
+                        { for new_powers }
+                    </Table>
+                </div>
+            </div>
+        }
+    }
+}
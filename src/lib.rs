@@ -0,0 +1,14 @@
+#![warn(clippy::all)]
+
+#[macro_use]
+extern crate derive_more;
+
+#[macro_use]
+extern crate macros;
+
+mod gui;
+pub mod save_data;
+mod services;
+pub mod unreal;
+
+pub use gui::App;
@@ -1,17 +1,6 @@
 #![warn(clippy::all)]
 
-#[macro_use]
-extern crate derive_more;
-
-#[macro_use]
-extern crate macros;
-
-mod gui;
-mod save_data;
-mod services;
-mod unreal;
-
-use gui::App;
+use trilogy_save_editor::App;
 
 fn main() {
     let document = gloo::utils::document();
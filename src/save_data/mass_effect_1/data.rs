@@ -1,5 +1,5 @@
 use anyhow::Result;
-use serde::{de, Serialize};
+use serde::{de, de::DeserializeOwned, Serialize};
 
 use super::{player::Name, List};
 use crate::{
@@ -7,7 +7,7 @@ use crate::{
         shared::{appearance::LinearColor, Rotator, Vector},
         Dummy,
     },
-    save_data::{RcCell, RcRef},
+    save_data::{RcCell, RcRef, SeqAccessExt},
     unreal,
 };
 
@@ -22,11 +22,15 @@ impl Data {
     where
         A: de::SeqAccess<'de>,
     {
-        let _osef = seq.next_element()?.unwrap();
+        let _osef = seq.next_required()?;
         let properties = List::<RcRef<Property>>::visit_seq(names, seq)?;
         Ok(Self { _osef, properties })
     }
 
+    pub fn duplicate(&self) -> Result<Self> {
+        Ok(Self { _osef: self._osef.clone(), properties: self.properties.duplicate()? })
+    }
+
     pub fn size(&self) -> Result<usize> {
         let mut size = 4;
         for property in self.properties.iter() {
@@ -36,8 +40,17 @@ impl Data {
     }
 }
 
-fn get_name(names: &[Name], id: u32) -> String {
-    names[id as usize].string.borrow().clone()
+fn get_name<E: de::Error>(names: &[Name], id: u32) -> Result<String, E> {
+    names
+        .get(id as usize)
+        .map(|name| name.string.borrow().clone())
+        .ok_or_else(|| E::custom(format!("unknown name id: {}", id)))
+}
+
+// The fields of a `#[rcize_fields]` struct are shared by `clone()`, a copy goes through the bytes
+fn copy_rcized<T: Serialize + DeserializeOwned>(value: &RcRef<T>) -> Result<RcRef<T>> {
+    let bytes = unreal::Serializer::to_vec(value)?;
+    Ok(unreal::Deserializer::from_bytes(&bytes)?)
 }
 
 impl List<RcRef<Property>> {
@@ -60,6 +73,14 @@ impl List<RcRef<Property>> {
 
         Ok(properties.into())
     }
+
+    pub fn duplicate(&self) -> Result<Self> {
+        let mut properties = Vec::new();
+        for property in self.iter() {
+            properties.push(property.borrow().duplicate()?.into());
+        }
+        Ok(properties.into())
+    }
 }
 
 #[derive(Serialize, Clone)]
@@ -169,31 +190,31 @@ impl Property {
         A: de::SeqAccess<'de>,
     {
         // Name
-        let name_id = seq.next_element()?.unwrap();
-        let _osef1 = seq.next_element()?.unwrap();
+        let name_id = seq.next_required()?;
+        let _osef1 = seq.next_required()?;
 
-        let name = get_name(names, name_id);
+        let name = get_name(names, name_id)?;
         if name == "None" {
             return Ok(Property::None { name_id, _osef: _osef1 });
         }
 
         // Type
-        let type_id = seq.next_element()?.unwrap();
-        let _osef2 = seq.next_element()?.unwrap();
+        let type_id = seq.next_required()?;
+        let _osef2 = seq.next_required()?;
         // Size
-        let size = seq.next_element()?.unwrap();
-        let _osef3 = seq.next_element()?.unwrap();
+        let size = seq.next_required()?;
+        let _osef3 = seq.next_required()?;
 
-        let type_name = get_name(names, type_id);
+        let type_name = get_name(names, type_id)?;
         let property = match type_name.as_str() {
             "ArrayProperty" => {
-                let len: u32 = seq.next_element()?.unwrap();
+                let len: u32 = seq.next_required()?;
                 let mut array = Vec::new();
                 // Hardcodé sinon je dois chercher dans toutes les classes du jeu...
                 match name.as_str() {
                     "m_PrereqTalentIDArray" | "m_PrereqTalentRankArray" => {
                         for _ in 0..len {
-                            let array_int = ArrayType::Int(seq.next_element()?.unwrap());
+                            let array_int = ArrayType::Int(seq.next_required()?);
                             array.push(array_int);
                         }
                     }
@@ -203,19 +224,19 @@ impl Property {
                     | "m_QuickSlotArray"
                     | "m_savedBuybackItems" => {
                         for _ in 0..len {
-                            let array_object = ArrayType::Object(seq.next_element()?.unwrap());
+                            let array_object = ArrayType::Object(seq.next_required()?);
                             array.push(array_object);
                         }
                     }
                     "m_vPosition" => {
                         for _ in 0..len {
-                            let array_vector = ArrayType::Vector(seq.next_element()?.unwrap());
+                            let array_vector = ArrayType::Vector(seq.next_required()?);
                             array.push(array_vector);
                         }
                     }
                     "m_DependentPackages" => {
                         for _ in 0..len {
-                            let array_string = ArrayType::String(seq.next_element()?.unwrap());
+                            let array_string = ArrayType::String(seq.next_required()?);
                             array.push(array_string);
                         }
                     }
@@ -231,16 +252,16 @@ impl Property {
                 Property::Array { name_id, _osef1, type_id, _osef2, size, _osef3, array }
             }
             "BoolProperty" => {
-                let value = seq.next_element()?.unwrap();
+                let value = seq.next_required()?;
                 Property::Bool { name_id, _osef1, type_id, _osef2, size, _osef3, value }
             }
             "ByteProperty" => {
                 if size == 1 {
-                    let value = seq.next_element()?.unwrap();
+                    let value = seq.next_required()?;
                     Property::Byte { name_id, _osef1, type_id, _osef2, size, _osef3, value }
                 } else {
-                    let value_name_id = seq.next_element()?.unwrap();
-                    let _osef4 = seq.next_element()?.unwrap();
+                    let value_name_id = seq.next_required()?;
+                    let _osef4 = seq.next_required()?;
                     Property::Name {
                         name_id,
                         _osef1,
@@ -254,16 +275,16 @@ impl Property {
                 }
             }
             "FloatProperty" => {
-                let value = seq.next_element()?.unwrap();
+                let value = seq.next_required()?;
                 Property::Float { name_id, _osef1, type_id, _osef2, size, _osef3, value }
             }
             "IntProperty" => {
-                let value = seq.next_element()?.unwrap();
+                let value = seq.next_required()?;
                 Property::Int { name_id, _osef1, type_id, _osef2, size, _osef3, value }
             }
             "NameProperty" => {
-                let value_name_id = seq.next_element()?.unwrap();
-                let _osef4 = seq.next_element()?.unwrap();
+                let value_name_id = seq.next_required()?;
+                let _osef4 = seq.next_required()?;
                 Property::Name {
                     name_id,
                     _osef1,
@@ -276,26 +297,26 @@ impl Property {
                 }
             }
             "ObjectProperty" => {
-                let object_id = seq.next_element()?.unwrap();
+                let object_id = seq.next_required()?;
                 Property::Object { name_id, _osef1, type_id, _osef2, size, _osef3, object_id }
             }
             "StrProperty" => {
-                let string = seq.next_element()?.unwrap();
+                let string = seq.next_required()?;
                 Property::Str { name_id, _osef1, type_id, _osef2, size, _osef3, string }
             }
             "StringRefProperty" => {
-                let value = seq.next_element()?.unwrap();
+                let value = seq.next_required()?;
                 Property::StringRef { name_id, _osef1, type_id, _osef2, size, _osef3, value }
             }
             "StructProperty" => {
-                let struct_name_id = seq.next_element()?.unwrap();
-                let _osef4 = seq.next_element()?.unwrap();
+                let struct_name_id = seq.next_required()?;
+                let _osef4 = seq.next_required()?;
 
-                let struct_name = get_name(names, struct_name_id);
+                let struct_name = get_name(names, struct_name_id)?;
                 let struct_type = match struct_name.as_str() {
-                    "LinearColor" => StructType::LinearColor(seq.next_element()?.unwrap()),
-                    "Vector" => StructType::Vector(seq.next_element()?.unwrap()),
-                    "Rotator" => StructType::Rotator(seq.next_element()?.unwrap()),
+                    "LinearColor" => StructType::LinearColor(seq.next_required()?),
+                    "Vector" => StructType::Vector(seq.next_required()?),
+                    "Rotator" => StructType::Rotator(seq.next_required()?),
                     _ => StructType::Properties(List::<RcRef<Property>>::visit_seq(names, seq)?),
                 };
                 Property::Struct {
@@ -310,7 +331,7 @@ impl Property {
                     struct_type,
                 }
             }
-            _ => unimplemented!(),
+            _ => return Err(de::Error::custom(format!("unknown property type: {}", type_name))),
         };
         Ok(property)
     }
@@ -340,6 +361,47 @@ impl Property {
             Property::None { .. } => 8,
         })
     }
+
+    // The stored size of an array follows its elements, it must be updated after adding or
+    // removing some of them
+    pub fn update_array_size(&mut self) -> Result<()> {
+        if let Property::Array { size, array, .. } = self {
+            let mut new_size = 4;
+            for item in array.iter() {
+                new_size += item.size()?;
+            }
+            *size = new_size as u32;
+        }
+        Ok(())
+    }
+
+    // `clone()` shares the values with the original property, this copies them
+    pub fn duplicate(&self) -> Result<Self> {
+        let mut property = self.clone();
+        match property {
+            Property::Array { ref mut array, .. } => {
+                *array = array.iter().map(ArrayType::duplicate).collect::<Result<_>>()?
+            }
+            Property::Bool { ref mut value, .. } => *value = value.get().into(),
+            Property::Byte { ref mut value, .. } => *value = value.get().into(),
+            Property::Float { ref mut value, .. } => *value = value.get().into(),
+            Property::Int { ref mut value, .. } | Property::StringRef { ref mut value, .. } => {
+                *value = value.get().into()
+            }
+            Property::Name { ref mut value_name_id, .. } => {
+                *value_name_id = value_name_id.get().into()
+            }
+            Property::Str { ref mut string, .. } => {
+                let copy = string.borrow().clone();
+                *string = copy.into()
+            }
+            Property::Struct { ref mut struct_type, .. } => {
+                *struct_type = struct_type.duplicate()?
+            }
+            Property::Object { .. } | Property::None { .. } => (),
+        }
+        Ok(property)
+    }
 }
 
 #[derive(Serialize, Clone)]
@@ -352,6 +414,16 @@ pub enum ArrayType {
 }
 
 impl ArrayType {
+    fn duplicate(&self) -> Result<Self> {
+        Ok(match self {
+            ArrayType::Int(value) => ArrayType::Int(value.get().into()),
+            ArrayType::Object(object_id) => ArrayType::Object(*object_id),
+            ArrayType::Vector(vector) => ArrayType::Vector(copy_rcized(vector)?),
+            ArrayType::String(string) => ArrayType::String(string.borrow().clone().into()),
+            ArrayType::Properties(properties) => ArrayType::Properties(properties.duplicate()?),
+        })
+    }
+
     fn size(&self) -> Result<usize> {
         Ok(match self {
             ArrayType::Int(_) => 4,
@@ -381,6 +453,17 @@ pub enum StructType {
 }
 
 impl StructType {
+    fn duplicate(&self) -> Result<Self> {
+        Ok(match self {
+            StructType::LinearColor(color) => {
+                StructType::LinearColor(color.borrow().clone().into())
+            }
+            StructType::Vector(vector) => StructType::Vector(copy_rcized(vector)?),
+            StructType::Rotator(rotator) => StructType::Rotator(copy_rcized(rotator)?),
+            StructType::Properties(properties) => StructType::Properties(properties.duplicate()?),
+        })
+    }
+
     fn size(&self) -> Result<usize> {
         Ok(match self {
             StructType::LinearColor(_) => 16,
@@ -2,6 +2,7 @@ pub mod data;
 pub mod player;
 pub mod plot_db;
 pub mod state;
+pub mod talents;
 
 use self::{player::*, state::*};
 
@@ -11,10 +12,10 @@ use std::io::{Cursor, Read, Write};
 use anyhow::Result;
 use serde::de;
 use serde::ser::SerializeTupleStruct;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
 use zip::{write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
 
-use super::{Dummy, List};
+use super::{Dummy, List, SeqAccessExt};
 use crate::unreal;
 
 #[rcize_fields]
@@ -33,32 +34,25 @@ impl Me1SaveGame {
     fn unzip(input: &[u8]) -> Result<(Player, State, Option<WorldSavePackage>)> {
         let mut zip = ZipArchive::new(Cursor::new(input))?;
 
-        let player: Player = {
-            let mut bytes = Vec::new();
-            zip.by_name("player.sav")?.read_to_end(&mut bytes)?;
-            unreal::Deserializer::from_bytes(&bytes)?
+        let player = Self::unzip_file(&mut zip, "player.sav")?;
+        let state = Self::unzip_file(&mut zip, "state.sav")?;
+        let world_save_package = if zip.file_names().any(|f| f == "WorldSavePackage.sav") {
+            Some(Self::unzip_file(&mut zip, "WorldSavePackage.sav")?)
+        } else {
+            None
         };
 
-        let state: State = {
-            let mut bytes = Vec::new();
-            zip.by_name("state.sav")?.read_to_end(&mut bytes)?;
-            unreal::Deserializer::from_bytes(&bytes)?
-        };
-
-        let world_save_package: Option<WorldSavePackage> =
-            if zip.file_names().any(|f| f == "WorldSavePackage.sav") {
-                Some({
-                    let mut bytes = Vec::new();
-                    zip.by_name("WorldSavePackage.sav")?.read_to_end(&mut bytes)?;
-                    unreal::Deserializer::from_bytes(&bytes)?
-                })
-            } else {
-                None
-            };
-
         Ok((player, state, world_save_package))
     }
 
+    fn unzip_file<T: DeserializeOwned>(
+        zip: &mut ZipArchive<Cursor<&[u8]>>, file_name: &str,
+    ) -> Result<T> {
+        let mut bytes = Vec::new();
+        zip.by_name(file_name)?.read_to_end(&mut bytes)?;
+        Ok(unreal::Deserializer::from_bytes(&bytes)?)
+    }
+
     fn zip(&self) -> Result<List<u8>> {
         let mut zip = Vec::new();
         {
@@ -105,17 +99,20 @@ impl<'de> Deserialize<'de> for Me1SaveGame {
             where
                 A: de::SeqAccess<'de>,
             {
-                let magic_number = seq.next_element()?.unwrap();
-                let begin = seq.next_element()?.unwrap();
-                let zip_offset = seq.next_element()?.unwrap();
+                let magic_number = seq.next_required()?;
+                let begin = seq.next_required()?;
+                let zip_offset: u32 = seq.next_required()?;
 
                 // No man's land
                 let mut no_mans_land = Vec::new();
-                for _ in 0..(zip_offset - 12) {
-                    no_mans_land.push(seq.next_element()?.unwrap());
+                let no_mans_land_len = zip_offset
+                    .checked_sub(12)
+                    .ok_or_else(|| de::Error::custom("invalid zip offset"))?;
+                for _ in 0..no_mans_land_len {
+                    no_mans_land.push(seq.next_required()?);
                 }
 
-                let zip_data: List<u8> = seq.next_element()?.unwrap();
+                let zip_data: List<u8> = seq.next_required()?;
                 let (player, state, world_save_package) =
                     Me1SaveGame::unzip(&zip_data).map_err(de::Error::custom)?;
 
@@ -134,6 +131,55 @@ impl<'de> Deserialize<'de> for Me1SaveGame {
     }
 }
 
+// `Me1SaveGame` without the world save package, enough to list a save without reading it all
+pub struct Me1SaveHeader {
+    pub player: Player,
+    pub state: State,
+}
+
+impl<'de> Deserialize<'de> for Me1SaveHeader {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Me1SaveHeaderVisitor;
+        impl<'de> de::Visitor<'de> for Me1SaveHeaderVisitor {
+            type Value = Me1SaveHeader;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a Me1SaveHeader")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                let _: Me1MagicNumber = seq.next_required()?;
+                let _: Dummy<4> = seq.next_required()?;
+                let zip_offset: u32 = seq.next_required()?;
+
+                // No man's land
+                let no_mans_land_len = zip_offset
+                    .checked_sub(12)
+                    .ok_or_else(|| de::Error::custom("invalid zip offset"))?;
+                for _ in 0..no_mans_land_len {
+                    let _: u8 = seq.next_required()?;
+                }
+
+                let zip_data: List<u8> = seq.next_required()?;
+                let read_header = || -> Result<Me1SaveHeader> {
+                    let mut zip = ZipArchive::new(Cursor::new(&zip_data[..]))?;
+                    let player = Me1SaveGame::unzip_file(&mut zip, "player.sav")?;
+                    let state = Me1SaveGame::unzip_file(&mut zip, "state.sav")?;
+                    Ok(Me1SaveHeader { player, state })
+                };
+                read_header().map_err(de::Error::custom)
+            }
+        }
+        deserializer.deserialize_tuple_struct("Me1SaveHeader", usize::MAX, Me1SaveHeaderVisitor)
+    }
+}
+
 impl serde::Serialize for Me1SaveGame {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
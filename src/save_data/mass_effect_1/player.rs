@@ -5,9 +5,12 @@ use serde::de;
 use serde::ser::SerializeTupleStruct;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use super::{data::Data, List};
+use super::{
+    data::{ArrayType, Data, Property},
+    List,
+};
 use crate::save_data::Dummy;
-use crate::save_data::RcRef;
+use crate::save_data::{RcRef, SeqAccessExt};
 use crate::unreal;
 
 #[derive(Clone)]
@@ -39,6 +42,99 @@ impl Player {
     pub fn get_data(&self, i: i32) -> &Data {
         &self.datas[i as usize - 1]
     }
+
+    pub fn get_data_mut(&mut self, i: i32) -> &mut Data {
+        &mut self.datas[i as usize - 1]
+    }
+
+    // Finds a name or adds it at the end of the names
+    pub fn name_id(&self, name: &str) -> u32 {
+        let mut names = self.names.borrow_mut();
+        let found =
+            names.iter().position(|other| !other.is_duplicate && *other.string.borrow() == name);
+        if let Some(id) = found {
+            return id as u32;
+        }
+
+        names.push(Name::new(name.to_owned()));
+        (names.len() - 1) as u32
+    }
+
+    pub fn current_game(&self) -> Option<&Data> {
+        self.objects.iter().enumerate().find_map(|(i, object)| {
+            let object_name = self.get_name(object.object_name_id);
+            (object_name == "CurrentGame").then(|| self.get_data(i as i32 + 1))
+        })
+    }
+
+    pub fn find_property<'a>(
+        &self, properties: &'a List<RcRef<Property>>, property_name: &str,
+    ) -> Option<&'a RcRef<Property>> {
+        properties.iter().find_map(|property| match *property.borrow() {
+            Property::Array { name_id, .. }
+            | Property::Bool { name_id, .. }
+            | Property::Byte { name_id, .. }
+            | Property::Float { name_id, .. }
+            | Property::Int { name_id, .. }
+            | Property::Name { name_id, .. }
+            | Property::Object { name_id, .. }
+            | Property::Str { name_id, .. }
+            | Property::StringRef { name_id, .. }
+            | Property::Struct { name_id, .. }
+            | Property::None { name_id, .. } => {
+                (self.get_name(name_id) == property_name).then(|| property)
+            }
+        })
+    }
+
+    pub fn find_object_id(
+        &self, properties: &List<RcRef<Property>>, property_name: &str,
+    ) -> Option<i32> {
+        self.find_property(properties, property_name).and_then(|property| {
+            match *property.borrow() {
+                Property::Object { object_id, .. } => Some(object_id),
+                _ => None,
+            }
+        })
+    }
+
+    // Copies an item at the end of the objects and returns the id of the copy.
+    // The upgrades are objects owned by the original item, the copy gets empty upgrade slots.
+    pub fn duplicate_item(&mut self, id: i32) -> Result<i32> {
+        let mut object = self.get_object(id).clone();
+        // The objects are named `name_number`, the copy needs a free number
+        object.value_id = self
+            .objects
+            .iter()
+            .filter(|other| other.object_name_id == object.object_name_id)
+            .map(|other| other.value_id)
+            .max()
+            .unwrap_or_default()
+            + 1;
+        // No parent object for an item of the inventory
+        object.link_id = 0;
+
+        let data = self.get_data(id).duplicate()?;
+        if let Some(slot_spec) = self.find_property(&data.properties, "m_aSlotSpec") {
+            if let Property::Array { ref array, .. } = *slot_spec.borrow() {
+                for slot in array {
+                    let xmods = match slot {
+                        ArrayType::Properties(slot) => self.find_property(slot, "m_aXMod"),
+                        _ => None,
+                    };
+                    if let Some(xmods) = xmods {
+                        if let Property::Array { ref mut array, .. } = *xmods.borrow_mut() {
+                            array.iter_mut().for_each(|xmod| *xmod = ArrayType::Object(0));
+                        }
+                    }
+                }
+            }
+        }
+
+        self.objects.push(object);
+        self.datas.push(data);
+        Ok(self.objects.len() as i32)
+    }
 }
 
 impl<'de> Deserialize<'de> for Player {
@@ -58,39 +154,46 @@ impl<'de> Deserialize<'de> for Player {
             where
                 A: de::SeqAccess<'de>,
             {
-                let begin = seq.next_element()?.unwrap();
-                let header_offset = seq.next_element()?.unwrap();
+                let begin = seq.next_required()?;
+                let header_offset: u32 = seq.next_required()?;
 
                 // No man's land 1
                 let mut no_mans_land1 = Vec::new();
-                for _ in 0..(header_offset - 12) {
-                    no_mans_land1.push(seq.next_element()?.unwrap());
+                let no_mans_land1_len = header_offset
+                    .checked_sub(12)
+                    .ok_or_else(|| de::Error::custom("invalid header offset"))?;
+                for _ in 0..no_mans_land1_len {
+                    no_mans_land1.push(seq.next_required()?);
                 }
 
-                let header: Header = seq.next_element()?.unwrap();
+                let header: Header = seq.next_required()?;
 
                 // Names
                 let mut names = Vec::new();
                 for _ in 0..header.name_len {
-                    names.push(seq.next_element()?.unwrap());
+                    names.push(seq.next_required()?);
                 }
 
                 // Imports
                 let mut classes = Vec::new();
                 for _ in 0..header.classes_len {
-                    classes.push(seq.next_element()?.unwrap());
+                    classes.push(seq.next_required()?);
                 }
 
                 // Objects
                 let mut objects = Vec::new();
                 for _ in 0..header.objects_len {
-                    objects.push(seq.next_element()?.unwrap());
+                    objects.push(seq.next_required()?);
                 }
 
                 // No man's land 2
                 let mut no_mans_land2 = Vec::new();
-                for _ in 0..(header.data_offset - header.no_mans_land_offset) {
-                    no_mans_land2.push(seq.next_element()?.unwrap());
+                let no_mans_land2_len = header
+                    .data_offset
+                    .checked_sub(header.no_mans_land_offset)
+                    .ok_or_else(|| de::Error::custom("invalid data offset"))?;
+                for _ in 0..no_mans_land2_len {
+                    no_mans_land2.push(seq.next_required()?);
                 }
 
                 // Data
@@ -140,6 +243,7 @@ impl serde::Serialize for Player {
         let mut header = header.clone();
 
         header.name_len = names.len() as u32;
+        header.objects_len = objects.len() as u32;
         header.classes_offset = header.name_offset;
         for name in names.iter() {
             header.classes_offset += name.size().map_err(Error::custom)? as u32;
@@ -203,6 +307,13 @@ pub struct Name {
 }
 
 impl Name {
+    // The flags of every name in the saves
+    const FLAGS: [u8; 8] = [0, 0, 0, 0, 16, 0, 7, 0];
+
+    fn new(string: String) -> Self {
+        Name { string: RcRef::new(string), _osef: Dummy(Self::FLAGS), is_duplicate: false }
+    }
+
     fn size(&self) -> Result<usize> {
         let bytes = unreal::Serializer::to_vec(&self.string)?;
         Ok(bytes.len() + 8)
@@ -243,6 +354,7 @@ mod test {
     use zip::ZipArchive;
 
     use super::*;
+    use crate::save_data::RcCell;
     use crate::unreal;
 
     #[test]
@@ -279,4 +391,89 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn duplicate_remove_item() -> Result<()> {
+        let input = fs::read("test/ME1Save.MassEffectSave")?;
+
+        let player_data = {
+            let mut offset_bytes = [0; 4];
+            offset_bytes.copy_from_slice(&input[8..12]);
+            let zip_offset = <u32>::from_le_bytes(offset_bytes);
+            let mut zip = ZipArchive::new(Cursor::new(&input[zip_offset as usize..]))?;
+
+            let mut bytes = Vec::new();
+            zip.by_name("player.sav")?.read_to_end(&mut bytes)?;
+            bytes
+        };
+
+        let mut player: Player = unreal::Deserializer::from_bytes(&player_data)?;
+        let objects_len = player.objects.len();
+
+        let inventory = |player: &Player| -> Option<RcRef<Property>> {
+            let current_game = player.current_game()?;
+            let m_player =
+                player.get_data(player.find_object_id(&current_game.properties, "m_Player")?);
+            let m_squad = player.get_data(player.find_object_id(&m_player.properties, "m_Squad")?);
+            let m_inventory =
+                player.get_data(player.find_object_id(&m_squad.properties, "m_Inventory")?);
+            player.find_property(&m_inventory.properties, "m_aItem").cloned()
+        };
+        let items = |m_a_item: &RcRef<Property>| -> Vec<i32> {
+            match *m_a_item.borrow() {
+                Property::Array { ref array, .. } => array
+                    .iter()
+                    .filter_map(|item| match item {
+                        ArrayType::Object(object_id) => Some(*object_id),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            }
+        };
+        let item_id = |player: &Player, object_id| -> Option<RcCell<i32>> {
+            let properties = &player.get_data(object_id).properties;
+            match *player.find_property(properties, "m_nID")?.borrow() {
+                Property::Int { ref value, .. } => Some(RcCell::clone(value)),
+                _ => None,
+            }
+        };
+
+        // A weapon with upgrade slots
+        let m_a_item = inventory(&player).unwrap();
+        let weapon = items(&m_a_item)[9];
+        assert_eq!(
+            player.get_name(player.get_object(weapon).object_name_id),
+            "BioItemXModdableSaveObject"
+        );
+
+        let copy = player.duplicate_item(weapon)?;
+        assert_eq!(copy as usize, objects_len + 1);
+        {
+            let mut m_a_item = m_a_item.borrow_mut();
+            if let Property::Array { ref mut array, .. } = *m_a_item {
+                array.push(ArrayType::Object(copy));
+                array.remove(0);
+            }
+            m_a_item.update_array_size()?;
+        }
+
+        // The copy doesn't share its values with the original
+        let original_id = item_id(&player, weapon).unwrap().get();
+        item_id(&player, copy).unwrap().set(original_id + 1);
+        assert_eq!(item_id(&player, weapon).unwrap().get(), original_id);
+
+        // Serialize
+        let output = unreal::Serializer::to_vec(&player)?;
+        let player: Player = unreal::Deserializer::from_bytes(&output)?;
+
+        assert_eq!(player.objects.len(), objects_len + 1);
+        let m_a_item = inventory(&player).unwrap();
+        let new_items = items(&m_a_item);
+        assert_eq!(new_items.len(), 104);
+        assert_eq!(new_items.last(), Some(&copy));
+        assert_eq!(item_id(&player, copy).unwrap().get(), original_id + 1);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,388 @@
+use anyhow::{anyhow, Result};
+
+use super::{
+    data::{ArrayType, Property},
+    player::Player,
+    List,
+};
+use crate::save_data::{
+    mass_effect_1_le::{
+        player::{ComplexTalent, SimpleTalent},
+        player_class_db::Me1LePlayerClass,
+    },
+    Dummy, RcCell, RcRef,
+};
+
+// Same order as `Me1LeClass`
+pub const CLASS_BASES: [&str; 6] = [
+    "BIO_PARTY_MEMBER_CLASS_BASE_SOLDIER",
+    "BIO_PARTY_MEMBER_CLASS_BASE_ENGINEER",
+    "BIO_PARTY_MEMBER_CLASS_BASE_ADEPT",
+    "BIO_PARTY_MEMBER_CLASS_BASE_INFILTRATOR",
+    "BIO_PARTY_MEMBER_CLASS_BASE_SENTINEL",
+    "BIO_PARTY_MEMBER_CLASS_BASE_VANGUARD",
+];
+
+impl Player {
+    // The behavior objects of the player and of the squad mates with their tag
+    pub fn behaviors(&self) -> Vec<(String, i32)> {
+        let current_game = match self.current_game() {
+            Some(current_game) => current_game,
+            None => return Vec::new(),
+        };
+
+        let player = self
+            .find_object_id(&current_game.properties, "m_Player")
+            .map(|object_id| (String::from("Player"), object_id));
+
+        let henchmen =
+            self.find_property(&current_game.properties, "m_Henchmen").map(|p| match *p.borrow() {
+                Property::Array { ref array, .. } => array
+                    .iter()
+                    .filter_map(|henchman| match henchman {
+                        ArrayType::Properties(henchman) => {
+                            let tag = match *self.find_property(henchman, "Tag")?.borrow() {
+                                Property::Name { ref value_name_id, .. } => {
+                                    self.get_name(value_name_id.get())
+                                }
+                                _ => return None,
+                            };
+                            let object_id = self.find_object_id(henchman, "Save")?;
+                            (tag.starts_with("hench_") && object_id > 0).then(|| (tag, object_id))
+                        }
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            });
+
+        player.into_iter().chain(henchmen.into_iter().flatten()).collect()
+    }
+
+    // The index of the class in `CLASS_BASES`, `None` for the squad mates' classes
+    pub fn class_base(&self, behavior: i32) -> Option<usize> {
+        let properties = &self.get_data(behavior).properties;
+        let class_base = match *self.find_property(properties, "m_ClassBase")?.borrow() {
+            Property::Name { ref value_name_id, .. } => self.get_name(value_name_id.get()),
+            _ => return None,
+        };
+        CLASS_BASES.iter().position(|&name| name == class_base)
+    }
+
+    // The talents share their values with the property tree, editing their ranks edits the save
+    pub fn simple_talents(&self, behavior: i32) -> Vec<RcRef<SimpleTalent>> {
+        self.talent_entries(behavior, "m_aSimpleTalents")
+            .iter()
+            .filter_map(|talent| {
+                Some(
+                    SimpleTalent {
+                        talent_id: self.find_int(talent, "m_TalentID")?,
+                        current_rank: self.find_int(talent, "m_Ranks")?,
+                    }
+                    .into(),
+                )
+            })
+            .collect()
+    }
+
+    pub fn complex_talents(&self, behavior: i32) -> Vec<RcRef<ComplexTalent>> {
+        self.talent_entries(behavior, "m_aComplexTalents")
+            .iter()
+            .filter_map(|talent| {
+                Some(
+                    ComplexTalent {
+                        talent_id: self.find_int(talent, "m_TalentID")?,
+                        current_rank: self.find_int(talent, "m_Ranks")?,
+                        max_rank: self.find_int(talent, "m_MaxRank")?,
+                        level_offset: self.find_int(talent, "m_LevelOffset")?,
+                        levels_per_rank: self.find_int(talent, "m_LevelsPerRank")?,
+                        visual_order: self.find_int(talent, "m_VisualOrder")?,
+                        prereq_talent_ids: self.find_int_array(talent, "m_PrereqTalentIDArray")?,
+                        prereq_talent_ranks: self
+                            .find_int_array(talent, "m_PrereqTalentRankArray")?,
+                    }
+                    .into(),
+                )
+            })
+            .collect()
+    }
+
+    // Rewrites the talent array, the entries are copies of the first one with the new values
+    pub fn set_simple_talents(&self, behavior: i32, talents: &[RcRef<SimpleTalent>]) -> Result<()> {
+        self.set_talent_entries(behavior, "m_aSimpleTalents", talents, |entry, talent| {
+            let talent = talent.borrow();
+            self.set_int(entry, "m_TalentID", talent.talent_id())?;
+            self.set_int(entry, "m_Ranks", talent.current_rank())
+        })
+    }
+
+    pub fn set_complex_talents(
+        &self, behavior: i32, talents: &[RcRef<ComplexTalent>],
+    ) -> Result<()> {
+        self.set_talent_entries(behavior, "m_aComplexTalents", talents, |entry, talent| {
+            let talent = talent.borrow();
+            self.set_int(entry, "m_TalentID", talent.talent_id())?;
+            self.set_int(entry, "m_Ranks", talent.current_rank())?;
+            self.set_int(entry, "m_MaxRank", talent.max_rank())?;
+            self.set_int(entry, "m_LevelOffset", talent.level_offset())?;
+            self.set_int(entry, "m_LevelsPerRank", talent.levels_per_rank())?;
+            self.set_int(entry, "m_VisualOrder", talent.visual_order())?;
+
+            let ids: Vec<_> = talent.prereq_talent_ids().iter().map(RcCell::get).collect();
+            self.set_int_array(entry, "m_PrereqTalentIDArray", &ids)?;
+            let ranks: Vec<_> = talent.prereq_talent_ranks().iter().map(RcCell::get).collect();
+            self.set_int_array(entry, "m_PrereqTalentRankArray", &ranks)
+        })
+    }
+
+    // 0 isn't saved
+    pub fn talent_points(&self, behavior: i32) -> Option<i32> {
+        self.find_int(&self.get_data(behavior).properties, "m_TalentPoints").map(|tp| tp.get())
+    }
+
+    pub fn xp_level(&self, behavior: i32) -> Option<i32> {
+        self.find_int(&self.get_data(behavior).properties, "m_XPLevel").map(|level| level.get())
+    }
+
+    // The game doesn't save the properties with their default value, a missing int is added
+    pub fn int_property(&mut self, behavior: i32, property_name: &str) -> Result<RcCell<i32>> {
+        if let Some(value) = self.find_int(&self.get_data(behavior).properties, property_name) {
+            return Ok(value);
+        }
+
+        let name_id = self.name_id(property_name);
+        let type_id = self.name_id("IntProperty");
+        let value = RcCell::new(0);
+        let property = Property::Int {
+            name_id,
+            _osef1: Dummy::default(),
+            type_id,
+            _osef2: Dummy::default(),
+            size: 4,
+            _osef3: Dummy::default(),
+            value: RcCell::clone(&value),
+        };
+
+        // Before the `None` that ends the properties
+        let properties = &mut self.get_data_mut(behavior).properties;
+        let idx = properties.len().saturating_sub(1);
+        properties.insert(idx, property.into());
+        Ok(value)
+    }
+
+    // Changes the class of the player like the LE class change: the talents are replaced by
+    // the new class ones and the spent talent points are refunded
+    pub fn change_class(&mut self, behavior: i32, class: &Me1LePlayerClass) -> Result<()> {
+        // New Game + things
+        const IGNORED_SIMPLES: &[i32] = &[262, 263, 266, 265, 267, 264];
+        const IGNORED_COMPLEXES: &[i32] = &[
+            108, // Charm
+            109, // Intimidate
+            259, // Spectre
+        ];
+
+        let class_base = self.name_id(CLASS_BASES[usize::from(class.player_class.clone())]);
+        {
+            let properties = &self.get_data(behavior).properties;
+            match *self
+                .find_property(properties, "m_ClassBase")
+                .ok_or_else(|| anyhow!("no m_ClassBase property"))?
+                .borrow()
+            {
+                Property::Name { ref value_name_id, .. } => value_name_id.set(class_base),
+                _ => return Err(anyhow!("m_ClassBase isn't a name")),
+            }
+            if let Some(localized_class_name) =
+                self.find_property(properties, "m_LocalizedClassName")
+            {
+                if let Property::StringRef { ref value, .. } = *localized_class_name.borrow() {
+                    value.set(class.localized_class_name);
+                }
+            }
+        }
+        self.int_property(behavior, "m_AutoLevelUpTemplateID")?.set(class.auto_levelup_template_id);
+        self.int_property(behavior, "m_SpecializationBonusID")?.set(-1);
+
+        let simple_talents: Vec<_> = self
+            .simple_talents(behavior)
+            .into_iter()
+            .filter(|talent| IGNORED_SIMPLES.contains(&talent.borrow().talent_id()))
+            .chain(class.simple_talents.iter().cloned())
+            .collect();
+        self.set_simple_talents(behavior, &simple_talents)?;
+
+        let mut spent_talent_points = 0;
+        let complex_talents: Vec<_> = self
+            .complex_talents(behavior)
+            .into_iter()
+            .filter(|talent| {
+                let talent = talent.borrow();
+                let is_ignored = IGNORED_COMPLEXES.contains(&talent.talent_id());
+                if !is_ignored {
+                    spent_talent_points += talent.current_rank();
+                }
+                is_ignored
+            })
+            .chain(class.complex_talents.iter().cloned())
+            .collect();
+        self.set_complex_talents(behavior, &complex_talents)?;
+
+        self.int_property(behavior, "m_TalentPoints")?.update(|tp| tp + spent_talent_points);
+        Ok(())
+    }
+
+    fn find_int(
+        &self, properties: &List<RcRef<Property>>, property_name: &str,
+    ) -> Option<RcCell<i32>> {
+        match *self.find_property(properties, property_name)?.borrow() {
+            Property::Int { ref value, .. } => Some(RcCell::clone(value)),
+            _ => None,
+        }
+    }
+
+    fn find_int_array(
+        &self, properties: &List<RcRef<Property>>, property_name: &str,
+    ) -> Option<RcRef<Vec<RcCell<i32>>>> {
+        match *self.find_property(properties, property_name)?.borrow() {
+            Property::Array { ref array, .. } => Some(RcRef::new(
+                array
+                    .iter()
+                    .filter_map(|value| match value {
+                        ArrayType::Int(value) => Some(RcCell::clone(value)),
+                        _ => None,
+                    })
+                    .collect(),
+            )),
+            _ => None,
+        }
+    }
+
+    fn set_int(
+        &self, properties: &List<RcRef<Property>>, property_name: &str, new_value: i32,
+    ) -> Result<()> {
+        self.find_int(properties, property_name)
+            .ok_or_else(|| anyhow!("no {} property", property_name))?
+            .set(new_value);
+        Ok(())
+    }
+
+    fn set_int_array(
+        &self, properties: &List<RcRef<Property>>, property_name: &str, values: &[i32],
+    ) -> Result<()> {
+        let mut property = self
+            .find_property(properties, property_name)
+            .ok_or_else(|| anyhow!("no {} property", property_name))?
+            .borrow_mut();
+        if let Property::Array { ref mut array, .. } = *property {
+            *array = values.iter().map(|&value| ArrayType::Int(value.into())).collect();
+        }
+        property.update_array_size()
+    }
+
+    fn talent_entries(&self, behavior: i32, property_name: &str) -> Vec<List<RcRef<Property>>> {
+        let properties = &self.get_data(behavior).properties;
+        match self.find_property(properties, property_name).map(|p| p.borrow()) {
+            Some(property) => match *property {
+                Property::Array { ref array, .. } => array
+                    .iter()
+                    .filter_map(|talent| match talent {
+                        ArrayType::Properties(talent) => Some(talent.clone()),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            },
+            None => Vec::new(),
+        }
+    }
+
+    fn set_talent_entries<T>(
+        &self, behavior: i32, property_name: &str, talents: &[T],
+        set_entry: impl Fn(&List<RcRef<Property>>, &T) -> Result<()>,
+    ) -> Result<()> {
+        let properties = &self.get_data(behavior).properties;
+        let mut property = self
+            .find_property(properties, property_name)
+            .ok_or_else(|| anyhow!("no {} property", property_name))?
+            .borrow_mut();
+
+        if let Property::Array { ref mut array, .. } = *property {
+            let template = match array.first() {
+                Some(ArrayType::Properties(template)) => template.duplicate()?,
+                _ => return Err(anyhow!("no talent to copy in {}", property_name)),
+            };
+
+            let mut new_array = Vec::new();
+            for talent in talents {
+                let entry = template.duplicate()?;
+                set_entry(&entry, talent)?;
+                new_array.push(ArrayType::Properties(entry));
+            }
+            *array = new_array;
+        }
+        property.update_array_size()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::*;
+    use crate::{
+        save_data::{
+            mass_effect_1::Me1SaveGame, mass_effect_1_le::player_class_db::Me1LePlayerClassDb,
+        },
+        unreal,
+    };
+
+    #[test]
+    fn change_class() -> Result<()> {
+        let input = fs::read("test/ME1Save.MassEffectSave")?;
+        let me1_save_game: Me1SaveGame = unreal::Deserializer::from_bytes(&input)?;
+        let mut player = me1_save_game.player().clone();
+
+        let class_db: Me1LePlayerClassDb =
+            ron::from_str(&fs::read_to_string("databases/me1_le_player_class_db.ron")?)?;
+
+        let behaviors = player.behaviors();
+        assert_eq!(behaviors.len(), 8);
+        let (ref tag, behavior) = behaviors[0];
+        assert_eq!(tag, "Player");
+
+        // Adept with the Shotguns bonus talent
+        assert_eq!(player.class_base(behavior), Some(2));
+        let complex_talents = player.complex_talents(behavior);
+        assert_eq!(complex_talents.len(), 13);
+        assert_eq!(player.simple_talents(behavior).len(), 23);
+
+        // The ranks are shared with the property tree
+        complex_talents[0].borrow_mut().set_current_rank(4);
+        assert_eq!(player.complex_talents(behavior)[0].borrow().current_rank(), 4);
+
+        // Not saved with 0 talent points
+        let spent: i32 = complex_talents.iter().map(|talent| talent.borrow().current_rank()).sum::<i32>()
+                - 4 // Charm
+                - 12 // Intimidate
+                - 4; // Spectre
+
+        let soldier = &class_db[0];
+        player.change_class(behavior, soldier)?;
+
+        // Serialize
+        let output = unreal::Serializer::to_vec(&player)?;
+        let player: Player = unreal::Deserializer::from_bytes(&output)?;
+
+        assert_eq!(player.class_base(behavior), Some(0));
+        let talent_ids: Vec<_> = player
+            .complex_talents(behavior)
+            .iter()
+            .map(|talent| talent.borrow().talent_id())
+            .collect();
+        let soldier_ids = soldier.complex_talents.iter().map(|talent| talent.borrow().talent_id());
+        assert_eq!(talent_ids, [108, 109, 259].into_iter().chain(soldier_ids).collect::<Vec<_>>());
+        assert_eq!(player.talent_points(behavior), Some(spent));
+
+        Ok(())
+    }
+}
@@ -1,20 +1,232 @@
 use serde::{Deserialize, Serialize};
 
-use super::{BaseObject, OptionObjectProxy};
-use crate::save_data::mass_effect_1_le::player::ItemLevel;
+use super::{BaseObject, Object, OptionObjectProxy};
+use crate::save_data::{
+    mass_effect_1_le::{item_db::DbItem, player::ItemLevel},
+    RcRef,
+};
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, RawUiChildren)]
 pub struct Shop {
-    last_player_level: i32,
-    is_initialized: bool,
-    inventory: Vec<OptionObjectProxy>,
+    pub last_player_level: i32,
+    pub is_initialized: bool,
+    pub inventory: Vec<OptionObjectProxy>,
+}
+
+impl Shop {
+    // Stock lists, they stay empty until the shop has been opened in game
+    pub fn stocks(&self) -> Vec<RcRef<Inventory>> {
+        self.stock_objects()
+            .into_iter()
+            .filter_map(|object| match object.borrow()._object {
+                Object::Inventory(ref inventory) => Some(RcRef::clone(inventory)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn add_item(&self, stock: &RcRef<Inventory>, item: DbItem, name: &str) -> bool {
+        match ShopItemClass::from_name(name) {
+            Some(class) => {
+                let new_item = self.new_item(class, item, ItemLevel::I);
+                stock.borrow_mut().items_mut().push(new_item);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Items of the same class keep their object and mods, the others are replaced
+    pub fn set_item(&self, stock: &RcRef<Inventory>, idx: usize, item: DbItem, name: &str) -> bool {
+        let class = match ShopItemClass::from_name(name) {
+            Some(class) => class,
+            None => return false,
+        };
+        let current = match stock.borrow().items().get(idx) {
+            Some(current) => RcRef::clone(current),
+            None => return false,
+        };
+
+        let current = current.borrow();
+        let same_class = current
+            .owner_class()
+            .as_ref()
+            .map(|owner_class| *owner_class.borrow() == class.owner_class())
+            .unwrap_or(false);
+
+        let item_level = match current._object {
+            Object::Item(ref current_item) => {
+                let mut current_item = current_item.borrow_mut();
+                if same_class {
+                    current_item.set_item_id(item.item_id);
+                    current_item.set_manufacturer_id(item.manufacturer_id);
+                    return true;
+                }
+                let item_level = *current_item.item_level();
+                item_level
+            }
+            Object::ItemMod(ref current_mod) => {
+                let mut current_mod = current_mod.borrow_mut();
+                if let (true, ShopItemClass::Mod(type_id)) = (same_class, class) {
+                    current_mod.set_item_id(item.item_id);
+                    current_mod.set_manufacturer_id(item.manufacturer_id);
+                    current_mod.set_type_id(type_id);
+                    return true;
+                }
+                let item_level = *current_mod.item_level();
+                item_level
+            }
+            _ => return false,
+        };
+        drop(current);
+
+        let new_item = self.new_item(class, item, item_level);
+        stock.borrow_mut().items_mut()[idx] = new_item;
+        true
+    }
+
+    fn stock_objects(&self) -> Vec<RcRef<BaseObject>> {
+        self.inventory().iter().filter_map(|proxy| proxy.borrow().proxy().clone()).collect()
+    }
+
+    fn new_item(
+        &self, class: ShopItemClass, item: DbItem, item_level: ItemLevel,
+    ) -> RcRef<BaseObject> {
+        let (class_name, object) = match class {
+            ShopItemClass::Mod(type_id) => {
+                let item_mod = ItemMod {
+                    item_id: item.item_id.into(),
+                    item_level: item_level.into(),
+                    manufacturer_id: item.manufacturer_id.into(),
+                    plot_conditional_id: 0.into(),
+                    type_id: type_id.into(),
+                };
+                ("BioItemXModSaveObject", Object::ItemMod(item_mod.into()))
+            }
+            _ => {
+                let slot_specs = class
+                    .slot_specs()
+                    .iter()
+                    .map(|&(type_id, len)| {
+                        let mut slot_spec = ModdableSlotSpec::default();
+                        slot_spec.set_type_id(type_id);
+                        *slot_spec.mods_mut() = vec![Default::default(); len];
+                        slot_spec.into()
+                    })
+                    .collect::<Vec<_>>();
+                let item = Item {
+                    item_id: item.item_id.into(),
+                    item_level: item_level.into(),
+                    manufacturer_id: item.manufacturer_id.into(),
+                    plot_conditional_id: 0.into(),
+                    slot_specs: slot_specs.into(),
+                };
+                ("BioItemXModdableSaveObject", Object::Item(item.into()))
+            }
+        };
+
+        let owner_class = class.owner_class();
+        BaseObject {
+            _class_name: class_name.to_owned(),
+            owner_name: self.next_owner_name(owner_class).into(),
+            owner_class: Some(owner_class.to_owned().into()).into(),
+            _object: object,
+        }
+        .into()
+    }
+
+    // `BioShop_0.BioInventory_2582` => `BioShop_0.BioItemWeaponRanged_<next free number>`
+    fn next_owner_name(&self, owner_class: &str) -> String {
+        let stocks = self.stock_objects();
+        let prefix = stocks
+            .first()
+            .and_then(|stock| {
+                stock.borrow().owner_name().split_once('.').map(|(prefix, _)| prefix.to_owned())
+            })
+            .unwrap_or_else(|| "BioShop".to_owned());
+
+        let next = self
+            .stocks()
+            .iter()
+            .flat_map(|stock| {
+                let items = stock.borrow().items().clone();
+                items.into_iter().filter_map(|item| {
+                    let owner_name = item.borrow().owner_name().clone();
+                    let (class, number) = owner_name.rsplit_once('_')?;
+                    class.ends_with(owner_class).then(|| number.parse::<i32>().ok()).flatten()
+                })
+            })
+            .max()
+            .map(|max| max + 1)
+            .unwrap_or_default();
+
+        format!("{}.{}_{}", prefix, owner_class, next)
+    }
+}
+
+// The object class isn't in the item database, it's deduced from the item name
+#[derive(Clone, Copy)]
+enum ShopItemClass {
+    Armor,
+    Weapon,
+    OmniTool,
+    BioAmp,
+    Mod(i32),
+}
+
+impl ShopItemClass {
+    // Grenades and grenade mods aren't sold in the shops of the test saves, their layout is unknown
+    fn from_name(name: &str) -> Option<Self> {
+        let class = if name.starts_with("[Armor Mod]") {
+            ShopItemClass::Mod(3)
+        } else if name.starts_with("[Weapon Ammo Mod]") {
+            ShopItemClass::Mod(0)
+        } else if name.starts_with("[Weapon Mod]") {
+            ShopItemClass::Mod(1)
+        } else if name.ends_with("Armor)") {
+            ShopItemClass::Armor
+        } else if name.ends_with("Omni-Tool") {
+            ShopItemClass::OmniTool
+        } else if name.ends_with("Biotic Amp") {
+            ShopItemClass::BioAmp
+        } else if ["Assault Rifle", "Shotgun", "Pistol", "Sniper Rifle", "Pulse Rifle"]
+            .iter()
+            .any(|weapon| name.ends_with(weapon))
+        {
+            ShopItemClass::Weapon
+        } else {
+            return None;
+        };
+        Some(class)
+    }
+
+    fn owner_class(self) -> &'static str {
+        match self {
+            ShopItemClass::Armor => "BioItemArmorShielded",
+            ShopItemClass::Weapon => "BioItemWeaponRanged",
+            ShopItemClass::OmniTool => "BioItemOmniTool",
+            ShopItemClass::BioAmp => "BioItemBioAmp",
+            ShopItemClass::Mod(_) => "BioItemXMod",
+        }
+    }
+
+    // (slot type id, number of slots), the smallest layout seen in shops
+    fn slot_specs(self) -> &'static [(i32, usize)] {
+        match self {
+            ShopItemClass::Armor => &[(2, 0), (3, 1)],
+            ShopItemClass::Weapon => &[(0, 1), (1, 1)],
+            ShopItemClass::BioAmp => &[(4, 0)],
+            ShopItemClass::OmniTool => &[(5, 0)],
+            ShopItemClass::Mod(_) => &[],
+        }
+    }
 }
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, RawUiChildren)]
 pub struct Inventory {
-    items: Vec<BaseObject>,
+    pub items: Vec<BaseObject>,
     plot_items: Vec<PlotItem>,
     credits: i32,
     grenades: i32,
@@ -37,27 +249,27 @@ struct PlotItem {
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, RawUiChildren)]
 pub struct Item {
-    item_id: i32,
-    item_level: ItemLevel,
-    manufacturer_id: i32,
-    plot_conditional_id: i32,
-    slot_specs: Vec<ModdableSlotSpec>,
+    pub item_id: i32,
+    pub item_level: ItemLevel,
+    pub manufacturer_id: i32,
+    pub plot_conditional_id: i32,
+    pub slot_specs: Vec<ModdableSlotSpec>,
 }
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "")]
-struct ModdableSlotSpec {
-    type_id: i32,
-    mods: Vec<OptionObjectProxy>,
+pub struct ModdableSlotSpec {
+    pub type_id: i32,
+    pub mods: Vec<OptionObjectProxy>,
 }
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, RawUiChildren)]
 pub struct ItemMod {
-    item_id: i32,
-    item_level: ItemLevel,
-    manufacturer_id: i32,
-    plot_conditional_id: i32,
-    type_id: i32,
+    pub item_id: i32,
+    pub item_level: ItemLevel,
+    pub manufacturer_id: i32,
+    pub plot_conditional_id: i32,
+    pub type_id: i32,
 }
@@ -1,5 +1,5 @@
 mod art_placeable;
-mod inventory;
+pub mod inventory;
 mod pawn;
 
 use self::{art_placeable::*, inventory::*, pawn::*};
@@ -9,23 +9,71 @@ use std::fmt;
 use indexmap::IndexMap;
 use serde::{de, Deserialize, Deserializer, Serialize};
 
-use crate::save_data::RcRef;
 use crate::save_data::{
     shared::{Rotator, Vector},
     Dummy,
 };
+use crate::save_data::{RcRef, SeqAccessExt};
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, RawUi, RawUiChildren)]
 pub struct Map {
-    levels: IndexMap<String, Level>,
-    world: Option<BaseObject>,
+    pub levels: IndexMap<String, Level>,
+    pub world: Option<BaseObject>,
+}
+
+impl Map {
+    pub fn shops(&self) -> Vec<LevelShop> {
+        self.levels()
+            .iter()
+            .flat_map(|(level_name, level)| {
+                let objects = level.borrow().objects().clone();
+                objects.into_iter().filter_map(move |object| {
+                    let object = object.borrow();
+                    match object._object {
+                        Object::Shop(ref shop) => Some(LevelShop {
+                            level_name: level_name.clone(),
+                            owner_name: object.owner_name().clone(),
+                            shop: RcRef::clone(shop),
+                        }),
+                        _ => None,
+                    }
+                })
+            })
+            .collect()
+    }
+
+    pub fn objects(&self) -> Vec<LevelObject> {
+        let mut objects = Vec::new();
+        for (level_name, level) in self.levels().iter() {
+            for object in level.borrow().objects().iter() {
+                BaseObject::flatten(level_name, object, &mut objects);
+            }
+        }
+        if let Some(ref world) = *self.world() {
+            BaseObject::flatten("", world, &mut objects);
+        }
+        objects
+    }
+}
+
+// `owner_name` is only unique inside a level
+pub struct LevelShop {
+    pub level_name: String,
+    pub owner_name: String,
+    pub shop: RcRef<Shop>,
+}
+
+// The world isn't part of a level, its `level_name` is empty
+pub struct LevelObject {
+    pub level_name: String,
+    pub object: RcRef<BaseObject>,
 }
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, RawUi, RawUiChildren)]
 pub struct Level {
-    objects: Vec<BaseObject>,
+    pub objects: Vec<BaseObject>,
     actors: Vec<String>,
 }
 
@@ -39,6 +87,60 @@ pub struct BaseObject {
     pub _object: Object,
 }
 
+impl BaseObject {
+    fn flatten(level_name: &str, object: &RcRef<BaseObject>, objects: &mut Vec<LevelObject>) {
+        objects
+            .push(LevelObject { level_name: level_name.to_owned(), object: RcRef::clone(object) });
+        for child in object.borrow().children() {
+            BaseObject::flatten(level_name, &child, objects);
+        }
+    }
+
+    fn children(&self) -> Vec<RcRef<BaseObject>> {
+        fn option(object: &RcRef<Option<RcRef<BaseObject>>>) -> Vec<RcRef<BaseObject>> {
+            object.borrow().iter().cloned().collect()
+        }
+
+        fn proxies(proxies: &[RcRef<OptionObjectProxy>]) -> Vec<RcRef<BaseObject>> {
+            proxies.iter().filter_map(|proxy| proxy.borrow().proxy().clone()).collect()
+        }
+
+        match self._object {
+            Object::PawnBehavior(ref behavior) => {
+                let behavior = behavior.borrow();
+                let mut children = option(&behavior.owner);
+                children.extend(option(&behavior.squad));
+                children.extend(option(&behavior.inventory));
+                children.extend(proxies(&behavior.quick_slots()));
+                children.extend(proxies(&behavior.equipment()));
+                children
+            }
+            Object::BaseSquad(ref squad) => option(&squad.borrow().inventory),
+            Object::Shop(ref shop) => proxies(&shop.borrow().inventory()),
+            Object::Inventory(ref inventory) => inventory.borrow().items().clone(),
+            Object::Item(ref item) => item
+                .borrow()
+                .slot_specs()
+                .iter()
+                .flat_map(|slot_spec| proxies(&slot_spec.borrow().mods()))
+                .collect(),
+            Object::ArtPlaceableBehavior(ref behavior) => {
+                let behavior = behavior.borrow();
+                let mut children = option(&behavior.owner);
+                children.extend(option(&behavior.inventory));
+                children
+            }
+            Object::VehicleBehavior(ref behavior) => option(&behavior.borrow().owner),
+            Object::World(ref world) => option(&world.borrow().pending_loot),
+            Object::Pawn(_)
+            | Object::ItemMod(_)
+            | Object::ArtPlaceable(_)
+            | Object::Vehicle(_)
+            | Object::Default => Vec::new(),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for BaseObject {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -56,31 +158,30 @@ impl<'de> Deserialize<'de> for BaseObject {
             where
                 A: de::SeqAccess<'de>,
             {
-                let class_name: String = seq.next_element()?.unwrap();
-                let owner_name = seq.next_element()?.unwrap();
-                let owner_class = seq.next_element()?.unwrap();
+                let class_name: String = seq.next_required()?;
+                let owner_name = seq.next_required()?;
+                let owner_class = seq.next_required()?;
                 let object = match class_name.as_str() {
-                    "BioPawnBehaviorSaveObject" => {
-                        Object::PawnBehavior(seq.next_element()?.unwrap())
-                    }
-                    "BioPawnSaveObject" => Object::Pawn(seq.next_element()?.unwrap()),
-                    "BioBaseSquadSaveObject" => Object::BaseSquad(seq.next_element()?.unwrap()),
-                    "BioShopSaveObject" => Object::Shop(seq.next_element()?.unwrap()),
-                    "BioInventorySaveObject" => Object::Inventory(seq.next_element()?.unwrap()),
-                    "BioItemXModdableSaveObject" => Object::Item(seq.next_element()?.unwrap()),
-                    "BioItemXModSaveObject" => Object::ItemMod(seq.next_element()?.unwrap()),
+                    "BioPawnBehaviorSaveObject" => Object::PawnBehavior(seq.next_required()?),
+                    "BioPawnSaveObject" => Object::Pawn(seq.next_required()?),
+                    "BioBaseSquadSaveObject" => Object::BaseSquad(seq.next_required()?),
+                    "BioShopSaveObject" => Object::Shop(seq.next_required()?),
+                    "BioInventorySaveObject" => Object::Inventory(seq.next_required()?),
+                    "BioItemXModdableSaveObject" => Object::Item(seq.next_required()?),
+                    "BioItemXModSaveObject" => Object::ItemMod(seq.next_required()?),
                     "BioArtPlaceableBehaviorSaveObject" => {
-                        Object::ArtPlaceableBehavior(seq.next_element()?.unwrap())
-                    }
-                    "BioArtPlaceableSaveObject" => {
-                        Object::ArtPlaceable(seq.next_element()?.unwrap())
+                        Object::ArtPlaceableBehavior(seq.next_required()?)
                     }
-                    "BioVehicleBehaviorSaveObject" => {
-                        Object::VehicleBehavior(seq.next_element()?.unwrap())
+                    "BioArtPlaceableSaveObject" => Object::ArtPlaceable(seq.next_required()?),
+                    "BioVehicleBehaviorSaveObject" => Object::VehicleBehavior(seq.next_required()?),
+                    "BioVehicleSaveObject" => Object::Vehicle(seq.next_required()?),
+                    "BioWorldInfoSaveObject" => Object::World(seq.next_required()?),
+                    _ => {
+                        return Err(de::Error::custom(format!(
+                            "Unknown legacy object class: {}",
+                            class_name
+                        )))
                     }
-                    "BioVehicleSaveObject" => Object::Vehicle(seq.next_element()?.unwrap()),
-                    "BioWorldInfoSaveObject" => Object::World(seq.next_element()?.unwrap()),
-                    _ => unreachable!(),
                 };
 
                 Ok(BaseObject { _class_name: class_name, owner_name, owner_class, _object: object })
@@ -126,7 +227,7 @@ pub struct VehicleBehavior {
     actor_type: String,
     powertrain_enabled: bool,
     vehicle_fonction_enabled: bool,
-    owner: Option<BaseObject>,
+    pub owner: Option<BaseObject>,
 }
 
 #[rcize_fields]
@@ -139,7 +240,7 @@ pub struct Vehicle {
     script_initialized: bool,
     hidden: bool,
     stasis: bool,
-    health: f32,
+    pub health: f32,
     shield: f32,
     first_name: String,
     localized_last_name: i32,
@@ -174,5 +275,5 @@ pub struct World {
     current_tip_id: i32,
     override_tip: i32,
     _browser_alerts: Dummy<8>, // [u8; 8]
-    pending_loot: Option<BaseObject>,
+    pub pending_loot: Option<BaseObject>,
 }
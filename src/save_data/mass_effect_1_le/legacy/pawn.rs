@@ -9,18 +9,18 @@ use crate::save_data::{
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, RawUiChildren)]
 pub struct PawnBehavior {
-    is_dead: bool,
+    pub is_dead: bool,
     generated_treasure: bool,
     challenge_scaled: bool,
-    owner: Option<BaseObject>,
-    health: f32,
+    pub owner: Option<BaseObject>,
+    pub health: f32,
     shield: f32,
     first_name: String,
     localized_last_name: i32,
-    health_max: f32,
+    pub health_max: f32,
     health_regen_rate: f32,
     radar_range: f32,
-    level: i32,
+    pub level: i32,
     health_per_level: f32,
     stability: f32,
     gender: u8,
@@ -31,8 +31,8 @@ pub struct PawnBehavior {
     precision: i32,
     coordination: i32,
     quick_slot: u8,
-    squad: Option<BaseObject>,
-    inventory: Option<BaseObject>,
+    pub squad: Option<BaseObject>,
+    pub inventory: Option<BaseObject>,
     _unknown: Dummy<3>,
     experience: i32,
     talent_points: i32,
@@ -57,8 +57,18 @@ pub struct PawnBehavior {
     head_gear_visible_preference: bool,
     simple_talents: Vec<SimpleTalent>,
     complex_talents: Vec<ComplexTalent>,
-    quick_slots: Vec<OptionObjectProxy>,
-    equipment: Vec<OptionObjectProxy>,
+    pub quick_slots: Vec<OptionObjectProxy>,
+    pub equipment: Vec<OptionObjectProxy>,
+}
+
+impl PawnBehavior {
+    pub fn revive(&self) {
+        self.is_dead.set(false);
+        // `health_max` is usually left at 0 in the save
+        if self.health.get() <= 0.0 {
+            self.health.set(self.health_max.get().max(1.0));
+        }
+    }
 }
 
 #[rcize_fields]
@@ -102,5 +112,5 @@ pub struct Pawn {
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, RawUiChildren)]
 pub struct BaseSquad {
-    inventory: Option<BaseObject>,
+    pub inventory: Option<BaseObject>,
 }
@@ -0,0 +1,377 @@
+use std::mem;
+
+use serde::{Deserialize, Serialize};
+
+use crate::save_data::RcRef;
+
+use super::{
+    item_db::{DbItem, Me1ItemDb},
+    player::{Inventory, Item, ItemMod, Me1LeClass},
+};
+
+#[derive(Deserialize, Serialize, Default)]
+pub struct Me1LeLoadout {
+    pub name: String,
+    pub equipment: Vec<Item>,
+    pub quick_slots: Vec<Item>,
+}
+
+impl Clone for Me1LeLoadout {
+    fn clone(&self) -> Self {
+        let copy = |items: &[Item]| items.iter().map(copy_item).collect();
+        Me1LeLoadout {
+            name: self.name.clone(),
+            equipment: copy(&self.equipment),
+            quick_slots: copy(&self.quick_slots),
+        }
+    }
+}
+
+impl Me1LeLoadout {
+    pub fn from_gear(name: String, equipment: &[RcRef<Item>], quick_slots: &[RcRef<Item>]) -> Self {
+        let copy =
+            |items: &[RcRef<Item>]| items.iter().map(|item| copy_item(&item.borrow())).collect();
+        Me1LeLoadout { name, equipment: copy(equipment), quick_slots: copy(quick_slots) }
+    }
+
+    // Every reason the game wouldn't accept this loadout on the wearer, empty if it does
+    pub fn validate(&self, item_db: &Me1ItemDb, wearer: Option<Wearer>) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        for (items, slots) in [(&self.equipment, EQUIPMENT_SLOTS), (&self.quick_slots, QUICK_SLOTS)]
+        {
+            if items.len() != slots.len() {
+                errors.push(format!("{} slots instead of {}", items.len(), slots.len()));
+            }
+
+            for (slot, item) in slots.iter().zip(items) {
+                errors.extend(
+                    slot.validate(item_db, wearer, item)
+                        .into_iter()
+                        .map(|error| format!("{}: {}", slot.label(), error)),
+                );
+            }
+        }
+        errors
+    }
+
+    // Replaces the gear with a copy of the loadout and moves the previously equipped items to the
+    // inventory, does nothing if they don't fit in it
+    pub fn apply(
+        &self, equipment: &[RcRef<Item>], quick_slots: &[RcRef<Item>], inventory: &Inventory,
+    ) -> bool {
+        let current = equipment.iter().zip(&self.equipment);
+        let current = current.chain(quick_slots.iter().zip(&self.quick_slots));
+
+        let replaced = current.clone().filter(|(current, _)| current.borrow().item_id() != 0);
+        if !inventory.has_room(replaced.count()) {
+            return false;
+        }
+
+        let mut unequipped = current
+            .filter_map(|(current, new)| {
+                let mut old = mem::replace(&mut *current.borrow_mut(), copy_item(new));
+                old.set_new_item(true);
+                (old.item_id() != 0).then(|| old.into())
+            })
+            .collect();
+        inventory.add_items(&mut unequipped)
+    }
+}
+
+// `Item::clone` shares its fields, a template must not change along with the gear it was made from
+fn copy_item(item: &Item) -> Item {
+    let attached_mods: Vec<RcRef<ItemMod>> = item
+        .attached_mods()
+        .iter()
+        .map(|item_mod| {
+            let item_mod = item_mod.borrow();
+            let item_level = *item_mod.item_level();
+            ItemMod {
+                item_id: item_mod.item_id().into(),
+                item_level: item_level.into(),
+                manufacturer_id: item_mod.manufacturer_id().into(),
+                plot_conditional_id: item_mod.plot_conditional_id().into(),
+            }
+            .into()
+        })
+        .collect();
+
+    Item {
+        item_id: item.item_id().into(),
+        item_level: (*item.item_level()).into(),
+        manufacturer_id: item.manufacturer_id().into(),
+        plot_conditional_id: item.plot_conditional_id().into(),
+        new_item: item.new_item().into(),
+        junk: false.into(),
+        attached_mods: attached_mods.into(),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Display)]
+pub enum Race {
+    Human,
+    Turian,
+    Krogan,
+    Quarian,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Display)]
+pub enum ArmorWeight {
+    Light,
+    Medium,
+    Heavy,
+}
+
+// The armor a character can wear, lighter armors always fit
+#[derive(Clone, Copy)]
+pub struct Wearer {
+    pub race: Race,
+    pub armor_weight: ArmorWeight,
+}
+
+impl Wearer {
+    pub fn player(player_class: &Me1LeClass) -> Self {
+        let armor_weight = match player_class {
+            Me1LeClass::Soldier => ArmorWeight::Heavy,
+            Me1LeClass::Infiltrator | Me1LeClass::Vanguard => ArmorWeight::Medium,
+            Me1LeClass::Engineer | Me1LeClass::Adept | Me1LeClass::Sentinel => ArmorWeight::Light,
+        };
+        Wearer { race: Race::Human, armor_weight }
+    }
+
+    pub fn henchman(tag: &str) -> Option<Self> {
+        let (race, armor_weight) = match tag {
+            // Liara wears human armors
+            "hench_asari" => (Race::Human, ArmorWeight::Light),
+            "hench_humanfemale" => (Race::Human, ArmorWeight::Heavy),
+            "hench_humanmale" => (Race::Human, ArmorWeight::Light),
+            "hench_krogan" => (Race::Krogan, ArmorWeight::Heavy),
+            "hench_quarian" => (Race::Quarian, ArmorWeight::Light),
+            "hench_turian" => (Race::Turian, ArmorWeight::Medium),
+            _ => return None,
+        };
+        Some(Wearer { race, armor_weight })
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Slot {
+    Unused,
+    Armor,
+    Grenade,
+    OmniTool,
+    BioAmp,
+    Pistol,
+    Shotgun,
+    AssaultRifle,
+    SniperRifle,
+}
+
+const EQUIPMENT_SLOTS: &[Slot] =
+    &[Slot::Unused, Slot::Armor, Slot::Grenade, Slot::OmniTool, Slot::BioAmp];
+const QUICK_SLOTS: &[Slot] = &[Slot::Pistol, Slot::Shotgun, Slot::AssaultRifle, Slot::SniperRifle];
+
+impl Slot {
+    fn label(self) -> &'static str {
+        match self {
+            Slot::Unused => "Unused slot",
+            Slot::Armor => "Armor",
+            Slot::Grenade => "Grenade",
+            Slot::OmniTool => "Omni-Tool",
+            Slot::BioAmp => "Biotic Amp",
+            Slot::Pistol => "Pistol",
+            Slot::Shotgun => "Shotgun",
+            Slot::AssaultRifle => "Assault Rifle",
+            Slot::SniperRifle => "Sniper Rifle",
+        }
+    }
+
+    fn accepts(self, name: &str) -> bool {
+        match self {
+            Slot::Unused => false,
+            Slot::Armor => name.ends_with(" Armor)"),
+            Slot::Grenade => name == "[Grenade]",
+            Slot::OmniTool => name.ends_with(" Omni-Tool"),
+            Slot::BioAmp => name.ends_with(" Biotic Amp"),
+            Slot::Pistol => name.ends_with(" Pistol"),
+            Slot::Shotgun => name.ends_with(" Shotgun"),
+            Slot::AssaultRifle => {
+                name.ends_with(" Assault Rifle") || name.ends_with(" Pulse Rifle")
+            }
+            Slot::SniperRifle => name.ends_with(" Sniper Rifle"),
+        }
+    }
+
+    fn accepts_mod(self, name: &str) -> bool {
+        match self {
+            Slot::Armor => name.starts_with("[Armor Mod]"),
+            Slot::Grenade => name.starts_with("[Grenade Mod]"),
+            Slot::Pistol | Slot::Shotgun | Slot::AssaultRifle | Slot::SniperRifle => {
+                name.starts_with("[Weapon Mod]") || name.starts_with("[Weapon Ammo Mod]")
+            }
+            Slot::Unused | Slot::OmniTool | Slot::BioAmp => false,
+        }
+    }
+
+    fn validate(self, item_db: &Me1ItemDb, wearer: Option<Wearer>, item: &Item) -> Vec<String> {
+        // Empty slot
+        if item.item_id() == 0 && item.attached_mods().is_empty() {
+            return Vec::new();
+        }
+
+        let db_item = DbItem { item_id: item.item_id(), manufacturer_id: item.manufacturer_id() };
+        let name = match item_db.get(&db_item) {
+            Some(name) => name,
+            None => {
+                return vec![format!(
+                    "unknown item {}/{}",
+                    db_item.item_id, db_item.manufacturer_id
+                )]
+            }
+        };
+
+        let mut errors = Vec::new();
+        if !self.accepts(name) {
+            errors.push(format!("`{}` doesn't fit in this slot", name));
+        }
+
+        if let (Some(wearer), Some((race, armor_weight))) = (wearer, parse_armor(name)) {
+            if race != wearer.race {
+                errors.push(format!("`{}` is not made for {} characters", name, wearer.race));
+            }
+            if armor_weight > wearer.armor_weight {
+                errors.push(format!(
+                    "`{}` is too heavy, the character can wear up to {} armors",
+                    name, wearer.armor_weight
+                ));
+            }
+        }
+
+        for item_mod in item.attached_mods().iter() {
+            let item_mod = item_mod.borrow();
+            let db_item =
+                DbItem { item_id: item_mod.item_id(), manufacturer_id: item_mod.manufacturer_id() };
+            match item_db.get(&db_item) {
+                Some(mod_name) if !self.accepts_mod(mod_name) => {
+                    errors.push(format!("`{}` can't be attached to `{}`", mod_name, name))
+                }
+                Some(_) => {}
+                None => errors
+                    .push(format!("unknown mod {}/{}", db_item.item_id, db_item.manufacturer_id)),
+            }
+        }
+        errors
+    }
+}
+
+// `[Aldrin Labs] Onyx (Krogan Medium Armor)` => `(Krogan, Medium)`
+fn parse_armor(name: &str) -> Option<(Race, ArmorWeight)> {
+    let kind = name.rsplit_once(" (")?.1.strip_suffix(" Armor)")?;
+    let (race, armor_weight) = kind.split_once(' ')?;
+
+    let race = match race {
+        "Human" => Race::Human,
+        "Turian" => Race::Turian,
+        "Krogan" => Race::Krogan,
+        "Quarian" => Race::Quarian,
+        _ => return None,
+    };
+    let armor_weight = match armor_weight {
+        "Light" => ArmorWeight::Light,
+        "Medium" => ArmorWeight::Medium,
+        "Heavy" => ArmorWeight::Heavy,
+        _ => return None,
+    };
+    Some((race, armor_weight))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use anyhow::Result;
+
+    use super::*;
+    use crate::{
+        save_data::mass_effect_1_le::{player::INVENTORY_CAP, Me1LeSaveGame},
+        unreal,
+    };
+
+    #[test]
+    fn loadout() -> Result<()> {
+        let input = fs::read_to_string("databases/me1_item_db.ron")?;
+        let item_db: Me1ItemDb = ron::from_str(&input)?;
+
+        let input = fs::read("test/ME1LeSave.pcsav")?;
+        let me1_save_game: Me1LeSaveGame = unreal::Deserializer::from_bytes(&input)?;
+        let save_data = me1_save_game.save_data.borrow();
+        let player = save_data.player();
+        let inventory = player.inventory();
+
+        // Shepard's gear: human light armor
+        let loadout = Me1LeLoadout::from_gear(
+            String::from("Shepard"),
+            &inventory.equipment(),
+            &inventory.quick_slots(),
+        );
+        let loadout: Me1LeLoadout = ron::from_str(&ron::to_string(&loadout)?)?;
+        assert!(loadout
+            .validate(&item_db, Some(Wearer::player(&player.player_class())))
+            .is_empty());
+        assert!(loadout.validate(&item_db, None).is_empty());
+
+        let squad = save_data.squad();
+        let henchman = |tag| squad.iter().find(|henchman| *henchman.borrow().tag() == tag).unwrap();
+        let garrus = henchman("hench_turian").borrow();
+        let errors = loadout.validate(&item_db, Wearer::henchman(&garrus.tag()));
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("not made for Turian"));
+
+        // Heavy armor
+        let mut heavy = loadout.clone();
+        heavy.equipment[1].set_item_id(291);
+        heavy.equipment[1].set_manufacturer_id(495);
+        let kaidan = henchman("hench_humanmale").borrow();
+        let errors = heavy.validate(&item_db, Wearer::henchman(&kaidan.tag()));
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("too heavy"));
+
+        // Wrong slot and mod
+        let mut wrong =
+            Me1LeLoadout::from_gear(String::new(), &kaidan.equipment(), &kaidan.quick_slots());
+        wrong.quick_slots.swap(0, 1);
+        assert_eq!(wrong.validate(&item_db, None).len(), 2);
+        let mut wrong = loadout.clone();
+        wrong.equipment.pop();
+        assert_eq!(wrong.validate(&item_db, None).len(), 1);
+        drop(kaidan);
+
+        // Apply
+        let liara = henchman("hench_asari").borrow();
+        let old_armor = liara.equipment()[1].borrow().manufacturer_id();
+        let len = inventory.inventory().len();
+        assert!(loadout.apply(&liara.equipment(), &liara.quick_slots(), &inventory));
+        assert_eq!(inventory.inventory().len(), len + 8);
+        assert_eq!(inventory.inventory()[len].borrow().manufacturer_id(), old_armor);
+        assert_eq!(
+            liara.equipment()[1].borrow().manufacturer_id(),
+            inventory.equipment()[1].borrow().manufacturer_id()
+        );
+
+        // No room for the replaced gear
+        let full = Inventory::default();
+        full.inventory.borrow_mut().resize_with(INVENTORY_CAP, Default::default);
+        let tali = henchman("hench_quarian").borrow();
+        let tali_armor = tali.equipment()[1].borrow().item_id();
+        assert!(!loadout.apply(&tali.equipment(), &tali.quick_slots(), &full));
+        assert_eq!(full.inventory().len(), INVENTORY_CAP);
+        assert_eq!(tali.equipment()[1].borrow().item_id(), tali_armor);
+
+        // The gear is a copy of the template
+        liara.equipment()[1].borrow_mut().set_item_id(0);
+        assert_eq!(loadout.equipment[1].item_id(), 287);
+
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
 pub mod item_db;
 pub mod legacy;
+pub mod loadout;
 pub mod player;
 pub mod player_class_db;
 pub mod squad;
+pub mod talent_db;
 
 use self::{legacy::*, player::*, squad::*};
 
@@ -17,7 +19,10 @@ use serde::de;
 use serde::ser::{self, SerializeStruct};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::{save_data::RcRef, unreal};
+use crate::{
+    save_data::{RcRef, SeqAccessExt},
+    unreal,
+};
 
 use super::{
     shared::{
@@ -61,51 +66,15 @@ impl<'de> Deserialize<'de> for Me1LeSaveGame {
             where
                 A: de::SeqAccess<'de>,
             {
-                let magic_number = seq.next_element()?.unwrap();
-                let block_size = seq.next_element()?.unwrap();
-
-                // Headers
-                let mut headers = Vec::new();
-                {
-                    let full_header = ChunkHeader {
-                        compressed_size: seq.next_element()?.unwrap(),
-                        uncompressed_size: seq.next_element()?.unwrap(),
-                    };
-                    headers.push(full_header);
-
-                    let mut finished = false;
-                    while !finished {
-                        let header = ChunkHeader {
-                            compressed_size: seq.next_element()?.unwrap(),
-                            uncompressed_size: seq.next_element()?.unwrap(),
-                        };
-                        if header.uncompressed_size < block_size {
-                            finished = true;
-                        }
-                        headers.push(header);
-                    }
-                }
-
-                // Save data
-                let save_data: Me1LeSaveData = {
-                    let mut uncompressed = Vec::new();
-
-                    for header in &headers[1..] {
-                        let mut compressed = Vec::new();
-                        for _ in 0..header.compressed_size {
-                            compressed.push(seq.next_element()?.unwrap());
-                        }
-
-                        let mut z = ZlibDecoder::new(&compressed[..]);
-                        z.read_to_end(&mut uncompressed).map_err(de::Error::custom)?;
-                    }
-
-                    unreal::Deserializer::from_bytes(&uncompressed).map_err(de::Error::custom)?
-                };
+                let magic_number = seq.next_required()?;
+                let block_size = seq.next_required()?;
+                let (headers, uncompressed) = decompress_chunks(&mut seq, block_size)?;
+                let save_data: Me1LeSaveData =
+                    unreal::Deserializer::from_bytes(&uncompressed).map_err(de::Error::custom)?;
 
-                let checksum = seq.next_element()?.unwrap();
-                let compression_flag = seq.next_element()?.unwrap();
-                let uncompressed_size = seq.next_element()?.unwrap();
+                let checksum = seq.next_required()?;
+                let compression_flag = seq.next_required()?;
+                let uncompressed_size = seq.next_required()?;
 
                 Ok(Me1LeSaveGame {
                     magic_number,
@@ -122,6 +91,85 @@ impl<'de> Deserialize<'de> for Me1LeSaveGame {
     }
 }
 
+// Reads the chunk headers then the chunks, the first header is the sum of the others
+fn decompress_chunks<'de, A>(
+    seq: &mut A, block_size: u32,
+) -> Result<(Vec<ChunkHeader>, Vec<u8>), A::Error>
+where
+    A: de::SeqAccess<'de>,
+{
+    // Headers
+    let mut headers = Vec::new();
+    {
+        let full_header = ChunkHeader {
+            compressed_size: seq.next_required()?,
+            uncompressed_size: seq.next_required()?,
+        };
+        headers.push(full_header);
+
+        let mut finished = false;
+        while !finished {
+            let header = ChunkHeader {
+                compressed_size: seq.next_required()?,
+                uncompressed_size: seq.next_required()?,
+            };
+            if header.uncompressed_size < block_size {
+                finished = true;
+            }
+            headers.push(header);
+        }
+    }
+
+    // Save data
+    let mut uncompressed = Vec::new();
+    for header in &headers[1..] {
+        let mut compressed = Vec::new();
+        for _ in 0..header.compressed_size {
+            compressed.push(seq.next_required()?);
+        }
+
+        let mut z = ZlibDecoder::new(&compressed[..]);
+        z.read_to_end(&mut uncompressed).map_err(de::Error::custom)?;
+    }
+
+    Ok((headers, uncompressed))
+}
+
+// `Me1LeSaveGame` read up to the player, enough to list a save without reading it all
+pub struct Me1LeSaveHeader {
+    pub save_data: Me1LeSaveDataHeader,
+}
+
+impl<'de> Deserialize<'de> for Me1LeSaveHeader {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Me1LeSaveHeaderVisitor;
+        impl<'de> de::Visitor<'de> for Me1LeSaveHeaderVisitor {
+            type Value = Me1LeSaveHeader;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a Me1LeSaveHeader")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                let _: Me1LeMagicNumber = seq.next_required()?;
+                let block_size = seq.next_required()?;
+                let (_, uncompressed) = decompress_chunks(&mut seq, block_size)?;
+                let save_data =
+                    unreal::Deserializer::from_bytes(&uncompressed).map_err(de::Error::custom)?;
+
+                Ok(Me1LeSaveHeader { save_data })
+            }
+        }
+        deserializer.deserialize_tuple_struct("Me1LeSaveHeader", usize::MAX, Me1LeSaveHeaderVisitor)
+    }
+}
+
 impl serde::Serialize for Me1LeSaveGame {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -202,12 +250,12 @@ pub struct Me1LeSaveData {
     character_id: String,
     created_date: SaveTimeStamp,
     pub plot: PlotTable,
-    journal: Journal,
-    codex: Codex,
-    timestamp: SaveTimeStamp,
-    seconds_played: i32,
+    pub journal: Journal,
+    pub codex: Codex,
+    pub timestamp: SaveTimeStamp,
+    pub seconds_played: i32,
     pub player: Player,
-    base_level_name: String,
+    pub base_level_name: String,
     map_name: String,
     parent_map_name: String,
     location: Vector,
@@ -218,6 +266,21 @@ pub struct Me1LeSaveData {
     pub no_export: NoExport, // Only serialized for normal savegames, not for character export
 }
 
+// The start of `Me1LeSaveData`
+#[derive(Deserialize)]
+pub struct Me1LeSaveDataHeader {
+    _version: Me1LeVersion,
+    _character_id: String,
+    _created_date: SaveTimeStamp,
+    _plot: PlotTable,
+    _journal: Journal,
+    _codex: Codex,
+    pub timestamp: SaveTimeStamp,
+    pub seconds_played: i32,
+    pub player: Player,
+    pub base_level_name: String,
+}
+
 #[derive(Serialize, Clone)]
 pub struct Me1LeVersion(i32);
 
@@ -239,11 +302,27 @@ impl<'de> Deserialize<'de> for Me1LeVersion {
 }
 
 #[derive(Clone)]
-pub struct NoExport(Option<RcRef<NoExportData>>);
+pub enum NoExport {
+    Data(RcRef<NoExportData>),
+    // The legacy objects have no length, one of an unknown class makes the rest unreadable.
+    // The raw data is kept to be written back unchanged.
+    Unknown(List<u8>, String),
+    None,
+}
 
 impl NoExport {
     pub fn as_ref(&self) -> Option<&RcRef<NoExportData>> {
-        self.0.as_ref()
+        match self {
+            NoExport::Data(no_export_data) => Some(no_export_data),
+            NoExport::Unknown(..) | NoExport::None => None,
+        }
+    }
+
+    pub fn unknown_error(&self) -> Option<&str> {
+        match self {
+            NoExport::Unknown(_, error) => Some(error),
+            NoExport::Data(_) | NoExport::None => None,
+        }
     }
 }
 
@@ -264,15 +343,19 @@ impl<'de> Deserialize<'de> for NoExport {
             where
                 D: Deserializer<'de>,
             {
-                let no_export_data: NoExportData = Deserialize::deserialize(deserializer)?;
-                Ok(NoExport(Some(no_export_data.into())))
+                let raw: List<u8> = Deserialize::deserialize(deserializer)?;
+                let no_export = match unreal::Deserializer::from_bytes::<NoExportData>(&raw) {
+                    Ok(no_export_data) => NoExport::Data(no_export_data.into()),
+                    Err(err) => NoExport::Unknown(raw, err.to_string()),
+                };
+                Ok(no_export)
             }
 
             fn visit_none<E>(self) -> Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(NoExport(None))
+                Ok(NoExport::None)
             }
         }
         deserializer.deserialize_any(NoExportVisitor)
@@ -284,9 +367,10 @@ impl serde::Serialize for NoExport {
     where
         S: Serializer,
     {
-        match self.0 {
-            Some(ref no_export_data) => no_export_data.serialize(serializer),
-            None => serializer.serialize_unit(),
+        match self {
+            NoExport::Data(no_export_data) => no_export_data.serialize(serializer),
+            NoExport::Unknown(raw, _) => serializer.serialize_bytes(raw),
+            NoExport::None => serializer.serialize_unit(),
         }
     }
 }
@@ -294,7 +378,7 @@ impl serde::Serialize for NoExport {
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, RawUiChildren)]
 pub struct NoExportData {
-    legacy_maps: IndexMap<String, Map>,
+    pub legacy_maps: IndexMap<String, Map>,
     mako: Vehicle,
 }
 
@@ -316,7 +400,10 @@ mod test {
     use crc::{Crc, CRC_32_BZIP2};
 
     use super::*;
-    use crate::unreal;
+    use crate::{
+        save_data::mass_effect_1_le::{item_db::DbItem, legacy::Object},
+        unreal,
+    };
 
     #[test]
     fn deserialize_serialize() -> Result<()> {
@@ -389,6 +476,202 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn shops() -> Result<()> {
+        let input = fs::read("test/ME1LeSave.pcsav")?;
+        let me1_save_game: Me1LeSaveGame = unreal::Deserializer::from_bytes(&input)?;
+
+        let shops: Vec<_> = {
+            let save_data = me1_save_game.save_data.borrow();
+            let no_export = save_data.no_export();
+            let no_export = no_export.as_ref().unwrap().borrow();
+            let legacy_maps = no_export.legacy_maps();
+            legacy_maps.values().flat_map(|map| map.borrow().shops()).collect()
+        };
+        assert!(!shops.is_empty());
+
+        let shop = shops.iter().find(|shop| !shop.shop.borrow().stocks().is_empty()).unwrap();
+        let shop = shop.shop.borrow();
+        let stock = &shop.stocks()[0];
+        let len = stock.borrow().items().len();
+
+        // Add
+        let pistol = DbItem { item_id: 3, manufacturer_id: 327 };
+        assert!(shop.add_item(stock, pistol, "[Armax Arsenal] Brawler Pistol"));
+        assert!(!shop.add_item(stock, pistol, "[Grenade]"));
+        assert_eq!(stock.borrow().items().len(), len + 1);
+
+        let new_item = RcRef::clone(&stock.borrow().items()[len]);
+        assert_eq!(
+            *new_item.borrow().owner_class().as_ref().unwrap().borrow(),
+            "BioItemWeaponRanged"
+        );
+        let owner_names: Vec<_> =
+            stock.borrow().items().iter().map(|item| item.borrow().owner_name().clone()).collect();
+        assert_eq!(owner_names.iter().filter(|name| **name == owner_names[len]).count(), 1);
+
+        // Set to another class
+        let armor = DbItem { item_id: 287, manufacturer_id: 374 };
+        assert!(shop.set_item(stock, len, armor, "[Aldrin Labs] Hydra (Human Light Armor)"));
+        let new_item = RcRef::clone(&stock.borrow().items()[len]);
+        assert_eq!(
+            *new_item.borrow().owner_class().as_ref().unwrap().borrow(),
+            "BioItemArmorShielded"
+        );
+        drop(shop);
+
+        // Serialize / deserialize
+        let output = unreal::Serializer::to_vec(&me1_save_game)?;
+        let _: Me1LeSaveGame = unreal::Deserializer::from_bytes(&output)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn inventory_cap() -> Result<()> {
+        let input = fs::read("test/ME1LeSave.pcsav")?;
+        let me1_save_game: Me1LeSaveGame = unreal::Deserializer::from_bytes(&input)?;
+
+        let save_data = me1_save_game.save_data.borrow();
+        let player = save_data.player();
+        let inventory = player.inventory();
+        assert!(!inventory.is_full());
+
+        let mut items = vec![Default::default(); INVENTORY_CAP - inventory.inventory().len()];
+        assert!(!inventory.add_items(&mut vec![Default::default(); items.len() + 1]));
+        assert!(inventory.add_items(&mut items));
+        assert!(items.is_empty());
+        assert!(inventory.is_full());
+        assert!(!inventory.add_items(&mut vec![Default::default()]));
+        assert_eq!(inventory.inventory().len(), INVENTORY_CAP);
+
+        Ok(())
+    }
+
+    #[test]
+    fn talents() -> Result<()> {
+        let input = fs::read("test/ME1LeSave.pcsav")?;
+        let me1_save_game: Me1LeSaveGame = unreal::Deserializer::from_bytes(&input)?;
+
+        let save_data = me1_save_game.save_data.borrow();
+        let squad = save_data.squad();
+        let liara =
+            squad.iter().find(|henchman| *henchman.borrow().tag() == "hench_asari").unwrap();
+        let liara = liara.borrow();
+        let talents = liara.complex_talents();
+        let idx = |talent_id| {
+            talents.iter().position(|talent| talent.borrow().talent_id() == talent_id).unwrap()
+        };
+        let rank = |talent_id| talents[idx(talent_id)].borrow().current_rank();
+        // Throw 8, Lift 12 requires Throw 6
+        assert_eq!((rank(49), rank(50)), (8, 12));
+        let level = liara.level();
+
+        // Within `max_rank`, the level and the available points
+        assert_eq!(ComplexTalent::respec(&talents, idx(49), 20, level, 2), -2);
+        assert_eq!(rank(49), 10);
+        assert_eq!(ComplexTalent::respec(&talents, idx(49), 12, level, 0), 0);
+        assert_eq!(rank(49), 10);
+
+        // Lowering a prerequisite resets its dependents
+        assert_eq!(ComplexTalent::respec(&talents, idx(49), 5, level, 0), 5 + 12);
+        assert_eq!((rank(49), rank(50)), (5, 0));
+        assert!(!talents[idx(50)].borrow().prerequisites_met(&talents));
+        assert_eq!(ComplexTalent::respec(&talents, idx(50), 3, level, 10), 0);
+        assert_eq!(rank(50), 0);
+
+        assert_eq!(ComplexTalent::respec(&talents, idx(49), 6, level, 10), -1);
+        assert_eq!(ComplexTalent::respec(&talents, idx(50), 3, level, 10), -3);
+        assert_eq!(rank(50), 3);
+
+        // Lift unlocks its ranks from level 5
+        assert_eq!(talents[idx(50)].borrow().max_rank_at(4), 0);
+        assert_eq!(talents[idx(50)].borrow().max_rank_at(7), 3);
+        assert_eq!(ComplexTalent::respec(&talents, idx(50), 12, 6, 10), 1);
+        assert_eq!(rank(50), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn objects() -> Result<()> {
+        let input = fs::read("test/ME1LeSave.pcsav")?;
+        let me1_save_game: Me1LeSaveGame = unreal::Deserializer::from_bytes(&input)?;
+
+        let save_data = me1_save_game.save_data.borrow();
+        let no_export = save_data.no_export();
+        let no_export = no_export.as_ref().unwrap().borrow();
+        let legacy_maps = no_export.legacy_maps();
+        let objects: Vec<_> = legacy_maps.values().flat_map(|map| map.borrow().objects()).collect();
+
+        // Nested objects are flattened too
+        let top_level: usize = legacy_maps
+            .values()
+            .flat_map(|map| {
+                map.borrow()
+                    .levels()
+                    .values()
+                    .map(|level| level.borrow().objects().len())
+                    .collect::<Vec<_>>()
+            })
+            .sum();
+        assert!(objects.len() > top_level);
+        let has_class = |class_name| {
+            objects.iter().any(|object| object.object.borrow()._class_name == class_name)
+        };
+        assert!(has_class("BioPawnSaveObject"));
+        assert!(has_class("BioWorldInfoSaveObject"));
+
+        // Revive
+        let behavior = objects
+            .iter()
+            .find_map(|object| match object.object.borrow()._object {
+                Object::PawnBehavior(ref behavior) => Some(RcRef::clone(behavior)),
+                _ => None,
+            })
+            .unwrap();
+        let behavior = behavior.borrow();
+        behavior.is_dead.set(true);
+        behavior.health.set(0.0);
+        behavior.revive();
+        assert!(!behavior.is_dead());
+        assert!(behavior.health() > 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_object() -> Result<()> {
+        let input = fs::read("test/ME1LeSave.pcsav")?;
+        let me1_save_game: Me1LeSaveGame = unreal::Deserializer::from_bytes(&input)?;
+
+        // Rename a class to one the editor doesn't know
+        {
+            let save_data = me1_save_game.save_data.borrow();
+            let no_export = save_data.no_export();
+            let no_export = no_export.as_ref().unwrap().borrow();
+            let legacy_maps = no_export.legacy_maps();
+            let map = legacy_maps.values().next().unwrap().borrow();
+            let levels = map.levels();
+            let level = levels.values().next().unwrap().borrow();
+            level.objects()[0].borrow_mut()._class_name = "BioUnknownSaveObject".to_owned();
+        }
+        let output = unreal::Serializer::to_vec(&me1_save_game)?;
+
+        // Kept as is
+        let me1_save_game: Me1LeSaveGame = unreal::Deserializer::from_bytes(&output)?;
+        {
+            let save_data = me1_save_game.save_data.borrow();
+            let no_export = save_data.no_export();
+            assert!(no_export.as_ref().is_none());
+            assert!(no_export.unknown_error().unwrap().contains("BioUnknownSaveObject"));
+        }
+        let output_2 = unreal::Serializer::to_vec(&me1_save_game)?;
+        assert!(output == output_2);
+
+        Ok(())
+    }
+
     // #[test]
     // fn uncompress() -> Result<()> {
     //     let input = fs::read("test/ME1Le_Export.pcsav")?;
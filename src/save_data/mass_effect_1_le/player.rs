@@ -5,7 +5,7 @@ use crate::save_data::{
         appearance::HeadMorph,
         player::{Notoriety, Origin},
     },
-    Dummy,
+    Dummy, RcRef,
 };
 
 #[rcize_fields]
@@ -91,8 +91,76 @@ pub struct ComplexTalent {
     pub level_offset: i32,
     pub levels_per_rank: i32,
     pub visual_order: i32,
-    prereq_talent_ids: Vec<i32>,
-    prereq_talent_ranks: Vec<i32>,
+    pub prereq_talent_ids: Vec<i32>,
+    pub prereq_talent_ranks: Vec<i32>,
+}
+
+impl ComplexTalent {
+    // A missing prerequisite talent counts as rank 0
+    pub fn prerequisites_met(&self, talents: &[RcRef<ComplexTalent>]) -> bool {
+        let prereq_ranks = self.prereq_talent_ranks();
+        self.prereq_talent_ids().iter().zip(prereq_ranks.iter()).all(|(id, rank)| {
+            talents.iter().any(|talent| {
+                let talent = talent.borrow();
+                talent.talent_id() == id.get() && talent.current_rank() >= rank.get()
+            })
+        })
+    }
+
+    // Each rank takes `levels_per_rank` levels past `level_offset`, up to `max_rank`
+    pub fn max_rank_at(&self, level: i32) -> i32 {
+        let unlocked = (level - self.level_offset()) / self.levels_per_rank().max(1);
+        unlocked.clamp(0, self.max_rank().max(0))
+    }
+
+    // Changes the rank of `talents[idx]` while keeping the build legal: the rank stays within
+    // `max_rank`, the character level, the prerequisites and the available points, the talents
+    // that no longer meet their prerequisites are reset. Returns the refund to add to the
+    // available points.
+    pub fn respec(
+        talents: &[RcRef<ComplexTalent>], idx: usize, rank: i32, level: i32, available: i32,
+    ) -> i32 {
+        let talent = match talents.get(idx) {
+            Some(talent) => talent,
+            None => return 0,
+        };
+
+        let current_rank = talent.borrow().current_rank();
+        let max_rank = if talent.borrow().prerequisites_met(talents) {
+            talent.borrow().max_rank_at(level)
+        } else {
+            0
+        };
+        let mut rank = rank.clamp(0, max_rank);
+        if rank > current_rank {
+            rank = rank.min(current_rank + available.max(0));
+        }
+        talent.borrow_mut().set_current_rank(rank);
+
+        let mut refund = current_rank - rank;
+        // Lowering a rank can break the prerequisites of other talents
+        while let Some(talent) = talents.iter().find(|talent| {
+            let talent = talent.borrow();
+            talent.current_rank() > 0 && !talent.prerequisites_met(talents)
+        }) {
+            refund += talent.borrow().current_rank();
+            talent.borrow_mut().set_current_rank(0);
+        }
+        refund
+    }
+
+    // Reset the talents to rank 0 and returns the refunded talent points
+    pub fn reset_ranks(talents: &[RcRef<ComplexTalent>]) -> i32 {
+        talents
+            .iter()
+            .map(|talent| {
+                let mut talent = talent.borrow_mut();
+                let refund = talent.current_rank();
+                talent.set_current_rank(0);
+                refund
+            })
+            .sum()
+    }
 }
 
 #[rcize_fields]
@@ -104,6 +172,28 @@ pub struct Inventory {
     pub buy_pack: Vec<Item>,
 }
 
+// The game discards the items past this when loading the save
+pub const INVENTORY_CAP: usize = 150;
+
+impl Inventory {
+    pub fn has_room(&self, count: usize) -> bool {
+        self.inventory().len() + count <= INVENTORY_CAP
+    }
+
+    pub fn is_full(&self) -> bool {
+        !self.has_room(1)
+    }
+
+    // Moves the items to the inventory if they all fit under the cap
+    pub fn add_items(&self, items: &mut Vec<RcRef<Item>>) -> bool {
+        let has_room = self.has_room(items.len());
+        if has_room {
+            self.inventory.borrow_mut().append(items);
+        }
+        has_room
+    }
+}
+
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Deserialize, Serialize, Copy, Clone, RawUi)]
 pub enum ItemLevel {
@@ -135,7 +225,7 @@ pub struct Item {
     pub manufacturer_id: i32,
     pub plot_conditional_id: i32,
     pub new_item: bool,
-    junk: bool,
+    pub junk: bool,
     pub attached_mods: Vec<ItemMod>,
 }
 
@@ -7,7 +7,7 @@ use super::player::{ComplexTalent, Item, SimpleTalent};
 #[display(fmt = "{}", tag)]
 pub struct Henchman {
     pub tag: String,
-    simple_talents: Vec<SimpleTalent>,
+    pub simple_talents: Vec<SimpleTalent>,
     pub complex_talents: Vec<ComplexTalent>,
     pub equipment: Vec<Item>,
     pub quick_slots: Vec<Item>,
@@ -30,8 +30,30 @@ pub struct Henchman {
     attribute_secondary: u8,
     health: f32,
     shield: f32,
-    level: i32,
+    pub level: i32,
     helmet_shown: bool,
     current_quick_slot: u8,
     health_max: f32,
 }
+
+impl Henchman {
+    pub fn name(&self) -> String {
+        let tag = self.tag();
+        henchman_name(&tag).map(str::to_owned).unwrap_or_else(|| tag.clone())
+    }
+}
+
+// Shared by ME1 and ME1LE, the squad mates have the same tags
+pub fn henchman_name(tag: &str) -> Option<&'static str> {
+    let name = match tag {
+        "hench_asari" => "Liara",
+        "hench_humanfemale" => "Ashley",
+        "hench_humanmale" => "Kaidan",
+        "hench_jenkins" => "Jenkins",
+        "hench_krogan" => "Wrex",
+        "hench_quarian" => "Tali",
+        "hench_turian" => "Garrus",
+        _ => return None,
+    };
+    Some(name)
+}
@@ -0,0 +1,31 @@
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, PartialEq, Eq)]
+pub struct DbTalent {
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Deserialize, Deref, From, PartialEq, Eq)]
+pub struct Me1LeTalentDb(IndexMap<i32, DbTalent>);
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use anyhow::Result;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_talent_db() -> Result<()> {
+        let input = fs::read_to_string("databases/me1_le_talent_db.ron")?;
+        let me1_le_talent_db: Me1LeTalentDb = ron::from_str(&input)?;
+
+        let throw = me1_le_talent_db.get(&49);
+        assert_eq!(throw.map(|talent| talent.name.as_str()), Some("Throw"));
+
+        Ok(())
+    }
+}
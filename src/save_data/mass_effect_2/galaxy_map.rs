@@ -5,14 +5,34 @@ use crate::save_data::shared::Vector2d;
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, RawUi)]
 pub struct GalaxyMap {
-    planets: Vec<Planet>,
+    pub planets: Vec<Planet>,
+}
+
+impl GalaxyMap {
+    // Only the planets already in the save, the ids of the others aren't known
+    pub fn visit_all(&self) {
+        for planet in self.planets().iter() {
+            planet.borrow_mut().set_visited(true);
+        }
+    }
+
+    pub fn remove_probes(&self) -> usize {
+        self.planets().iter().map(|planet| planet.borrow_mut().probes_mut().drain(..).count()).sum()
+    }
 }
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "{}", id)]
 pub struct Planet {
-    id: i32,
-    visited: bool,
-    probes: Vec<Vector2d>,
+    pub id: i32,
+    pub visited: bool,
+    pub probes: Vec<Vector2d>,
+}
+
+impl Planet {
+    // `cluster * 10000 + system * 100 + planet` => `cluster * 10000 + system * 100`
+    pub fn system_id(&self) -> i32 {
+        self.id() - self.id() % 100
+    }
 }
@@ -1,7 +1,7 @@
-mod galaxy_map;
+pub mod galaxy_map;
 pub mod player;
 pub mod plot_db;
-mod squad;
+pub mod squad;
 
 use self::{galaxy_map::*, player::*, squad::*};
 
@@ -19,12 +19,12 @@ use super::Guid;
 pub struct Me2SaveGame {
     _version: Me2Version,
     debug_name: String,
-    seconds_played: f32,
+    pub seconds_played: f32,
     disc: i32,
-    base_level_name: String,
+    pub base_level_name: String,
     pub difficulty: Difficulty,
     pub end_game_state: EndGameState,
-    timestamp: SaveTimeStamp,
+    pub timestamp: SaveTimeStamp,
     location: Vector,
     rotation: Rotator,
     current_loading_tip: i32,
@@ -34,12 +34,12 @@ pub struct Me2SaveGame {
     doors: Vec<Door>,
     pawns: Vec<Guid>,
     pub player: Player,
-    squad: Vec<Henchman>,
+    pub squad: Vec<Henchman>,
     pub plot: PlotTable,
-    journal: Journal,
-    codex: Codex,
+    pub journal: Journal,
+    pub codex: Codex,
     pub me1_plot: PlotTable,
-    galaxy_map: GalaxyMap,
+    pub galaxy_map: GalaxyMap,
     dependant_dlcs: Vec<DependentDlc>,
 }
 
@@ -78,12 +78,12 @@ impl<'de> Deserialize<'de> for Me2Version {
 pub struct Me2LeSaveGame {
     _version: Me2LeVersion,
     debug_name: String,
-    seconds_played: f32,
+    pub seconds_played: f32,
     disc: i32,
-    base_level_name: String,
+    pub base_level_name: String,
     pub difficulty: Difficulty,
     pub end_game_state: EndGameState,
-    timestamp: SaveTimeStamp,
+    pub timestamp: SaveTimeStamp,
     location: Vector,
     rotation: Rotator,
     current_loading_tip: i32,
@@ -94,12 +94,12 @@ pub struct Me2LeSaveGame {
     pawns: Vec<Guid>,
     pub player: Player,
     me1_import_bonus: Me1ImportBonus,
-    squad: Vec<Henchman>,
+    pub squad: Vec<Henchman>,
     pub plot: PlotTable,
-    journal: Journal,
-    codex: Codex,
+    pub journal: Journal,
+    pub codex: Codex,
     pub me1_plot: PlotTable,
-    galaxy_map: GalaxyMap,
+    pub galaxy_map: GalaxyMap,
     dependant_dlcs: Vec<DependentDlc>,
 }
 
@@ -135,6 +135,28 @@ impl<'de> Deserialize<'de> for Me2LeVersion {
     }
 }
 
+// The start of `Me2SaveGame` and `Me2LeSaveGame`, enough to list a save without reading it all
+#[derive(Deserialize)]
+pub struct Me2SaveHeader {
+    _version: i32,
+    _debug_name: String,
+    pub seconds_played: f32,
+    _disc: i32,
+    pub base_level_name: String,
+    pub difficulty: Difficulty,
+    _end_game_state: EndGameState,
+    pub timestamp: SaveTimeStamp,
+    _location: Vector,
+    _rotation: Rotator,
+    _current_loading_tip: i32,
+    _levels: Vec<Level>,
+    _streaming_states: Vec<StreamingState>,
+    _kismet_records: Vec<Kismet>,
+    _doors: Vec<Door>,
+    _pawns: Vec<Guid>,
+    pub player: PlayerHeader,
+}
+
 #[derive(Deserialize, Serialize, Clone, RawUi)]
 pub enum Difficulty {
     Casual,
@@ -160,7 +182,7 @@ mod test {
     use crc::{Crc, CRC_32_BZIP2};
 
     use super::*;
-    use crate::unreal;
+    use crate::{save_data::shared::squad_db::SquadDb, unreal};
 
     #[test]
     fn deserialize_serialize_vanilla() -> Result<()> {
@@ -256,4 +278,27 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn reset_talents() -> Result<()> {
+        let input = fs::read_to_string("databases/me2_squad_db.ron")?;
+        let squad_db: SquadDb = ron::from_str(&input)?;
+
+        let input = fs::read("test/ME2Save.pcsav")?;
+        let me2_save_game: Me2SaveGame = unreal::Deserializer::from_bytes(&input)?;
+
+        // Jacob: Pull and his passive are evolved, loyalty is free
+        let squad = me2_save_game.squad();
+        let jacob = squad.iter().find(|character| *character.borrow().tag() == "hench_leading");
+        let mut jacob = jacob.unwrap().borrow_mut();
+        let base_ranks = squad_db.base_ranks("hench_leading");
+        assert_eq!(Power::reset_ranks(&mut jacob.powers_mut(), &base_ranks), 31);
+        assert_eq!(jacob.powers().len(), 5);
+        assert!(jacob.powers().iter().all(|power| {
+            let power = power.borrow();
+            power.rank() == 0.0 || *power.name() == "LoyaltyRequirement"
+        }));
+
+        Ok(())
+    }
 }
@@ -1,8 +1,14 @@
+use std::mem;
+
 use serde::{Deserialize, Serialize};
 
-use crate::save_data::shared::{
-    appearance::Appearance,
-    player::{Notoriety, Origin, WeaponLoadout},
+use crate::save_data::{
+    shared::{
+        appearance::Appearance,
+        player::{Notoriety, Origin, WeaponLoadout},
+        player_class_db::BaseRanks,
+    },
+    RcRef,
 };
 
 #[rcize_fields]
@@ -17,14 +23,14 @@ pub struct Player {
     pub origin: Origin,
     pub notoriety: Notoriety,
     pub talent_points: i32,
-    mapped_power_1: String,
-    mapped_power_2: String,
-    mapped_power_3: String,
+    pub mapped_power_1: String,
+    pub mapped_power_2: String,
+    pub mapped_power_3: String,
     pub appearance: Appearance,
     pub powers: Vec<Power>,
     weapons: Vec<Weapon>,
     weapons_loadout: WeaponLoadout,
-    hotkeys: Vec<Hotkey>,
+    pub hotkeys: Vec<Hotkey>,
     pub credits: i32,
     pub medigel: i32,
     pub eezo: i32,
@@ -34,7 +40,17 @@ pub struct Player {
     pub probes: i32,
     pub current_fuel: f32,
     pub face_code: String,
-    localized_class_name: i32,
+    pub localized_class_name: i32,
+}
+
+// The start of `Player`
+#[derive(Deserialize)]
+pub struct PlayerHeader {
+    _is_female: bool,
+    pub class_name: String,
+    pub level: i32,
+    _current_xp: f32,
+    pub first_name: String,
 }
 
 #[rcize_fields]
@@ -42,11 +58,42 @@ pub struct Player {
 #[display(fmt = "{}", name)]
 pub struct Power {
     pub name: String,
-    rank: f32,
+    pub rank: f32,
     pub power_class_name: String,
     wheel_display_index: i32,
 }
 
+impl Power {
+    // Evolving a power moves its ranks to a new rank 4 power
+    pub const EVOLVED_RANK: i32 = 4;
+
+    // Rank 1 costs 1 point, rank 2 costs 2 points, etc.
+    pub fn rank_cost(rank: i32) -> i32 {
+        (1..=rank).sum()
+    }
+
+    // Reset the powers to their base rank, remove the evolved ones and returns the refunded talent points
+    pub fn reset_ranks(powers: &mut Vec<RcRef<Power>>, base_ranks: &BaseRanks<'_>) -> i32 {
+        let mut refund = 0;
+        for power in mem::take(powers) {
+            {
+                let mut power = power.borrow_mut();
+                let rank = power.rank() as i32;
+                let base_rank = base_ranks.reset_rank(&power.power_class_name(), rank);
+                if let Some(base_rank) = base_rank {
+                    refund += Self::rank_cost(rank) - Self::rank_cost(base_rank);
+                    if rank >= Self::EVOLVED_RANK {
+                        continue;
+                    }
+                    power.set_rank(base_rank as f32);
+                }
+            }
+            powers.push(power);
+        }
+        refund
+    }
+}
+
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "{}", class_name)]
@@ -62,7 +109,7 @@ struct Weapon {
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "")]
-struct Hotkey {
-    pawn_name: String,
-    power_id: i32,
+pub struct Hotkey {
+    pub pawn_name: String,
+    pub power_id: i32,
 }
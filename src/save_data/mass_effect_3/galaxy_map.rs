@@ -5,25 +5,58 @@ use crate::save_data::shared::Vector2d;
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, RawUi)]
 pub struct GalaxyMap {
-    planets: Vec<Planet>,
-    systems: Vec<System>,
+    pub planets: Vec<Planet>,
+    pub systems: Vec<System>,
+}
+
+impl GalaxyMap {
+    // Only the planets already in the save, the ids of the others aren't known
+    pub fn visit_all(&self) {
+        for planet in self.planets().iter() {
+            planet.borrow_mut().set_visited(true);
+        }
+    }
+
+    pub fn scan_all(&self) {
+        for planet in self.planets().iter() {
+            planet.borrow_mut().set_show_as_scanned(true);
+        }
+    }
+
+    pub fn remove_probes(&self) -> usize {
+        self.planets().iter().map(|planet| planet.borrow_mut().probes_mut().drain(..).count()).sum()
+    }
 }
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "{}", id)]
 pub struct Planet {
-    id: i32,
-    visited: bool,
-    probes: Vec<Vector2d>,
-    show_as_scanned: bool,
+    pub id: i32,
+    pub visited: bool,
+    pub probes: Vec<Vector2d>,
+    pub show_as_scanned: bool,
+}
+
+impl Planet {
+    // `cluster * 10000 + system * 100 + planet` => `cluster * 10000 + system * 100`
+    pub fn system_id(&self) -> i32 {
+        self.id() - self.id() % 100
+    }
 }
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "{}", id)]
 pub struct System {
-    id: i32,
-    reaper_alert_level: f32,
-    reaper_detected: bool,
+    pub id: i32,
+    pub reaper_alert_level: f32,
+    pub reaper_detected: bool,
+}
+
+impl System {
+    pub fn clear_reaper_alert(&mut self) {
+        self.set_reaper_alert_level(0.0);
+        self.set_reaper_detected(false);
+    }
 }
@@ -1,19 +1,25 @@
-mod galaxy_map;
+pub mod galaxy_map;
 pub mod player;
+pub mod player_variable_db;
 pub mod plot;
 pub mod plot_db;
+pub mod power_db;
+pub mod squad;
 pub mod war_asset;
-mod squad;
+pub mod war_asset_db;
+pub mod weapon_db;
 
-use self::{galaxy_map::*, player::*, plot::*, squad::*};
+use self::{galaxy_map::*, player::*, plot::*, squad::*, weapon_db::Me3WeaponDb};
+
+use std::mem;
 
 use anyhow::Result;
 use indexmap::IndexMap;
 use serde::{de, Deserialize, Deserializer, Serialize};
 
 use super::shared::{
-    plot::PlotTable as Me1PlotTable, Door, EndGameState, Kismet, Level, Rotator, SaveTimeStamp,
-    StreamingState, Vector,
+    plot::{Codex, PlotTable as Me1PlotTable},
+    Door, EndGameState, Kismet, Level, Rotator, SaveTimeStamp, StreamingState, Vector,
 };
 use super::Guid;
 
@@ -22,13 +28,13 @@ use super::Guid;
 pub struct Me3SaveGame {
     _version: Me3Version,
     debug_name: String,
-    seconds_played: f32,
+    pub seconds_played: f32,
     disc: i32,
-    base_level_name: String,
+    pub base_level_name: String,
     base_level_name_display_override: String,
     pub difficulty: Difficulty,
     pub end_game_state: EndGameState,
-    timestamp: SaveTimeStamp,
+    pub timestamp: SaveTimeStamp,
     location: Vector,
     rotation: Rotator,
     current_loading_tip: i32,
@@ -39,21 +45,46 @@ pub struct Me3SaveGame {
     placeables: Vec<Placeable>,
     pawns: Vec<Guid>,
     pub player: Player,
-    squad: Vec<Henchman>,
+    pub squad: Vec<Henchman>,
     pub plot: PlotTable,
-    journal: Journal,
-    codex: Codex,
+    pub journal: Journal,
+    pub codex: Codex,
     _me1_plot: Me1PlotTable,
     pub player_variables: IndexMap<String, i32>,
-    galaxy_map: GalaxyMap,
+    pub galaxy_map: GalaxyMap,
     dependant_dlcs: Vec<DependentDlc>,
-    treasures: Vec<LevelTreasure>,
+    pub treasures: Vec<LevelTreasure>,
     use_modules: Vec<Guid>,
     pub conversation_mode: AutoReplyModeOptions,
     objective_markers: Vec<ObjectiveMarker>,
     saved_objective_text: i32,
 }
 
+impl Me3SaveGame {
+    // Pending rewards are given to the player, only weapons exist as items in the save
+    pub fn claim_treasures(&self, weapon_db: &Me3WeaponDb) {
+        let treasures = mem::take(&mut *self.treasures.borrow_mut());
+        let mut player = self.player.borrow_mut();
+
+        for treasure in treasures {
+            let treasure = treasure.borrow();
+            player.credits.update(|credits| credits + treasure.credits());
+            player.current_xp.update(|xp| xp + treasure.xp() as f32);
+
+            for item in treasure.items().iter() {
+                let item = item.borrow();
+                let owned =
+                    player.weapons().iter().any(|weapon| *weapon.borrow().class_name() == *item);
+                if !owned && weapon_db.weapons.contains_key(&*item) {
+                    let mut weapon = Weapon::default();
+                    *weapon.class_name_mut() = item.clone();
+                    player.weapons_mut().push(weapon.into());
+                }
+            }
+        }
+    }
+}
+
 #[derive(Serialize, Clone)]
 pub struct Me3Version {
     version: i32,
@@ -84,6 +115,30 @@ impl<'de> Deserialize<'de> for Me3Version {
     }
 }
 
+// The start of `Me3SaveGame`, enough to list a save without reading it all
+#[derive(Deserialize)]
+pub struct Me3SaveHeader {
+    _version: i32,
+    _debug_name: String,
+    pub seconds_played: f32,
+    _disc: i32,
+    pub base_level_name: String,
+    _base_level_name_display_override: String,
+    pub difficulty: Difficulty,
+    _end_game_state: EndGameState,
+    pub timestamp: SaveTimeStamp,
+    _location: Vector,
+    _rotation: Rotator,
+    _current_loading_tip: i32,
+    _levels: Vec<Level>,
+    _streaming_states: Vec<StreamingState>,
+    _kismet_records: Vec<Kismet>,
+    _doors: Vec<Door>,
+    _placeables: Vec<Placeable>,
+    _pawns: Vec<Guid>,
+    pub player: PlayerHeader,
+}
+
 #[derive(Deserialize, Serialize, Clone, RawUi)]
 pub enum Difficulty {
     Narrative,
@@ -126,11 +181,11 @@ struct DependentDlc {
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "{}", level_name)]
-struct LevelTreasure {
-    level_name: String,
-    credits: i32,
-    xp: i32,
-    items: Vec<String>,
+pub struct LevelTreasure {
+    pub level_name: String,
+    pub credits: i32,
+    pub xp: i32,
+    pub items: Vec<String>,
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -174,7 +229,10 @@ mod test {
     use crc::{Crc, CRC_32_BZIP2};
 
     use super::*;
-    use crate::unreal;
+    use crate::{
+        save_data::shared::{player_class_db::PlayerClassDb, squad_db::SquadDb},
+        unreal,
+    };
 
     #[test]
     fn deserialize_serialize() -> Result<()> {
@@ -241,4 +299,166 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn war_assets() -> Result<()> {
+        let input = fs::read("test/ME3Save.pcsav")?;
+        let me3_save_game: Me3SaveGame = unreal::Deserializer::from_bytes(&input)?;
+
+        let input = fs::read_to_string("databases/me3_war_asset_db.ron")?;
+        let war_asset_db: war_asset_db::Me3WarAssetDb = ron::from_str(&input)?;
+
+        // DLC war assets are not in the database yet
+        let player = me3_save_game.player();
+        let war_assets = player.war_assets();
+        let known = war_assets.keys().filter(|war_asset| war_asset_db.contains_key(&war_asset.0));
+        assert!(known.count() > 100);
+
+        Ok(())
+    }
+
+    #[test]
+    fn weapons() -> Result<()> {
+        let input = fs::read("test/ME3Save.pcsav")?;
+        let me3_save_game: Me3SaveGame = unreal::Deserializer::from_bytes(&input)?;
+
+        let input = fs::read_to_string("databases/me3_weapon_db.ron")?;
+        let weapon_db: weapon_db::Me3WeaponDb = ron::from_str(&input)?;
+
+        let player = me3_save_game.player();
+        for weapon_mod in player.weapons_mods().iter() {
+            let weapon_mod = weapon_mod.borrow();
+            let weapon_class_name = weapon_mod.weapon_class_name();
+            assert!(weapon_db.weapons.contains_key(&*weapon_class_name));
+
+            let compatible = weapon_db.compatible_mods(&weapon_class_name);
+            for mod_class_name in weapon_mod.weapon_mod_class_names().iter() {
+                let mod_class_name = mod_class_name.borrow();
+                assert!(compatible.iter().any(|(class_name, _)| **class_name == *mod_class_name));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn powers() -> Result<()> {
+        let input = fs::read("test/ME3Save.pcsav")?;
+        let me3_save_game: Me3SaveGame = unreal::Deserializer::from_bytes(&input)?;
+
+        let input = fs::read_to_string("databases/me3_power_db.ron")?;
+        let power_db: power_db::Me3PowerDb = ron::from_str(&input)?;
+
+        let player = me3_save_game.player();
+        for power in player.powers().iter() {
+            assert!(power_db.get_power(&power.borrow().power_class_name()).is_some());
+        }
+
+        // Pull: rank 6, second, second and first evolutions
+        let pull = player.powers().iter().find(|power| *power.borrow().name() == "Pull").cloned();
+        let mut pull = pull.unwrap().borrow_mut().clone();
+        assert_eq!(pull.evolution(0), Some(1));
+        assert_eq!(pull.evolution(1), Some(1));
+        assert_eq!(pull.evolution(2), Some(0));
+
+        // Respec to rank 4 refunds ranks 5 and 6
+        assert_eq!(pull.respec(4), 11);
+        assert_eq!(pull.evolution(0), Some(1));
+        assert_eq!(pull.evolution(1), None);
+        assert_eq!(pull.evolution(2), None);
+
+        // Back to rank 6 defaults to the first evolutions
+        assert_eq!(pull.respec(6), -11);
+        assert_eq!(pull.evolution(1), Some(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn reset_talents() -> Result<()> {
+        let input = fs::read_to_string("databases/me3_player_class_db.ron")?;
+        let player_class_db: PlayerClassDb = ron::from_str(&input)?;
+        let input = fs::read_to_string("databases/me3_squad_db.ron")?;
+        let squad_db: SquadDb = ron::from_str(&input)?;
+
+        let input = fs::read("test/ME3Save.pcsav")?;
+        let me3_save_game: Me3SaveGame = unreal::Deserializer::from_bytes(&input)?;
+
+        // Player: everything but Unity is bought
+        let player = me3_save_game.player();
+        let base_ranks = player_class_db.base_ranks(&player.class_name());
+        assert_eq!(Power::reset_ranks(&player.powers(), &base_ranks), 178);
+        assert!(player.powers().iter().all(|power| {
+            let power = power.borrow();
+            power.rank() == 0.0 || *power.name() == "Unity"
+        }));
+
+        // Squad mates: the main game ones get their first power for free, the Citadel ones don't
+        let squad = me3_save_game.squad();
+        let reset = |tag: &str| {
+            let henchman = squad.iter().find(|character| *character.borrow().tag() == tag);
+            let henchman = henchman.unwrap().borrow();
+            let refund = Power::reset_ranks(&henchman.powers(), &squad_db.base_ranks(tag));
+            let first_rank = henchman.powers()[0].borrow().rank();
+            (refund, first_rank)
+        };
+        assert_eq!(reset("hench_anderson"), (0, 1.0));
+        assert_eq!(reset("hench_kaidan"), (89, 1.0));
+        assert_eq!(reset("hench_wrex"), (87, 0.0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn galaxy_map() -> Result<()> {
+        let input = fs::read("test/ME3Save.pcsav")?;
+        let me3_save_game: Me3SaveGame = unreal::Deserializer::from_bytes(&input)?;
+
+        // No planet is added
+        let galaxy_map = me3_save_game.galaxy_map();
+        let planet_count = galaxy_map.planets().len();
+        galaxy_map.scan_all();
+        galaxy_map.visit_all();
+        assert_eq!(galaxy_map.planets().len(), planet_count);
+        assert!(galaxy_map.planets().iter().all(|planet| {
+            let planet = planet.borrow();
+            planet.visited() && planet.show_as_scanned()
+        }));
+
+        for system in galaxy_map.systems().iter() {
+            system.borrow_mut().clear_reaper_alert();
+        }
+        assert!(galaxy_map.systems().iter().all(|system| !system.borrow().reaper_detected()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn claim_treasures() -> Result<()> {
+        let input = fs::read("test/ME3Save.pcsav")?;
+        let me3_save_game: Me3SaveGame = unreal::Deserializer::from_bytes(&input)?;
+
+        let input = fs::read_to_string("databases/me3_weapon_db.ron")?;
+        let weapon_db: weapon_db::Me3WeaponDb = ron::from_str(&input)?;
+
+        let credits = me3_save_game.player().credits();
+        let pending: i32 =
+            me3_save_game.treasures().iter().map(|treasure| treasure.borrow().credits()).sum();
+
+        me3_save_game.claim_treasures(&weapon_db);
+        assert!(me3_save_game.treasures().is_empty());
+        assert_eq!(me3_save_game.player().credits(), credits + pending);
+
+        // Weapons already owned aren't given twice
+        let player = me3_save_game.player();
+        let weapons = player.weapons();
+        for weapon in weapons.iter() {
+            let class_name = weapon.borrow().class_name().clone();
+            let count =
+                weapons.iter().filter(|weapon| *weapon.borrow().class_name() == class_name).count();
+            assert_eq!(count, 1);
+        }
+
+        Ok(())
+    }
 }
@@ -1,12 +1,14 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
+
 use crate::save_data::{
+    mass_effect_3::war_asset::WarAsset,
     shared::{
         appearance::Appearance,
         player::{Notoriety, Origin, WeaponLoadout},
+        player_class_db::BaseRanks,
     },
-    mass_effect_3::war_asset::WarAsset,
-    Guid,
+    Guid, RcCell, RcRef,
 };
 
 #[rcize_fields]
@@ -24,20 +26,20 @@ pub struct Player {
     pub origin: Origin,
     pub notoriety: Notoriety,
     pub talent_points: i32,
-    mapped_power_1: String,
-    mapped_power_2: String,
-    mapped_power_3: String,
+    pub mapped_power_1: String,
+    pub mapped_power_2: String,
+    pub mapped_power_3: String,
     pub appearance: Appearance,
     emissive_id: i32,
     pub powers: Vec<Power>,
-    war_assets: IndexMap<WarAsset, i32>,
-    weapons: Vec<Weapon>,
-    weapons_mods: Vec<WeaponMod>,
-    weapons_loadout: WeaponLoadout,
+    pub war_assets: IndexMap<WarAsset, i32>,
+    pub weapons: Vec<Weapon>,
+    pub weapons_mods: Vec<WeaponMod>,
+    pub weapons_loadout: WeaponLoadout,
     primary_weapon: String,
     secondary_weapon: String,
     loadout_weapon_group: Vec<i32>,
-    hotkeys: Vec<Hotkey>,
+    pub hotkeys: Vec<Hotkey>,
     health: f32,
     pub credits: i32,
     pub medigel: i32,
@@ -49,31 +51,114 @@ pub struct Player {
     pub current_fuel: f32,
     pub grenades: i32,
     pub face_code: String,
-    localized_class_name: i32,
+    pub localized_class_name: i32,
     character_guid: Guid,
 }
 
+// The start of `Player`
+#[derive(Deserialize)]
+pub struct PlayerHeader {
+    _is_female: bool,
+    pub class_name: String,
+    _is_combat_pawn: bool,
+    _is_injured_pawn: bool,
+    _use_casual_appearance: bool,
+    pub level: i32,
+    _current_xp: f32,
+    pub first_name: String,
+}
+
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "{}", name)]
 pub struct Power {
     pub name: String,
-    rank: f32,
-    evolved_choice_0: i32,
-    evolved_choice_1: i32,
-    evolved_choice_2: i32,
-    evolved_choice_3: i32,
-    evolved_choice_4: i32,
-    evolved_choice_5: i32,
+    pub rank: f32,
+    pub evolved_choice_0: i32,
+    pub evolved_choice_1: i32,
+    pub evolved_choice_2: i32,
+    pub evolved_choice_3: i32,
+    pub evolved_choice_4: i32,
+    pub evolved_choice_5: i32,
     pub power_class_name: String,
     wheel_display_index: i32,
 }
 
+impl Power {
+    // Evolutions start at rank 4, 2 choices per rank
+    pub const EVOLUTION_TIERS: usize = 3;
+    pub const MAX_RANK: i32 = 6;
+
+    // Rank 1 costs 1 point, rank 2 costs 2 points, etc.
+    pub fn rank_cost(rank: i32) -> i32 {
+        (1..=rank).sum()
+    }
+
+    fn evolved_choices(&self, tier: usize) -> (&RcCell<i32>, &RcCell<i32>) {
+        match tier {
+            0 => (&self.evolved_choice_0, &self.evolved_choice_1),
+            1 => (&self.evolved_choice_2, &self.evolved_choice_3),
+            _ => (&self.evolved_choice_4, &self.evolved_choice_5),
+        }
+    }
+
+    // `Some(0)` for the first choice, `Some(1)` for the second
+    pub fn evolution(&self, tier: usize) -> Option<usize> {
+        let (first, second) = self.evolved_choices(tier);
+        if first.get() != 0 {
+            Some(0)
+        } else if second.get() != 0 {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    pub fn set_evolution(&mut self, tier: usize, choice: Option<usize>) {
+        // The game stores the tier number (1 to 3) into the chosen slot
+        let value = tier as i32 + 1;
+        let (first, second) = self.evolved_choices(tier);
+        first.set(if choice == Some(0) { value } else { 0 });
+        second.set(if choice == Some(1) { value } else { 0 });
+    }
+
+    // Change the rank and returns the refunded talent points (negative if spent)
+    pub fn respec(&mut self, rank: i32) -> i32 {
+        let rank = rank.clamp(0, Self::MAX_RANK);
+        let old_rank = self.rank() as i32;
+
+        for tier in 0..Self::EVOLUTION_TIERS {
+            let unlocked = rank >= tier as i32 + 4;
+            match self.evolution(tier) {
+                Some(_) if !unlocked => self.set_evolution(tier, None),
+                None if unlocked => self.set_evolution(tier, Some(0)),
+                _ => (),
+            }
+        }
+        self.set_rank(rank as f32);
+
+        Self::rank_cost(old_rank) - Self::rank_cost(rank)
+    }
+
+    // Reset the powers to their base rank and returns the refunded talent points
+    pub fn reset_ranks(powers: &[RcRef<Power>], base_ranks: &BaseRanks<'_>) -> i32 {
+        powers
+            .iter()
+            .map(|power| {
+                let mut power = power.borrow_mut();
+                let base_rank =
+                    base_ranks.reset_rank(&power.power_class_name(), power.rank() as i32);
+                base_rank.map(|base_rank| power.respec(base_rank)).unwrap_or_default()
+            })
+            .sum()
+    }
+}
+
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "{}", class_name)]
 pub struct Weapon {
-    class_name: String,
+    pub class_name: String,
     ammo_used_count: i32,
     ammo_total: i32,
     current_weapon: bool,
@@ -86,14 +171,14 @@ pub struct Weapon {
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "{}", weapon_class_name)]
 pub struct WeaponMod {
-    weapon_class_name: String,
-    weapon_mod_class_names: Vec<String>,
+    pub weapon_class_name: String,
+    pub weapon_mod_class_names: Vec<String>,
 }
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "")]
-struct Hotkey {
-    pawn_name: String,
-    power_name: String,
+pub struct Hotkey {
+    pub pawn_name: String,
+    pub power_name: String,
 }
@@ -0,0 +1,84 @@
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, PartialEq, Eq)]
+pub struct DbPlayerVariable {
+    pub label: String,
+    pub description: String,
+}
+
+// `GAWAsset_187_SeenInGUI` => prefix `GAWAsset_`, id `187`, suffix `_SeenInGUI`
+#[derive(Deserialize, Clone, PartialEq, Eq)]
+pub struct DbVariablePattern {
+    pub prefix: String,
+    pub suffix: String,
+    pub label: String,
+    pub description: String,
+}
+
+impl DbVariablePattern {
+    fn id<'a>(&self, key: &'a str) -> Option<&'a str> {
+        let (prefix, suffix) = (self.prefix.len(), self.suffix.len());
+        if key.len() <= prefix + suffix {
+            return None;
+        }
+
+        let starts_with = key.get(..prefix)?.eq_ignore_ascii_case(&self.prefix);
+        let ends_with = key.get(key.len() - suffix..)?.eq_ignore_ascii_case(&self.suffix);
+        (starts_with && ends_with).then(|| key.get(prefix..key.len() - suffix)).flatten()
+    }
+}
+
+#[derive(Deserialize, PartialEq, Eq)]
+pub struct Me3PlayerVariableDb {
+    pub keys: IndexMap<String, DbPlayerVariable>,
+    pub patterns: Vec<DbVariablePattern>,
+}
+
+impl Me3PlayerVariableDb {
+    // The case of the keys isn't consistent between saves
+    pub fn get(&self, key: &str) -> Option<DbPlayerVariable> {
+        let exact = self.keys.iter().find_map(|(db_key, variable)| {
+            db_key.eq_ignore_ascii_case(key).then(|| variable.clone())
+        });
+        exact.or_else(|| {
+            self.patterns.iter().find_map(|pattern| {
+                pattern.id(key).map(|id| DbPlayerVariable {
+                    label: format!("{}: {}", pattern.label, id),
+                    description: pattern.description.clone(),
+                })
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use anyhow::Result;
+
+    use super::*;
+    use crate::{save_data::mass_effect_3::Me3SaveGame, unreal};
+
+    #[test]
+    fn deserialize_player_variable_db() -> Result<()> {
+        let input = fs::read_to_string("databases/me3_player_variable_db.ron")?;
+        let player_variable_db: Me3PlayerVariableDb = ron::from_str(&input)?;
+
+        let seen = player_variable_db.get("GAWAsset_187_SeenInGUI");
+        assert_eq!(seen.map(|variable| variable.label), Some("War asset seen: 187".to_owned()));
+        let pistol = player_variable_db.get("sfxgamecontent.sfxweapon_pistol_carnifex");
+        assert_eq!(pistol.map(|variable| variable.label), Some("M-6 Carnifex".to_owned()));
+
+        // Only `None` is unknown in a save without mods
+        let input = fs::read("test/ME3Save.pcsav")?;
+        let me3_save_game: Me3SaveGame = unreal::Deserializer::from_bytes(&input)?;
+        let player_variables = me3_save_game.player_variables();
+        let unknown: Vec<_> =
+            player_variables.keys().filter(|key| player_variable_db.get(key).is_none()).collect();
+        assert_eq!(unknown, ["None"]);
+
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
-use crate::save_data::shared::plot::{BitVec, PlotCodex};
+use crate::save_data::shared::plot::{self, BitVec, Quest};
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone)]
@@ -14,24 +14,37 @@ pub struct PlotTable {
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, RawUi)]
 pub struct Journal {
-    quest_progress_counter: i32,
-    quest_progress: Vec<PlotQuest>,
-    quest_ids: Vec<i32>,
+    pub quest_progress_counter: i32,
+    pub quest_progress: Vec<PlotQuest>,
+    pub quest_ids: Vec<i32>,
 }
 
-#[rcize_fields]
-#[derive(Deserialize, Serialize, Clone, RawUi)]
-pub struct Codex {
-    codex_entries: Vec<PlotCodex>,
-    codex_ids: Vec<i32>,
+impl Journal {
+    // Same layout as the ME1 / ME2 journal
+    pub fn remove_quest(&mut self, idx: usize) {
+        let quest_count = plot::remove_quest(
+            &mut self.quest_progress.borrow_mut(),
+            &mut self.quest_ids.borrow_mut(),
+            idx,
+        );
+        if let Some(quest_count) = quest_count {
+            self.set_quest_progress_counter(quest_count);
+        }
+    }
 }
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "{}", quest_counter)]
 pub struct PlotQuest {
-    quest_counter: i32,
-    quest_updated: bool,
-    active_goal: i32,
-    history: Vec<i32>,
+    pub quest_counter: i32,
+    pub quest_updated: bool,
+    pub active_goal: i32,
+    pub history: Vec<i32>,
+}
+
+impl Quest for PlotQuest {
+    fn set_quest_counter(&mut self, quest_counter: i32) {
+        PlotQuest::set_quest_counter(self, quest_counter)
+    }
 }
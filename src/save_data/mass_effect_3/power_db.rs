@@ -0,0 +1,43 @@
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, PartialEq, Eq)]
+pub struct DbPower {
+    pub name: String,
+    pub evolutions: Vec<(String, String)>,
+}
+
+#[derive(Deserialize, Deref, From, PartialEq, Eq)]
+pub struct Me3PowerDb(IndexMap<String, DbPower>);
+
+impl Me3PowerDb {
+    // `SFXGameContent.SFXPowerCustomAction_Throw` => `SFXPowerCustomAction_Throw`
+    // The package case isn't consistent between saves
+    pub fn get_power(&self, power_class_name: &str) -> Option<&DbPower> {
+        let class_name = power_class_name
+            .rsplit_once('.')
+            .map(|(_, class_name)| class_name)
+            .unwrap_or(power_class_name);
+        self.iter().find_map(|(key, power)| key.eq_ignore_ascii_case(class_name).then(|| power))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use anyhow::Result;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_power_db() -> Result<()> {
+        let input = fs::read_to_string("databases/me3_power_db.ron")?;
+        let me3_power_db: Me3PowerDb = ron::from_str(&input)?;
+
+        let grenade = me3_power_db.get_power("sfxgamecontent.SFXPowerCustomAction_BioticGrenade");
+        assert_eq!(grenade.map(|power| power.name.as_str()), Some("Cluster Grenade"));
+
+        Ok(())
+    }
+}
@@ -7,13 +7,13 @@ use crate::save_data::shared::player::WeaponLoadout;
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "{}", tag)]
 pub struct Henchman {
-    tag: String,
-    powers: Vec<Power>,
-    character_level: i32,
-    talent_points: i32,
-    weapon_loadout: WeaponLoadout,
-    mapped_power: String,
-    weapon_mods: Vec<WeaponMod>,
-    grenades: i32,
-    weapons: Vec<Weapon>,
+    pub tag: String,
+    pub powers: Vec<Power>,
+    pub character_level: i32,
+    pub talent_points: i32,
+    pub weapon_loadout: WeaponLoadout,
+    pub mapped_power: String,
+    pub weapon_mods: Vec<WeaponMod>,
+    pub grenades: i32,
+    pub weapons: Vec<Weapon>,
 }
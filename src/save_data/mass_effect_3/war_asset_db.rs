@@ -0,0 +1,46 @@
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Display)]
+pub enum WarAssetKind {
+    Military,
+    Modifier,
+    Salvage,
+    Intel,
+    Artifact,
+    External,
+    Quest,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Eq)]
+pub struct DbWarAsset {
+    pub name: String,
+    pub kind: WarAssetKind,
+    pub category: String,
+    #[serde(default)]
+    pub strength: Option<i32>,
+}
+
+#[derive(Deserialize, Deref, From, PartialEq, Eq)]
+pub struct Me3WarAssetDb(IndexMap<i32, DbWarAsset>);
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use anyhow::Result;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_war_asset_db() -> Result<()> {
+        let input = fs::read_to_string("databases/me3_war_asset_db.ron")?;
+        let me3_war_asset_db: Me3WarAssetDb = ron::from_str(&input)?;
+
+        assert_eq!(me3_war_asset_db[&208].name, "Ashley");
+        assert!(me3_war_asset_db[&208].kind == WarAssetKind::Military);
+        assert_eq!(me3_war_asset_db[&6].strength, Some(200));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Hash, Display)]
+pub enum WeaponCategory {
+    #[display(fmt = "Assault rifle")]
+    AssaultRifle,
+    Shotgun,
+    #[display(fmt = "Sniper rifle")]
+    SniperRifle,
+    #[display(fmt = "Submachine gun")]
+    SubmachineGun,
+    Pistol,
+    #[display(fmt = "Heavy weapon")]
+    HeavyWeapon,
+}
+
+impl WeaponCategory {
+    pub fn all() -> [WeaponCategory; 6] {
+        [
+            WeaponCategory::AssaultRifle,
+            WeaponCategory::Shotgun,
+            WeaponCategory::SniperRifle,
+            WeaponCategory::SubmachineGun,
+            WeaponCategory::Pistol,
+            WeaponCategory::HeavyWeapon,
+        ]
+    }
+}
+
+#[derive(Deserialize, Clone, PartialEq, Eq)]
+pub struct DbWeapon {
+    pub name: String,
+    pub category: WeaponCategory,
+}
+
+#[derive(Deserialize, PartialEq, Eq)]
+pub struct Me3WeaponDb {
+    pub weapons: IndexMap<String, DbWeapon>,
+    pub mods: IndexMap<String, DbWeapon>,
+}
+
+impl Me3WeaponDb {
+    pub fn weapons_of(
+        &self, category: WeaponCategory,
+    ) -> impl Iterator<Item = (&String, &DbWeapon)> {
+        self.weapons.iter().filter(move |(_, weapon)| weapon.category == category)
+    }
+
+    // Mods attach to every weapon of their category
+    pub fn compatible_mods(&self, weapon_class_name: &str) -> Vec<(&String, &DbWeapon)> {
+        match self.weapons.get(weapon_class_name) {
+            Some(weapon) => self
+                .mods
+                .iter()
+                .filter(|(_, weapon_mod)| weapon_mod.category == weapon.category)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use anyhow::Result;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_weapon_db() -> Result<()> {
+        let input = fs::read_to_string("databases/me3_weapon_db.ron")?;
+        let me3_weapon_db: Me3WeaponDb = ron::from_str(&input)?;
+
+        let avenger = &me3_weapon_db.weapons["SFXGameContent.SFXWeapon_AssaultRifle_Avenger"];
+        assert_eq!(avenger.name, "M-8 Avenger");
+        assert!(avenger.category == WeaponCategory::AssaultRifle);
+
+        let mods = me3_weapon_db.compatible_mods("SFXGameContent.SFXWeapon_Pistol_Carnifex");
+        assert!(!mods.is_empty());
+        assert!(mods.iter().all(|(_, weapon_mod)| weapon_mod.category == WeaponCategory::Pistol));
+
+        Ok(())
+    }
+}
@@ -132,6 +132,15 @@ impl<T: Display> Display for RcRef<T> {
     }
 }
 
+// `SeqAccess::next_element` that fails instead of returning `None`
+pub trait SeqAccessExt<'de>: de::SeqAccess<'de> {
+    fn next_required<T: Deserialize<'de>>(&mut self) -> Result<T, Self::Error> {
+        self.next_element()?.ok_or_else(|| de::Error::custom("unexpected end of data"))
+    }
+}
+
+impl<'de, A: de::SeqAccess<'de>> SeqAccessExt<'de> for A {}
+
 // Implémentation des dummy
 #[derive(Clone)]
 pub struct Dummy<const LEN: usize>([u8; LEN]);
@@ -162,7 +171,8 @@ impl<'de, const LEN: usize> Deserialize<'de> for Dummy<LEN> {
                 let mut result = [0u8; LEN];
                 let mut i = 0;
                 while let Some(element) = seq.next_element()? {
-                    result[i] = element;
+                    *result.get_mut(i).ok_or_else(|| de::Error::invalid_length(i + 1, &self))? =
+                        element;
                     i += 1;
                 }
                 Ok(Dummy(result))
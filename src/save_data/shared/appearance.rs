@@ -7,25 +7,25 @@ use super::Vector;
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, RawUi)]
 pub struct Appearance {
-    combat_appearance: PlayerAppearanceType,
-    casual_id: i32,
-    full_body_id: i32,
-    torso_id: i32,
-    shoulder_id: i32,
-    arm_id: i32,
-    leg_id: i32,
-    specular_id: i32,
-    tint1_id: i32,
-    tint2_id: i32,
-    tint3_id: i32,
-    pattern_id: i32,
-    pattern_color_id: i32,
-    helmet_id: i32,
+    pub combat_appearance: PlayerAppearanceType,
+    pub casual_id: i32,
+    pub full_body_id: i32,
+    pub torso_id: i32,
+    pub shoulder_id: i32,
+    pub arm_id: i32,
+    pub leg_id: i32,
+    pub specular_id: i32,
+    pub tint1_id: i32,
+    pub tint2_id: i32,
+    pub tint3_id: i32,
+    pub pattern_id: i32,
+    pub pattern_color_id: i32,
+    pub helmet_id: i32,
     pub head_morph: Option<HeadMorph>,
 }
 
-#[derive(Deserialize, Serialize, Clone, RawUi)]
-enum PlayerAppearanceType {
+#[derive(Deserialize, Serialize, Copy, Clone, RawUi)]
+pub enum PlayerAppearanceType {
     Parts,
     Full,
 }
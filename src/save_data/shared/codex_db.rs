@@ -0,0 +1,66 @@
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+#[derive(Deserialize, Clone, PartialEq, Eq)]
+pub struct DbCodexEntry {
+    pub name: String,
+    pub pages: IndexMap<i32, String>,
+}
+
+#[derive(Deserialize, Deref, From, PartialEq, Eq)]
+pub struct CodexDb(IndexMap<i32, DbCodexEntry>);
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use anyhow::Result;
+
+    use super::*;
+    use crate::{
+        save_data::{
+            mass_effect_1_le::Me1LeSaveGame, mass_effect_2::Me2LeSaveGame, shared::plot::Codex,
+        },
+        unreal,
+    };
+
+    // Every codex entry of the save is in the database with all its pages
+    fn check_codex(codex_db: &CodexDb, codex: &Codex) {
+        for (id, entry) in codex.codex_ids().iter().zip(codex.codex_entries().iter()) {
+            let db_entry = codex_db.get(&id.get());
+            assert!(db_entry.is_some(), "missing codex entry: {}", id.get());
+            for page in entry.borrow().pages().iter() {
+                let page = page.borrow().page();
+                assert!(db_entry.unwrap().pages.contains_key(&page), "missing page: {}", page);
+            }
+        }
+    }
+
+    #[test]
+    fn deserialize_codex_db() -> Result<()> {
+        // ME1LE
+        let input = fs::read_to_string("databases/me1_le_codex_db.ron")?;
+        let me1_le_codex_db: CodexDb = ron::from_str(&input)?;
+
+        let input = fs::read("test/ME1LeSave.pcsav")?;
+        let save_game: Me1LeSaveGame = unreal::Deserializer::from_bytes(&input)?;
+        check_codex(&me1_le_codex_db, &save_game.save_data.borrow().codex.borrow());
+
+        // ME2
+        let input = fs::read_to_string("databases/me2_codex_db.ron")?;
+        let me2_codex_db: CodexDb = ron::from_str(&input)?;
+
+        let input = fs::read("test/ME2LeSave.pcsav")?;
+        let save_game: Me2LeSaveGame = unreal::Deserializer::from_bytes(&input)?;
+        check_codex(&me2_codex_db, &save_game.codex.borrow());
+
+        // ME3
+        let input = fs::read_to_string("databases/me3_codex_db.ron")?;
+        let me3_codex_db: CodexDb = ron::from_str(&input)?;
+
+        let organizations = me3_codex_db.get(&58245).map(|entry| entry.name.as_str());
+        assert_eq!(organizations, Some("Organizations"));
+
+        Ok(())
+    }
+}
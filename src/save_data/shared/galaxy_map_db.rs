@@ -0,0 +1,63 @@
+use indexmap::IndexSet;
+use serde::Deserialize;
+
+#[derive(Deserialize, PartialEq, Eq)]
+pub struct GalaxyMapDb {
+    pub systems: IndexSet<i32>,
+    pub planets: IndexSet<i32>,
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use anyhow::Result;
+
+    use super::*;
+    use crate::{
+        save_data::{
+            mass_effect_2::{Me2LeSaveGame, Me2SaveGame},
+            mass_effect_3::Me3SaveGame,
+        },
+        unreal,
+    };
+
+    #[test]
+    fn deserialize_galaxy_map_db() -> Result<()> {
+        // ME2
+        let input = fs::read_to_string("databases/me2_galaxy_map_db.ron")?;
+        let me2_galaxy_map_db: GalaxyMapDb = ron::from_str(&input)?;
+
+        let input = fs::read("test/ME2Save.pcsav")?;
+        let save_game: Me2SaveGame = unreal::Deserializer::from_bytes(&input)?;
+        for planet in save_game.galaxy_map().planets().iter() {
+            let planet = planet.borrow();
+            assert!(me2_galaxy_map_db.planets.contains(&planet.id()));
+            assert!(me2_galaxy_map_db.systems.contains(&planet.system_id()));
+        }
+
+        let input = fs::read("test/ME2LeSave.pcsav")?;
+        let save_game: Me2LeSaveGame = unreal::Deserializer::from_bytes(&input)?;
+        for planet in save_game.galaxy_map().planets().iter() {
+            assert!(me2_galaxy_map_db.planets.contains(&planet.borrow().id()));
+        }
+
+        // ME3
+        let input = fs::read_to_string("databases/me3_galaxy_map_db.ron")?;
+        let me3_galaxy_map_db: GalaxyMapDb = ron::from_str(&input)?;
+
+        let input = fs::read("test/ME3Save.pcsav")?;
+        let save_game: Me3SaveGame = unreal::Deserializer::from_bytes(&input)?;
+        let galaxy_map = save_game.galaxy_map();
+        for planet in galaxy_map.planets().iter() {
+            let planet = planet.borrow();
+            assert!(me3_galaxy_map_db.planets.contains(&planet.id()));
+            assert!(me3_galaxy_map_db.systems.contains(&planet.system_id()));
+        }
+        for system in galaxy_map.systems().iter() {
+            assert!(me3_galaxy_map_db.systems.contains(&system.borrow().id()));
+        }
+
+        Ok(())
+    }
+}
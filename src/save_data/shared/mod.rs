@@ -1,6 +1,11 @@
 pub mod appearance;
+pub mod codex_db;
+pub mod galaxy_map_db;
 pub mod player;
+pub mod player_class_db;
 pub mod plot;
+pub mod squad_db;
+pub mod xp;
 
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
@@ -43,10 +48,10 @@ impl serde::Serialize for EndGameState {
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, RawUi)]
 pub struct SaveTimeStamp {
-    seconds_since_midnight: i32,
-    day: i32,
-    month: i32,
-    year: i32,
+    pub seconds_since_midnight: i32,
+    pub day: i32,
+    pub month: i32,
+    pub year: i32,
 }
 
 #[rcize_fields]
@@ -0,0 +1,386 @@
+use std::cell::Ref;
+
+use serde::Deserialize;
+
+use crate::save_data::{
+    mass_effect_2::player::Power as Me2Power, mass_effect_3::player::Power as Me3Power, RcCell,
+    RcRef,
+};
+
+#[derive(Deserialize)]
+pub struct DbClassPower {
+    pub name: String,
+    pub power_class_name: String,
+    // Ranks given by the game, they don't cost talent points
+    #[serde(default)]
+    pub base_rank: i32,
+}
+
+// Ranks kept when the talents are reset
+pub struct BaseRanks<'a> {
+    pub powers: &'a [DbClassPower],
+    // Powers given by the game (first aid, melee, etc.), they are kept as is
+    pub free_powers: &'a [DbClassPower],
+}
+
+impl BaseRanks<'_> {
+    // The rank after a reset, `None` for the free powers
+    pub fn reset_rank(&self, power_class_name: &str, rank: i32) -> Option<i32> {
+        let is_power =
+            |power: &&DbClassPower| power.power_class_name.eq_ignore_ascii_case(power_class_name);
+
+        if self.free_powers.iter().any(|power| is_power(&power)) {
+            return None;
+        }
+        let base_rank =
+            self.powers.iter().find(is_power).map(|power| power.base_rank).unwrap_or_default();
+        Some(rank.min(base_rank))
+    }
+}
+
+#[derive(Deserialize)]
+pub struct PlayerClass {
+    pub class_name: String,
+    pub localized_class_name: i32,
+    pub powers: Vec<DbClassPower>,
+}
+
+impl PlayerClass {
+    fn has_power(&self, power_class_name: &str) -> bool {
+        self.powers
+            .iter()
+            .any(|power| power.power_class_name.eq_ignore_ascii_case(power_class_name))
+    }
+
+    // The power of this class evolved by an ME2 evolved power
+    fn evolved_power<P: ClassPower>(&self, power: &P) -> Option<&DbClassPower> {
+        let evolved_from = power.evolved_from()?;
+        self.powers.iter().find(|class_power| class_power.name == evolved_from)
+    }
+}
+
+// The player's fields changed by a class change
+pub struct ClassPlayer<'a, P> {
+    pub class_name: &'a RcRef<String>,
+    pub localized_class_name: &'a RcCell<i32>,
+    pub talent_points: &'a RcCell<i32>,
+    pub powers: &'a RcRef<Vec<RcRef<P>>>,
+    pub mapped_powers: [&'a RcRef<String>; 3],
+}
+
+#[derive(Deserialize, Deref)]
+pub struct PlayerClassDb {
+    #[deref]
+    classes: Vec<PlayerClass>,
+    free_powers: Vec<DbClassPower>,
+}
+
+impl PlayerClassDb {
+    // ME3 non combat classes have the powers of their combat class
+    pub fn get_class(&self, class_name: &str) -> Option<&PlayerClass> {
+        let class_name = class_name.trim_end_matches("NonCombat");
+        self.iter().find(|player_class| player_class.class_name == class_name)
+    }
+
+    // Powers given by any class, everything else (bonus powers, melee, etc.) is kept on class change
+    pub fn is_class_power(&self, power_class_name: &str) -> bool {
+        self.iter()
+            .flat_map(|player_class| &player_class.powers)
+            .any(|power| power.power_class_name.eq_ignore_ascii_case(power_class_name))
+    }
+
+    pub fn base_ranks(&self, class_name: &str) -> BaseRanks<'_> {
+        let powers =
+            self.get_class(class_name).map(|class| class.powers.as_slice()).unwrap_or_default();
+        BaseRanks { powers, free_powers: &self.free_powers }
+    }
+
+    // Change the class and its powers, refund the talent points of the removed powers and unmap them
+    // The hotkeys are left to the caller, ME2 and ME3 don't reference the powers the same way
+    pub fn set_player_class<P: ClassPower>(
+        &self, player: &ClassPlayer<'_, P>, class_name: &str,
+    ) -> bool {
+        let new_class = match self.get_class(class_name) {
+            Some(new_class) => new_class,
+            None => return false,
+        };
+
+        let refund = self.change_class(
+            &player.class_name.borrow(),
+            new_class,
+            &mut player.powers.borrow_mut(),
+        );
+        *player.class_name.borrow_mut() = class_name.to_owned();
+        player.localized_class_name.set(new_class.localized_class_name);
+        player.talent_points.update(|talent_points| talent_points + refund);
+
+        unmap_removed_powers(player.mapped_powers, &player.powers.borrow());
+        true
+    }
+
+    // Keep the non class powers and the ones shared with the new class, remove the others
+    // and add the missing powers of the new class, returns the refunded talent points
+    // The evolved powers of the old class follow the power they evolve
+    pub fn change_class<P: ClassPower>(
+        &self, old_class_name: &str, new_class: &PlayerClass, powers: &mut Vec<RcRef<P>>,
+    ) -> i32 {
+        let old_class = self.get_class(old_class_name);
+        let mut refund = 0;
+        powers.retain(|power| {
+            let power = power.borrow();
+            let power_class_name = power.power_class_name();
+            let keep = if self.is_class_power(&power_class_name) {
+                new_class.has_power(&power_class_name)
+            } else if let Some(class_power) =
+                old_class.and_then(|old_class| old_class.evolved_power(&*power))
+            {
+                new_class.has_power(&class_power.power_class_name)
+            } else {
+                true
+            };
+            if !keep {
+                refund += power.spent_points();
+            }
+            keep
+        });
+
+        for class_power in &new_class.powers {
+            let has_power = powers.iter().any(|power| {
+                power
+                    .borrow()
+                    .power_class_name()
+                    .eq_ignore_ascii_case(&class_power.power_class_name)
+            });
+            if !has_power {
+                powers.push(P::from_db(class_power).into());
+            }
+        }
+        refund
+    }
+}
+
+// Reset the powers to their base rank and refund the talent points
+pub fn reset_talents<P: ClassPower>(
+    talent_points: &RcCell<i32>, powers: &RcRef<Vec<RcRef<P>>>, base_ranks: &BaseRanks<'_>,
+) {
+    let refund = P::reset_ranks(&mut powers.borrow_mut(), base_ranks);
+    talent_points.update(|talent_points| talent_points + refund);
+}
+
+// ME2 and ME3 powers, for the class change
+pub trait ClassPower: Sized {
+    fn from_db(class_power: &DbClassPower) -> Self;
+    fn name(&self) -> Ref<'_, String>;
+    fn power_class_name(&self) -> Ref<'_, String>;
+    fn spent_points(&self) -> i32;
+    fn reset_ranks(powers: &mut Vec<RcRef<Self>>, base_ranks: &BaseRanks<'_>) -> i32;
+
+    // The name of the power it evolves, for the ME2 evolved powers that are separate rank 4 powers
+    fn evolved_from(&self) -> Option<String> {
+        None
+    }
+}
+
+impl ClassPower for Me2Power {
+    fn from_db(class_power: &DbClassPower) -> Self {
+        let mut power = Me2Power::default();
+        *power.name_mut() = class_power.name.clone();
+        *power.power_class_name_mut() = class_power.power_class_name.clone();
+        power
+    }
+
+    fn name(&self) -> Ref<'_, String> {
+        Me2Power::name(self)
+    }
+
+    fn power_class_name(&self) -> Ref<'_, String> {
+        Me2Power::power_class_name(self)
+    }
+
+    fn spent_points(&self) -> i32 {
+        Me2Power::rank_cost(self.rank() as i32)
+    }
+
+    fn reset_ranks(powers: &mut Vec<RcRef<Self>>, base_ranks: &BaseRanks<'_>) -> i32 {
+        Me2Power::reset_ranks(powers, base_ranks)
+    }
+
+    // `EvolvedWarpProjectile_Heavy` and `Shockwave_Evolved1` evolve `WarpProjectile` and `Shockwave`,
+    // the evolved passives keep the name of their power
+    fn evolved_from(&self) -> Option<String> {
+        if (self.rank() as i32) < Me2Power::EVOLVED_RANK {
+            return None;
+        }
+        let name = self.name();
+        name.trim_start_matches("Evolved").split('_').next().map(str::to_owned)
+    }
+}
+
+impl ClassPower for Me3Power {
+    fn from_db(class_power: &DbClassPower) -> Self {
+        let mut power = Me3Power::default();
+        *power.name_mut() = class_power.name.clone();
+        *power.power_class_name_mut() = class_power.power_class_name.clone();
+        power
+    }
+
+    fn name(&self) -> Ref<'_, String> {
+        Me3Power::name(self)
+    }
+
+    fn power_class_name(&self) -> Ref<'_, String> {
+        Me3Power::power_class_name(self)
+    }
+
+    fn spent_points(&self) -> i32 {
+        Me3Power::rank_cost(self.rank() as i32)
+    }
+
+    fn reset_ranks(powers: &mut Vec<RcRef<Self>>, base_ranks: &BaseRanks<'_>) -> i32 {
+        Me3Power::reset_ranks(powers, base_ranks)
+    }
+}
+
+// Unmap the mapped powers that aren't in the powers anymore
+// The game maps a power by its class name without the package (`SFXPower_Throw_Adept`)
+fn unmap_removed_powers<P: ClassPower>(mapped_powers: [&RcRef<String>; 3], powers: &[RcRef<P>]) {
+    for mapped_power in mapped_powers {
+        let mut mapped_power = mapped_power.borrow_mut();
+        let is_mapped = |power: &RcRef<P>| {
+            let power = power.borrow();
+            let power_class_name = power.power_class_name();
+            let class_name = power_class_name.rsplit('.').next().unwrap_or_default();
+            class_name.eq_ignore_ascii_case(&mapped_power) || *power.name() == *mapped_power
+        };
+        if !powers.iter().any(is_mapped) {
+            *mapped_power = "None".to_owned();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use anyhow::Result;
+
+    use super::*;
+    use crate::{
+        save_data::{
+            mass_effect_2::{Me2LeSaveGame, Me2SaveGame},
+            mass_effect_3::Me3SaveGame,
+        },
+        unreal,
+    };
+
+    #[test]
+    fn me2_player_class_db() -> Result<()> {
+        let input = fs::read_to_string("databases/me2_player_class_db.ron")?;
+        let me2_player_class_db: PlayerClassDb = ron::from_str(&input)?;
+
+        // The test save is an Adept
+        let input = fs::read("test/ME2Save.pcsav")?;
+        let save_game: Me2SaveGame = unreal::Deserializer::from_bytes(&input)?;
+        let mut player = save_game.player.borrow_mut();
+        let adept = me2_player_class_db.get_class(&player.class_name()).unwrap();
+        assert_eq!(adept.localized_class_name, player.localized_class_name());
+        for class_power in &adept.powers {
+            assert!(player.powers().iter().any(|power| {
+                let power = power.borrow();
+                *power.name() == class_power.name
+                    && power.power_class_name().eq_ignore_ascii_case(&class_power.power_class_name)
+            }));
+        }
+
+        assert!(!me2_player_class_db.is_class_power("SFXGameContent_Powers.SFXPower_FirstAid"));
+
+        // Change class
+        let soldier = me2_player_class_db.get_class("SFXGame.SFXPawn_PlayerSoldier").unwrap();
+        let spent: i32 = player
+            .powers()
+            .iter()
+            .map(|power| power.borrow())
+            .filter(|power| !soldier.has_power(&power.power_class_name()))
+            .filter(|power| me2_player_class_db.is_class_power(&power.power_class_name()))
+            .map(|power| power.spent_points())
+            .sum();
+        let adept = player.class_name().clone();
+        let refund = me2_player_class_db.change_class(&adept, soldier, &mut player.powers_mut());
+        assert_eq!(refund, spent);
+        for class_power in &soldier.powers {
+            assert!(player.powers().iter().any(|power| {
+                power
+                    .borrow()
+                    .power_class_name()
+                    .eq_ignore_ascii_case(&class_power.power_class_name)
+            }));
+        }
+        assert!(player.powers().iter().all(|power| {
+            let power_class_name = power.borrow().power_class_name().clone();
+            !me2_player_class_db.is_class_power(&power_class_name)
+                || soldier.has_power(&power_class_name)
+        }));
+
+        // The evolved powers are rank 4 powers with their own class name
+        // The test save is an Adept with 4 of them and an evolved power mapped to the power wheel
+        let input = fs::read("test/ME2LeSave.pcsav")?;
+        let save_game: Me2LeSaveGame = unreal::Deserializer::from_bytes(&input)?;
+        let player = save_game.player.borrow();
+        assert_eq!(*player.class_name(), "SFXGame.SFXPawn_PlayerAdept");
+        let is_evolved = |power: &Me2Power| power.rank() as i32 == Me2Power::EVOLVED_RANK;
+        assert_eq!(player.powers().iter().filter(|power| is_evolved(&power.borrow())).count(), 4);
+        assert_eq!(*player.mapped_power_2(), "SFXPower_WarpProjectile_Heavy");
+
+        let spent: i32 = player
+            .powers()
+            .iter()
+            .map(|power| power.borrow())
+            .filter(|power| {
+                me2_player_class_db.is_class_power(&power.power_class_name()) || is_evolved(power)
+            })
+            .map(|power| power.spent_points())
+            .sum();
+        let talent_points = player.talent_points();
+        let class_player = ClassPlayer {
+            class_name: &player.class_name,
+            localized_class_name: &player.localized_class_name,
+            talent_points: &player.talent_points,
+            powers: &player.powers,
+            mapped_powers: [&player.mapped_power_1, &player.mapped_power_2, &player.mapped_power_3],
+        };
+        assert!(
+            me2_player_class_db.set_player_class(&class_player, "SFXGame.SFXPawn_PlayerSoldier")
+        );
+        assert_eq!(player.talent_points(), talent_points + spent);
+        assert!(!player.powers().iter().any(|power| is_evolved(&power.borrow())));
+        // The bonus power is kept
+        assert!(player.powers().iter().any(|power| *power.borrow().name() == "WarpAmmo"));
+        assert_eq!(*player.mapped_power_2(), "None");
+
+        Ok(())
+    }
+
+    #[test]
+    fn me3_player_class_db() -> Result<()> {
+        let input = fs::read_to_string("databases/me3_player_class_db.ron")?;
+        let me3_player_class_db: PlayerClassDb = ron::from_str(&input)?;
+
+        // The test save is an Adept
+        let input = fs::read("test/ME3Save.pcsav")?;
+        let save_game: Me3SaveGame = unreal::Deserializer::from_bytes(&input)?;
+        let player = save_game.player();
+        let adept = me3_player_class_db.get_class(&player.class_name()).unwrap();
+        assert_eq!(adept.localized_class_name, player.localized_class_name());
+        for class_power in &adept.powers {
+            assert!(player.powers().iter().any(|power| {
+                let power = power.borrow();
+                *power.name() == class_power.name
+                    && power.power_class_name().eq_ignore_ascii_case(&class_power.power_class_name)
+            }));
+        }
+
+        assert!(!me3_player_class_db.is_class_power("SFXGameContent.SFXPowerCustomAction_Unity"));
+
+        Ok(())
+    }
+}
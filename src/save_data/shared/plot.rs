@@ -3,6 +3,8 @@ use bitvec::prelude::*;
 use indexmap::IndexMap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+use crate::save_data::{RcCell, RcRef};
+
 #[derive(Deref, DerefMut, Clone)]
 pub struct BitVec(bitvec::vec::BitVec<u32, Lsb0>);
 
@@ -38,40 +40,113 @@ pub struct PlotTable {
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, RawUi)]
 pub struct Journal {
-    quest_progress_counter: i32,
-    quest_progress: Vec<PlotQuest>,
-    quest_ids: Vec<i32>,
+    pub quest_progress_counter: i32,
+    pub quest_progress: Vec<PlotQuest>,
+    pub quest_ids: Vec<i32>,
+}
+
+impl Journal {
+    pub fn remove_quest(&mut self, idx: usize) {
+        let quest_count = remove_quest(
+            &mut self.quest_progress.borrow_mut(),
+            &mut self.quest_ids.borrow_mut(),
+            idx,
+        );
+        if let Some(quest_count) = quest_count {
+            self.set_quest_progress_counter(quest_count);
+        }
+    }
+}
+
+// ME1 / ME2 and ME3 quests, for the quest removal
+pub trait Quest {
+    fn set_quest_counter(&mut self, quest_counter: i32);
+}
+
+// `quest_progress` and `quest_ids` are parallel lists, `quest_counter` is the position in both,
+// returns the new quest count
+pub fn remove_quest<Q: Quest>(
+    quest_progress: &mut Vec<RcRef<Q>>, quest_ids: &mut Vec<RcCell<i32>>, idx: usize,
+) -> Option<i32> {
+    if idx >= quest_ids.len() {
+        return None;
+    }
+    quest_progress.remove(idx);
+    quest_ids.remove(idx);
+
+    for (counter, quest) in quest_progress.iter().enumerate() {
+        quest.borrow_mut().set_quest_counter(counter as i32);
+    }
+    Some(quest_progress.len() as i32)
 }
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, RawUi)]
 pub struct Codex {
-    codex_entries: Vec<PlotCodex>,
-    codex_ids: Vec<i32>,
+    pub codex_entries: Vec<PlotCodex>,
+    pub codex_ids: Vec<i32>,
+}
+
+impl Codex {
+    pub fn mark_all_read(&self) {
+        for entry in self.codex_entries().iter() {
+            for page in entry.borrow().pages().iter() {
+                page.borrow_mut().set_is_new(false);
+            }
+        }
+    }
+
+    // Adds the entry if missing and the pages it lacks, both shown as new
+    pub fn add_entry(&mut self, id: i32, pages: impl IntoIterator<Item = i32>) {
+        let idx = self.codex_ids().iter().position(|codex_id| codex_id.get() == id);
+        let entry = match idx {
+            Some(idx) => RcRef::clone(&self.codex_entries()[idx]),
+            None => {
+                let entry = RcRef::new(PlotCodex::default());
+                self.codex_entries_mut().push(RcRef::clone(&entry));
+                self.codex_ids_mut().push(id.into());
+                entry
+            }
+        };
+
+        let mut entry = entry.borrow_mut();
+        let mut entry_pages = entry.pages_mut();
+        for page in pages {
+            if !entry_pages.iter().any(|entry_page| entry_page.borrow().page() == page) {
+                entry_pages.push(PlotCodexPage { page: page.into(), is_new: true.into() }.into());
+            }
+        }
+    }
 }
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "{}", quest_counter)]
 pub struct PlotQuest {
-    quest_counter: i32,
-    quest_updated: bool,
-    history: Vec<i32>,
+    pub quest_counter: i32,
+    pub quest_updated: bool,
+    pub history: Vec<i32>,
+}
+
+impl Quest for PlotQuest {
+    fn set_quest_counter(&mut self, quest_counter: i32) {
+        PlotQuest::set_quest_counter(self, quest_counter)
+    }
 }
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "")]
 pub struct PlotCodex {
-    pages: Vec<PlotCodexPage>,
+    pub pages: Vec<PlotCodexPage>,
 }
 
 #[rcize_fields]
 #[derive(Deserialize, Serialize, Clone, Default, Display, RawUi)]
 #[display(fmt = "{}", page)]
 pub struct PlotCodexPage {
-    page: i32,
-    is_new: bool,
+    pub page: i32,
+    pub is_new: bool,
 }
 
 #[derive(Deserialize, Clone, PartialEq, Eq)]
@@ -86,3 +161,65 @@ pub struct RawPlotDb {
     pub integers: IndexMap<usize, String>,
     pub floats: IndexMap<usize, String>,
 }
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use anyhow::Result;
+
+    use crate::{
+        save_data::{mass_effect_2::Me2SaveGame, mass_effect_3::Me3SaveGame},
+        unreal,
+    };
+
+    #[test]
+    fn journal() -> Result<()> {
+        let input = fs::read("test/ME2Save.pcsav")?;
+        let me2_save_game: Me2SaveGame = unreal::Deserializer::from_bytes(&input)?;
+
+        // Remove quest
+        let mut journal = me2_save_game.journal.borrow_mut();
+        let len = journal.quest_ids().len();
+        let second_id = journal.quest_ids()[1].get();
+        journal.remove_quest(0);
+        assert_eq!(journal.quest_ids().len(), len - 1);
+        assert_eq!(journal.quest_progress().len(), len - 1);
+        assert_eq!(journal.quest_progress_counter(), len as i32 - 1);
+        assert_eq!(journal.quest_ids()[0].get(), second_id);
+        for (counter, quest) in journal.quest_progress().iter().enumerate() {
+            assert_eq!(quest.borrow().quest_counter(), counter as i32);
+        }
+
+        // Out of range
+        journal.remove_quest(len);
+        assert_eq!(journal.quest_ids().len(), len - 1);
+
+        // Codex
+        let mut codex = me2_save_game.codex.borrow_mut();
+        let len = codex.codex_ids().len();
+        codex.add_entry(-1, [1, 2]);
+        codex.add_entry(-1, [2, 3]);
+        assert_eq!(codex.codex_ids().len(), len + 1);
+        assert_eq!(codex.codex_entries()[len].borrow().pages().len(), 3);
+
+        codex.mark_all_read();
+        assert!(codex.codex_entries().iter().all(|entry| entry
+            .borrow()
+            .pages()
+            .iter()
+            .all(|page| !page.borrow().is_new())));
+
+        // ME3
+        let input = fs::read("test/ME3Save.pcsav")?;
+        let me3_save_game: Me3SaveGame = unreal::Deserializer::from_bytes(&input)?;
+
+        let mut journal = me3_save_game.journal.borrow_mut();
+        let len = journal.quest_ids().len();
+        journal.remove_quest(len - 1);
+        assert_eq!(journal.quest_progress().len(), len - 1);
+        assert_eq!(journal.quest_progress_counter(), len as i32 - 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use super::player_class_db::{BaseRanks, DbClassPower};
+
+#[derive(Deserialize)]
+pub struct DbHenchman {
+    pub name: String,
+    pub powers: Vec<DbClassPower>,
+}
+
+#[derive(Deserialize, Deref)]
+pub struct SquadDb {
+    #[deref]
+    henchmen: IndexMap<String, DbHenchman>,
+    free_powers: Vec<DbClassPower>,
+}
+
+impl SquadDb {
+    pub fn base_ranks(&self, tag: &str) -> BaseRanks<'_> {
+        let powers = self.get(tag).map(|henchman| henchman.powers.as_slice()).unwrap_or_default();
+        BaseRanks { powers, free_powers: &self.free_powers }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use anyhow::Result;
+
+    use super::*;
+    use crate::{
+        save_data::{
+            mass_effect_2::{Me2LeSaveGame, Me2SaveGame},
+            mass_effect_3::Me3SaveGame,
+        },
+        unreal,
+    };
+
+    // Every squad mate of the save is in the database with all its powers,
+    // returns the number of squad mates
+    fn check_squad(squad_db: &SquadDb, squad: Vec<(String, Vec<String>)>) -> usize {
+        let squad: Vec<_> =
+            squad.into_iter().filter(|(tag, _)| tag.starts_with("hench_")).collect();
+        for (tag, powers) in &squad {
+            let henchman = squad_db.get(tag);
+            assert!(henchman.is_some(), "missing squad mate: {}", tag);
+            for power in &henchman.unwrap().powers {
+                assert!(
+                    powers.iter().any(|name| name.eq_ignore_ascii_case(&power.power_class_name)),
+                    "missing power: {}",
+                    power.power_class_name
+                );
+            }
+        }
+        squad.len()
+    }
+
+    #[test]
+    fn deserialize_squad_db() -> Result<()> {
+        macro_rules! squad {
+            ($save_game:expr) => {
+                $save_game
+                    .squad()
+                    .iter()
+                    .map(|character| {
+                        let character = character.borrow();
+                        let powers: Vec<_> = character
+                            .powers()
+                            .iter()
+                            .map(|power| power.borrow().power_class_name().clone())
+                            .collect();
+                        let tag = character.tag().clone();
+                        (tag, powers)
+                    })
+                    .collect()
+            };
+        }
+
+        // ME2
+        let input = fs::read_to_string("databases/me2_squad_db.ron")?;
+        let me2_squad_db: SquadDb = ron::from_str(&input)?;
+
+        let input = fs::read("test/ME2Save.pcsav")?;
+        let save_game: Me2SaveGame = unreal::Deserializer::from_bytes(&input)?;
+        assert!(check_squad(&me2_squad_db, squad!(save_game)) > 0);
+
+        let input = fs::read("test/ME2LeSave.pcsav")?;
+        let save_game: Me2LeSaveGame = unreal::Deserializer::from_bytes(&input)?;
+        assert!(check_squad(&me2_squad_db, squad!(save_game)) > 0);
+
+        // ME3
+        let input = fs::read_to_string("databases/me3_squad_db.ron")?;
+        let me3_squad_db: SquadDb = ron::from_str(&input)?;
+
+        let input = fs::read("test/ME3Save.pcsav")?;
+        let save_game: Me3SaveGame = unreal::Deserializer::from_bytes(&input)?;
+        assert!(check_squad(&me3_squad_db, squad!(save_game)) > 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+// Only ME2 has a table, ME1, ME1LE and ME3 have no sourced XP and talent point tables
+#[derive(PartialEq)]
+pub struct XpTable {
+    // Total XP needed to reach each level, the first one is level 2
+    level_xp: &'static [i32],
+    // `(from level, points)`, the talent points earned at each level up from this level
+    player_points: &'static [(i32, i32)],
+    squad_points: &'static [(i32, i32)],
+}
+
+impl XpTable {
+    pub const ME2: XpTable = XpTable {
+        level_xp: &[
+            1000, 2000, 3000, 4000, 5000, 6000, 7000, 8000, 9000, 10_000, 11_000, 12_000, 13_000,
+            14_000, 15_000, 16_000, 17_000, 18_000, 19_000, 20_000, 21_000, 22_000, 23_000, 24_000,
+            25_000, 26_000, 27_000, 28_000, 29_000,
+        ],
+        player_points: &[(2, 2), (24, 1)],
+        squad_points: &[(2, 1)],
+    };
+
+    pub fn max_level(&self) -> i32 {
+        self.level_xp.len() as i32 + 1
+    }
+
+    // XP needed to reach `level`
+    pub fn xp(&self, level: i32) -> i32 {
+        let idx = level.clamp(1, self.max_level()) - 2;
+        usize::try_from(idx).ok().and_then(|idx| self.level_xp.get(idx)).copied().unwrap_or(0)
+    }
+
+    // Level reached with `xp`
+    pub fn level(&self, xp: f32) -> i32 {
+        self.level_xp.iter().take_while(|&&level_xp| xp >= level_xp as f32).count() as i32 + 1
+    }
+
+    // Talent points earned going from `from` to `to`, negative if `to` is lower
+    pub fn player_points(&self, from: i32, to: i32) -> i32 {
+        Self::points(self.player_points, from, to)
+    }
+
+    pub fn squad_points(&self, from: i32, to: i32) -> i32 {
+        Self::points(self.squad_points, from, to)
+    }
+
+    fn points(points: &[(i32, i32)], from: i32, to: i32) -> i32 {
+        let earned = |level: i32| -> i32 {
+            (2..=level)
+                .map(|level| {
+                    points
+                        .iter()
+                        .rev()
+                        .find_map(|&(from, points)| (level >= from).then(|| points))
+                        .unwrap_or_default()
+                })
+                .sum()
+        };
+        earned(to) - earned(from)
+    }
+
+    // Adds `earned` to `talent_points`, if there aren't enough points to remove
+    // `reset_talents` is called to refund the spent ones. The points stop at 0, the warning
+    // tells what couldn't be done.
+    pub fn earn_points(
+        talent_points: i32, earned: i32, reset_talents: impl FnOnce() -> i32,
+    ) -> (i32, Option<String>) {
+        let talent_points = talent_points + earned;
+        if talent_points >= 0 {
+            return (talent_points, None);
+        }
+
+        let talent_points = talent_points + reset_talents();
+        if talent_points >= 0 {
+            (talent_points, Some(String::from("the talents were reset to refund the points")))
+        } else {
+            let warning =
+                format!("the talents were reset, {} points couldn't be removed", -talent_points);
+            (0, Some(warning))
+        }
+    }
+
+    pub fn warning(&self, level: i32, xp: f32) -> Option<String> {
+        if !(1..=self.max_level()).contains(&level) {
+            Some(format!("Level {} is out of the 1 - {} range", level, self.max_level()))
+        } else if self.level(xp) != level {
+            Some(format!(
+                "{} XP is the XP of level {}, level {} starts at {} XP",
+                xp,
+                self.level(xp),
+                level,
+                self.xp(level)
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn xp_table() {
+        let table = XpTable::ME2;
+        assert!(table.level_xp.windows(2).all(|xp| xp[0] < xp[1]));
+        for level in 1..=table.max_level() {
+            assert_eq!(table.level(table.xp(level) as f32), level);
+        }
+
+        // From the test saves
+        assert_eq!(table.level(25_825.0), 26);
+
+        // At level 30 you have 51 points to spend
+        assert_eq!(table.player_points(1, 30), 51);
+        assert_eq!(table.player_points(30, 26), -4);
+    }
+
+    #[test]
+    fn earn_points() {
+        assert_eq!(XpTable::earn_points(5, -4, || unreachable!()), (1, None));
+        let (talent_points, warning) = XpTable::earn_points(2, -4, || 10);
+        assert_eq!(talent_points, 8);
+        assert!(warning.is_some());
+        let (talent_points, warning) = XpTable::earn_points(2, -10, || 3);
+        assert_eq!(talent_points, 0);
+        assert!(warning.unwrap().contains("5 points"));
+    }
+}
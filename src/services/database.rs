@@ -6,8 +6,13 @@ use yew::{prelude::*, ContextProvider};
 use crate::{
     save_data::{
         mass_effect_1::plot_db::Me1PlotDb, mass_effect_1_le::item_db::Me1ItemDb,
-        mass_effect_1_le::player_class_db::Me1LePlayerClassDb, mass_effect_2::plot_db::Me2PlotDb,
-        mass_effect_3::plot_db::Me3PlotDb, shared::plot::RawPlotDb,
+        mass_effect_1_le::player_class_db::Me1LePlayerClassDb,
+        mass_effect_1_le::talent_db::Me1LeTalentDb, mass_effect_2::plot_db::Me2PlotDb,
+        mass_effect_3::player_variable_db::Me3PlayerVariableDb, mass_effect_3::plot_db::Me3PlotDb,
+        mass_effect_3::power_db::Me3PowerDb, mass_effect_3::war_asset_db::Me3WarAssetDb,
+        mass_effect_3::weapon_db::Me3WeaponDb, shared::codex_db::CodexDb,
+        shared::galaxy_map_db::GalaxyMapDb, shared::player_class_db::PlayerClassDb,
+        shared::plot::RawPlotDb, shared::squad_db::SquadDb,
     },
     services::rpc,
 };
@@ -17,10 +22,24 @@ pub enum Type {
     Me1Plot,
     Me1RawPlot,
     Me1Items,
+    Me1LeTalents,
+    Me1LeCodex,
     Me2Plot,
     Me2RawPlot,
+    Me2PlayerClasses,
+    Me2Squad,
+    Me2Codex,
+    Me2GalaxyMap,
     Me3Plot,
     Me3RawPlot,
+    Me3PlayerClasses,
+    Me3Squad,
+    Me3Codex,
+    Me3GalaxyMap,
+    Me3PlayerVariables,
+    Me3Powers,
+    Me3WarAssets,
+    Me3Weapons,
 }
 
 pub enum Database {
@@ -28,10 +47,24 @@ pub enum Database {
     Me1Plot(Me1PlotDb),
     Me1RawPlot(RawPlotDb),
     Me1Items(Me1ItemDb),
+    Me1LeTalents(Me1LeTalentDb),
+    Me1LeCodex(CodexDb),
     Me2Plot(Me2PlotDb),
     Me2RawPlot(RawPlotDb),
+    Me2PlayerClasses(PlayerClassDb),
+    Me2Squad(SquadDb),
+    Me2Codex(CodexDb),
+    Me2GalaxyMap(GalaxyMapDb),
     Me3Plot(Me3PlotDb),
     Me3RawPlot(RawPlotDb),
+    Me3PlayerClasses(PlayerClassDb),
+    Me3Squad(SquadDb),
+    Me3Codex(CodexDb),
+    Me3GalaxyMap(GalaxyMapDb),
+    Me3PlayerVariables(Me3PlayerVariableDb),
+    Me3Powers(Me3PowerDb),
+    Me3WarAssets(Me3WarAssetDb),
+    Me3Weapons(Me3WeaponDb),
 }
 
 pub enum Msg {
@@ -41,73 +74,191 @@ pub enum Msg {
 }
 
 #[derive(Clone, Default)]
-pub struct Databases {
+struct LoadedDatabases {
     me1_le_player_classes: Option<Rc<Me1LePlayerClassDb>>,
     me1_plot: Option<Rc<Me1PlotDb>>,
     me1_raw_plot: Option<Rc<RawPlotDb>>,
     me1_item_db: Option<Rc<Me1ItemDb>>,
+    me1_le_talents: Option<Rc<Me1LeTalentDb>>,
+    me1_le_codex: Option<Rc<CodexDb>>,
     me2_plot: Option<Rc<Me2PlotDb>>,
     me2_raw_plot: Option<Rc<RawPlotDb>>,
+    me2_player_classes: Option<Rc<PlayerClassDb>>,
+    me2_squad: Option<Rc<SquadDb>>,
+    me2_codex: Option<Rc<CodexDb>>,
+    me2_galaxy_map: Option<Rc<GalaxyMapDb>>,
     me3_plot: Option<Rc<Me3PlotDb>>,
     me3_raw_plot: Option<Rc<RawPlotDb>>,
+    me3_player_classes: Option<Rc<PlayerClassDb>>,
+    me3_squad: Option<Rc<SquadDb>>,
+    me3_codex: Option<Rc<CodexDb>>,
+    me3_galaxy_map: Option<Rc<GalaxyMapDb>>,
+    me3_player_variables: Option<Rc<Me3PlayerVariableDb>>,
+    me3_powers: Option<Rc<Me3PowerDb>>,
+    me3_war_assets: Option<Rc<Me3WarAssetDb>>,
+    me3_weapons: Option<Rc<Me3WeaponDb>>,
+}
+
+#[derive(Clone, Default)]
+pub struct Databases {
+    // Behind a `Rc` so the messages carrying it stay small
+    loaded: Rc<LoadedDatabases>,
     load_callback: Callback<Type>,
 }
 
 impl Databases {
     pub fn get_me1_le_player_classes(self) -> Option<Rc<Me1LePlayerClassDb>> {
-        if self.me1_le_player_classes.is_none() {
+        if self.loaded.me1_le_player_classes.is_none() {
             self.load_database(Type::Me1LePlayerClasses);
         }
-        self.me1_le_player_classes
+        self.loaded.me1_le_player_classes.clone()
     }
 
     pub fn get_me1_plot(self) -> Option<Rc<Me1PlotDb>> {
-        if self.me1_plot.is_none() {
+        if self.loaded.me1_plot.is_none() {
             self.load_database(Type::Me1Plot);
         }
-        self.me1_plot
+        self.loaded.me1_plot.clone()
     }
 
     pub fn get_me1_raw_plot(self) -> Option<Rc<RawPlotDb>> {
-        if self.me1_raw_plot.is_none() {
+        if self.loaded.me1_raw_plot.is_none() {
             self.load_database(Type::Me1RawPlot);
         }
-        self.me1_raw_plot
+        self.loaded.me1_raw_plot.clone()
     }
 
     pub fn get_me1_item_db(self) -> Option<Rc<Me1ItemDb>> {
-        if self.me1_item_db.is_none() {
+        if self.loaded.me1_item_db.is_none() {
             self.load_database(Type::Me1Items);
         }
-        self.me1_item_db
+        self.loaded.me1_item_db.clone()
+    }
+
+    pub fn get_me1_le_talents(self) -> Option<Rc<Me1LeTalentDb>> {
+        if self.loaded.me1_le_talents.is_none() {
+            self.load_database(Type::Me1LeTalents);
+        }
+        self.loaded.me1_le_talents.clone()
+    }
+
+    pub fn get_me1_le_codex(self) -> Option<Rc<CodexDb>> {
+        if self.loaded.me1_le_codex.is_none() {
+            self.load_database(Type::Me1LeCodex);
+        }
+        self.loaded.me1_le_codex.clone()
     }
 
     pub fn get_me2_plot(self) -> Option<Rc<Me2PlotDb>> {
-        if self.me2_plot.is_none() {
+        if self.loaded.me2_plot.is_none() {
             self.load_database(Type::Me2Plot);
         }
-        self.me2_plot
+        self.loaded.me2_plot.clone()
     }
 
     pub fn get_me2_raw_plot(self) -> Option<Rc<RawPlotDb>> {
-        if self.me2_raw_plot.is_none() {
+        if self.loaded.me2_raw_plot.is_none() {
             self.load_database(Type::Me2RawPlot);
         }
-        self.me2_raw_plot
+        self.loaded.me2_raw_plot.clone()
+    }
+
+    pub fn get_me2_player_classes(self) -> Option<Rc<PlayerClassDb>> {
+        if self.loaded.me2_player_classes.is_none() {
+            self.load_database(Type::Me2PlayerClasses);
+        }
+        self.loaded.me2_player_classes.clone()
+    }
+
+    pub fn get_me2_squad(self) -> Option<Rc<SquadDb>> {
+        if self.loaded.me2_squad.is_none() {
+            self.load_database(Type::Me2Squad);
+        }
+        self.loaded.me2_squad.clone()
+    }
+
+    pub fn get_me2_codex(self) -> Option<Rc<CodexDb>> {
+        if self.loaded.me2_codex.is_none() {
+            self.load_database(Type::Me2Codex);
+        }
+        self.loaded.me2_codex.clone()
+    }
+
+    pub fn get_me2_galaxy_map(self) -> Option<Rc<GalaxyMapDb>> {
+        if self.loaded.me2_galaxy_map.is_none() {
+            self.load_database(Type::Me2GalaxyMap);
+        }
+        self.loaded.me2_galaxy_map.clone()
     }
 
     pub fn get_me3_plot(self) -> Option<Rc<Me3PlotDb>> {
-        if self.me3_plot.is_none() {
+        if self.loaded.me3_plot.is_none() {
             self.load_database(Type::Me3Plot);
         }
-        self.me3_plot
+        self.loaded.me3_plot.clone()
     }
 
     pub fn get_me3_raw_plot(self) -> Option<Rc<RawPlotDb>> {
-        if self.me3_raw_plot.is_none() {
+        if self.loaded.me3_raw_plot.is_none() {
             self.load_database(Type::Me3RawPlot);
         }
-        self.me3_raw_plot
+        self.loaded.me3_raw_plot.clone()
+    }
+
+    pub fn get_me3_player_classes(self) -> Option<Rc<PlayerClassDb>> {
+        if self.loaded.me3_player_classes.is_none() {
+            self.load_database(Type::Me3PlayerClasses);
+        }
+        self.loaded.me3_player_classes.clone()
+    }
+
+    pub fn get_me3_squad(self) -> Option<Rc<SquadDb>> {
+        if self.loaded.me3_squad.is_none() {
+            self.load_database(Type::Me3Squad);
+        }
+        self.loaded.me3_squad.clone()
+    }
+
+    pub fn get_me3_codex(self) -> Option<Rc<CodexDb>> {
+        if self.loaded.me3_codex.is_none() {
+            self.load_database(Type::Me3Codex);
+        }
+        self.loaded.me3_codex.clone()
+    }
+
+    pub fn get_me3_galaxy_map(self) -> Option<Rc<GalaxyMapDb>> {
+        if self.loaded.me3_galaxy_map.is_none() {
+            self.load_database(Type::Me3GalaxyMap);
+        }
+        self.loaded.me3_galaxy_map.clone()
+    }
+
+    pub fn get_me3_player_variables(self) -> Option<Rc<Me3PlayerVariableDb>> {
+        if self.loaded.me3_player_variables.is_none() {
+            self.load_database(Type::Me3PlayerVariables);
+        }
+        self.loaded.me3_player_variables.clone()
+    }
+
+    pub fn get_me3_powers(self) -> Option<Rc<Me3PowerDb>> {
+        if self.loaded.me3_powers.is_none() {
+            self.load_database(Type::Me3Powers);
+        }
+        self.loaded.me3_powers.clone()
+    }
+
+    pub fn get_me3_war_assets(self) -> Option<Rc<Me3WarAssetDb>> {
+        if self.loaded.me3_war_assets.is_none() {
+            self.load_database(Type::Me3WarAssets);
+        }
+        self.loaded.me3_war_assets.clone()
+    }
+
+    pub fn get_me3_weapons(self) -> Option<Rc<Me3WeaponDb>> {
+        if self.loaded.me3_weapons.is_none() {
+            self.load_database(Type::Me3Weapons);
+        }
+        self.loaded.me3_weapons.clone()
     }
 
     fn load_database(&self, db_type: Type) {
@@ -117,25 +268,52 @@ impl Databases {
 
 impl PartialEq for Databases {
     fn eq(&self, other: &Self) -> bool {
-        let Databases {
+        let LoadedDatabases {
             me1_le_player_classes,
             me1_plot,
             me1_raw_plot,
             me1_item_db,
+            me1_le_talents,
+            me1_le_codex,
             me2_plot,
             me2_raw_plot,
+            me2_player_classes,
+            me2_squad,
+            me2_codex,
+            me2_galaxy_map,
             me3_plot,
             me3_raw_plot,
-            load_callback: _,
-        } = self;
-        me1_le_player_classes.is_some() == other.me1_le_player_classes.is_some()
-            && me1_plot.is_some() == other.me1_plot.is_some()
-            && me1_raw_plot.is_some() == other.me1_raw_plot.is_some()
-            && me1_item_db.is_some() == other.me1_item_db.is_some()
-            && me2_plot.is_some() == other.me2_plot.is_some()
-            && me2_raw_plot.is_some() == other.me2_raw_plot.is_some()
-            && me3_plot.is_some() == other.me3_plot.is_some()
-            && me3_raw_plot.is_some() == other.me3_raw_plot.is_some()
+            me3_player_classes,
+            me3_squad,
+            me3_codex,
+            me3_galaxy_map,
+            me3_player_variables,
+            me3_powers,
+            me3_war_assets,
+            me3_weapons,
+        } = &*self.loaded;
+        me1_le_player_classes.is_some() == other.loaded.me1_le_player_classes.is_some()
+            && me1_plot.is_some() == other.loaded.me1_plot.is_some()
+            && me1_raw_plot.is_some() == other.loaded.me1_raw_plot.is_some()
+            && me1_item_db.is_some() == other.loaded.me1_item_db.is_some()
+            && me1_le_talents.is_some() == other.loaded.me1_le_talents.is_some()
+            && me1_le_codex.is_some() == other.loaded.me1_le_codex.is_some()
+            && me2_plot.is_some() == other.loaded.me2_plot.is_some()
+            && me2_raw_plot.is_some() == other.loaded.me2_raw_plot.is_some()
+            && me2_player_classes.is_some() == other.loaded.me2_player_classes.is_some()
+            && me2_squad.is_some() == other.loaded.me2_squad.is_some()
+            && me2_codex.is_some() == other.loaded.me2_codex.is_some()
+            && me2_galaxy_map.is_some() == other.loaded.me2_galaxy_map.is_some()
+            && me3_plot.is_some() == other.loaded.me3_plot.is_some()
+            && me3_raw_plot.is_some() == other.loaded.me3_raw_plot.is_some()
+            && me3_player_classes.is_some() == other.loaded.me3_player_classes.is_some()
+            && me3_squad.is_some() == other.loaded.me3_squad.is_some()
+            && me3_codex.is_some() == other.loaded.me3_codex.is_some()
+            && me3_galaxy_map.is_some() == other.loaded.me3_galaxy_map.is_some()
+            && me3_player_variables.is_some() == other.loaded.me3_player_variables.is_some()
+            && me3_powers.is_some() == other.loaded.me3_powers.is_some()
+            && me3_war_assets.is_some() == other.loaded.me3_war_assets.is_some()
+            && me3_weapons.is_some() == other.loaded.me3_weapons.is_some()
     }
 }
 
@@ -183,6 +361,18 @@ impl Component for DatabaseProvider {
                         let db = ron::from_str(&response)?;
                         Ok(Database::Me1Items(db))
                     }),
+                    Type::Me1LeTalents => {
+                        Self::load_db(ctx, "databases/me1_le_talent_db.ron", |response| {
+                            let db = ron::from_str(&response)?;
+                            Ok(Database::Me1LeTalents(db))
+                        })
+                    }
+                    Type::Me1LeCodex => {
+                        Self::load_db(ctx, "databases/me1_le_codex_db.ron", |response| {
+                            let db = ron::from_str(&response)?;
+                            Ok(Database::Me1LeCodex(db))
+                        })
+                    }
                     Type::Me2Plot => Self::load_db(ctx, "databases/me2_plot_db.ron", |response| {
                         let db = ron::from_str(&response)?;
                         Ok(Database::Me2Plot(db))
@@ -193,6 +383,30 @@ impl Component for DatabaseProvider {
                             Ok(Database::Me2RawPlot(db))
                         })
                     }
+                    Type::Me2PlayerClasses => {
+                        Self::load_db(ctx, "databases/me2_player_class_db.ron", |response| {
+                            let db = ron::from_str(&response)?;
+                            Ok(Database::Me2PlayerClasses(db))
+                        })
+                    }
+                    Type::Me2Squad => {
+                        Self::load_db(ctx, "databases/me2_squad_db.ron", |response| {
+                            let db = ron::from_str(&response)?;
+                            Ok(Database::Me2Squad(db))
+                        })
+                    }
+                    Type::Me2Codex => {
+                        Self::load_db(ctx, "databases/me2_codex_db.ron", |response| {
+                            let db = ron::from_str(&response)?;
+                            Ok(Database::Me2Codex(db))
+                        })
+                    }
+                    Type::Me2GalaxyMap => {
+                        Self::load_db(ctx, "databases/me2_galaxy_map_db.ron", |response| {
+                            let db = ron::from_str(&response)?;
+                            Ok(Database::Me2GalaxyMap(db))
+                        })
+                    }
                     Type::Me3Plot => Self::load_db(ctx, "databases/me3_plot_db.ron", |response| {
                         let db = ron::from_str(&response)?;
                         Ok(Database::Me3Plot(db))
@@ -203,34 +417,125 @@ impl Component for DatabaseProvider {
                             Ok(Database::Me3RawPlot(db))
                         })
                     }
+                    Type::Me3PlayerClasses => {
+                        Self::load_db(ctx, "databases/me3_player_class_db.ron", |response| {
+                            let db = ron::from_str(&response)?;
+                            Ok(Database::Me3PlayerClasses(db))
+                        })
+                    }
+                    Type::Me3Squad => {
+                        Self::load_db(ctx, "databases/me3_squad_db.ron", |response| {
+                            let db = ron::from_str(&response)?;
+                            Ok(Database::Me3Squad(db))
+                        })
+                    }
+                    Type::Me3Codex => {
+                        Self::load_db(ctx, "databases/me3_codex_db.ron", |response| {
+                            let db = ron::from_str(&response)?;
+                            Ok(Database::Me3Codex(db))
+                        })
+                    }
+                    Type::Me3GalaxyMap => {
+                        Self::load_db(ctx, "databases/me3_galaxy_map_db.ron", |response| {
+                            let db = ron::from_str(&response)?;
+                            Ok(Database::Me3GalaxyMap(db))
+                        })
+                    }
+                    Type::Me3PlayerVariables => {
+                        Self::load_db(ctx, "databases/me3_player_variable_db.ron", |response| {
+                            let db = ron::from_str(&response)?;
+                            Ok(Database::Me3PlayerVariables(db))
+                        })
+                    }
+                    Type::Me3Powers => {
+                        Self::load_db(ctx, "databases/me3_power_db.ron", |response| {
+                            let db = ron::from_str(&response)?;
+                            Ok(Database::Me3Powers(db))
+                        })
+                    }
+                    Type::Me3WarAssets => {
+                        Self::load_db(ctx, "databases/me3_war_asset_db.ron", |response| {
+                            let db = ron::from_str(&response)?;
+                            Ok(Database::Me3WarAssets(db))
+                        })
+                    }
+                    Type::Me3Weapons => {
+                        Self::load_db(ctx, "databases/me3_weapon_db.ron", |response| {
+                            let db = ron::from_str(&response)?;
+                            Ok(Database::Me3Weapons(db))
+                        })
+                    }
                 }
                 false
             }
             Msg::DatabaseLoaded(db) => {
+                let loaded = Rc::make_mut(&mut self.dbs.loaded);
                 match *db {
                     Database::Me1LePlayerClasses(db) => {
-                        self.dbs.me1_le_player_classes = Some(db.into());
+                        loaded.me1_le_player_classes = Some(db.into());
                     }
                     Database::Me1Plot(db) => {
-                        self.dbs.me1_plot = Some(db.into());
+                        loaded.me1_plot = Some(db.into());
                     }
                     Database::Me1RawPlot(db) => {
-                        self.dbs.me1_raw_plot = Some(db.into());
+                        loaded.me1_raw_plot = Some(db.into());
                     }
                     Database::Me1Items(db) => {
-                        self.dbs.me1_item_db = Some(db.into());
+                        loaded.me1_item_db = Some(db.into());
+                    }
+                    Database::Me1LeTalents(db) => {
+                        loaded.me1_le_talents = Some(db.into());
+                    }
+                    Database::Me1LeCodex(db) => {
+                        loaded.me1_le_codex = Some(db.into());
                     }
                     Database::Me2Plot(db) => {
-                        self.dbs.me2_plot = Some(db.into());
+                        loaded.me2_plot = Some(db.into());
                     }
                     Database::Me2RawPlot(db) => {
-                        self.dbs.me2_raw_plot = Some(db.into());
+                        loaded.me2_raw_plot = Some(db.into());
+                    }
+                    Database::Me2PlayerClasses(db) => {
+                        loaded.me2_player_classes = Some(db.into());
+                    }
+                    Database::Me2Squad(db) => {
+                        loaded.me2_squad = Some(db.into());
+                    }
+                    Database::Me2Codex(db) => {
+                        loaded.me2_codex = Some(db.into());
+                    }
+                    Database::Me2GalaxyMap(db) => {
+                        loaded.me2_galaxy_map = Some(db.into());
                     }
                     Database::Me3Plot(db) => {
-                        self.dbs.me3_plot = Some(db.into());
+                        loaded.me3_plot = Some(db.into());
                     }
                     Database::Me3RawPlot(db) => {
-                        self.dbs.me3_raw_plot = Some(db.into());
+                        loaded.me3_raw_plot = Some(db.into());
+                    }
+                    Database::Me3PlayerClasses(db) => {
+                        loaded.me3_player_classes = Some(db.into());
+                    }
+                    Database::Me3Squad(db) => {
+                        loaded.me3_squad = Some(db.into());
+                    }
+                    Database::Me3Codex(db) => {
+                        loaded.me3_codex = Some(db.into());
+                    }
+                    Database::Me3GalaxyMap(db) => {
+                        loaded.me3_galaxy_map = Some(db.into());
+                    }
+                    Database::Me3PlayerVariables(db) => {
+                        loaded.me3_player_variables = Some(db.into());
+                    }
+                    Database::Me3Powers(db) => {
+                        loaded.me3_powers = Some(db.into());
+                    }
+                    Database::Me3WarAssets(db) => {
+                        loaded.me3_war_assets = Some(db.into());
+                    }
+                    Database::Me3Weapons(db) => {
+                        loaded.me3_weapons = Some(db.into());
                     }
                 }
                 true
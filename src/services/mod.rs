@@ -2,3 +2,4 @@ pub mod database;
 pub mod drop_handler;
 pub mod rpc;
 pub mod save_handler;
+pub mod save_summary;
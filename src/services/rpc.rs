@@ -86,6 +86,26 @@ pub async fn export_head_morph_dialog() -> Result<Option<PathBuf>> {
     call("export_head_morph_dialog").await
 }
 
+pub async fn backup_save(params: BackupParams) -> Result<()> {
+    call_with_params("backup_save", params).await
+}
+
+pub async fn list_backups(path: PathBuf) -> Result<Vec<BackupFile>> {
+    call_with_params("list_backups", path).await
+}
+
+pub async fn restore_backup(params: RestoreParams) -> Result<RpcFile> {
+    call_with_params("restore_backup", params).await
+}
+
+pub async fn read_save_header(params: HeaderParams) -> Result<RpcFile> {
+    call_with_params("read_save_header", params).await
+}
+
+pub async fn list_saves() -> Result<Vec<SaveFile>> {
+    call("list_saves").await
+}
+
 pub async fn load_database(path: &str) -> Result<RpcFile> {
     call_with_params("load_database", path).await
 }
@@ -97,6 +117,37 @@ pub struct DialogParams {
     pub filters: Vec<(&'static str, Vec<&'static str>)>,
 }
 
+#[derive(Serialize)]
+pub struct BackupParams {
+    pub path: PathBuf,
+    pub retention: usize,
+}
+
+#[derive(Serialize)]
+pub struct RestoreParams {
+    pub backup: PathBuf,
+    pub path: PathBuf,
+    pub retention: usize,
+}
+
+#[derive(Serialize)]
+pub struct HeaderParams {
+    pub path: PathBuf,
+    pub len: usize,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct BackupFile {
+    pub path: PathBuf,
+    pub timestamp: f64,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct SaveFile {
+    pub path: PathBuf,
+    pub modified: f64,
+}
+
 #[derive(Deserialize, Serialize)]
 pub struct RpcFile {
     pub path: PathBuf,
@@ -2,7 +2,10 @@ use std::{path::PathBuf, rc::Rc};
 
 use anyhow::{bail, Context as ErrorContext, Error, Result};
 use crc::{Crc, CRC_32_BZIP2};
-use gloo::utils;
+use gloo::{
+    storage::{LocalStorage, Storage},
+    utils,
+};
 use ron::ser::PrettyConfig;
 use serde::Deserialize;
 use yew::{prelude::*, ContextProvider};
@@ -18,12 +21,23 @@ use crate::{
         shared::appearance::HeadMorph,
         RcRef,
     },
-    services::rpc::{self, Base64File, DialogParams, RpcFile},
+    services::rpc::{self, BackupParams, Base64File, DialogParams, RestoreParams, RpcFile},
     unreal,
 };
 
 use super::drop_handler::DropHandler;
 
+const BACKUP_RETENTION_KEY: &str = "backup_retention";
+pub const DEFAULT_BACKUP_RETENTION: usize = 10;
+
+pub fn backup_retention() -> usize {
+    LocalStorage::get(BACKUP_RETENTION_KEY).unwrap_or(DEFAULT_BACKUP_RETENTION)
+}
+
+pub fn set_backup_retention(retention: usize) {
+    let _ = LocalStorage::set(BACKUP_RETENTION_KEY, retention);
+}
+
 #[derive(Clone)]
 pub enum SaveGame {
     MassEffect1 { file_path: PathBuf, save_game: RcRef<Me1SaveGame> },
@@ -34,10 +48,38 @@ pub enum SaveGame {
     MassEffect3 { file_path: PathBuf, save_game: RcRef<Me3SaveGame> },
 }
 
+impl SaveGame {
+    pub fn file_path(&self) -> &PathBuf {
+        match self {
+            SaveGame::MassEffect1 { file_path, .. }
+            | SaveGame::MassEffect1Le { file_path, .. }
+            | SaveGame::MassEffect1LePs4 { file_path, .. }
+            | SaveGame::MassEffect2 { file_path, .. }
+            | SaveGame::MassEffect2Le { file_path, .. }
+            | SaveGame::MassEffect3 { file_path, .. } => file_path,
+        }
+    }
+
+    // Parts of the save that couldn't be read but are kept unchanged
+    fn has_unknown_data(&self) -> bool {
+        match self {
+            SaveGame::MassEffect1Le { save_game, .. } => {
+                save_game.borrow().save_data.borrow().no_export().unknown_error().is_some()
+            }
+            SaveGame::MassEffect1LePs4 { save_game, .. } => {
+                save_game.borrow().no_export().unknown_error().is_some()
+            }
+            _ => false,
+        }
+    }
+}
+
 pub enum Action {
     OpenSave,
+    OpenPath(PathBuf),
     SaveSave,
     ReloadSave,
+    RestoreBackup(PathBuf),
     ImportHeadMorph(Callback<HeadMorph>),
     ExportHeadMorph(RcRef<HeadMorph>),
 }
@@ -47,6 +89,7 @@ pub enum Msg {
     SaveOpened(SaveGame),
     SaveDropped(Result<(String, Vec<u8>)>),
     SaveSaved,
+    BackupRestored(SaveGame),
     HeadMorphImported(HeadMorph, Callback<HeadMorph>),
     HeadMorphExported,
     Error(Error),
@@ -109,6 +152,7 @@ impl Component for SaveHandlerProvider {
                         let last_dir = self.save_handler.save_game.is_some();
                         Self::open_save(ctx, last_dir);
                     }
+                    Action::OpenPath(path) => Self::reload_save(ctx, path),
                     Action::SaveSave => {
                         if let Some(ref save_game) = self.save_handler.save_game {
                             Self::save_save(ctx, save_game);
@@ -116,16 +160,12 @@ impl Component for SaveHandlerProvider {
                     }
                     Action::ReloadSave => {
                         if let Some(ref save_game) = self.save_handler.save_game {
-                            match save_game.as_ref() {
-                                SaveGame::MassEffect1 { file_path, .. }
-                                | SaveGame::MassEffect1Le { file_path, .. }
-                                | SaveGame::MassEffect1LePs4 { file_path, .. }
-                                | SaveGame::MassEffect2 { file_path, .. }
-                                | SaveGame::MassEffect2Le { file_path, .. }
-                                | SaveGame::MassEffect3 { file_path, .. } => {
-                                    Self::reload_save(ctx, file_path.clone())
-                                }
-                            }
+                            Self::reload_save(ctx, save_game.file_path().clone());
+                        }
+                    }
+                    Action::RestoreBackup(backup) => {
+                        if let Some(ref save_game) = self.save_handler.save_game {
+                            Self::restore_backup(ctx, save_game.file_path().clone(), backup);
                         }
                     }
                     Action::ImportHeadMorph(callback) => Self::import_head_morph(ctx, callback),
@@ -135,9 +175,14 @@ impl Component for SaveHandlerProvider {
             }
             // Messages
             Msg::SaveOpened(save_game) => {
+                let notification = if save_game.has_unknown_data() {
+                    "Opened, unknown world objects are kept unchanged"
+                } else {
+                    "Opened"
+                };
                 self.save_handler.save_game = Some(save_game.into());
                 self.change_theme();
-                ctx.props().onnotification.emit("Opened");
+                ctx.props().onnotification.emit(notification);
                 true
             }
             Msg::SaveDropped(result) => {
@@ -151,6 +196,17 @@ impl Component for SaveHandlerProvider {
                 ctx.props().onnotification.emit("Saved");
                 false
             }
+            Msg::BackupRestored(save_game) => {
+                let notification = if save_game.has_unknown_data() {
+                    "Restored, unknown world objects are kept unchanged"
+                } else {
+                    "Restored"
+                };
+                self.save_handler.save_game = Some(save_game.into());
+                self.change_theme();
+                ctx.props().onnotification.emit(notification);
+                true
+            }
             Msg::HeadMorphImported(head_morph, callback) => {
                 callback.emit(head_morph);
                 ctx.props().onnotification.emit("Imported");
@@ -274,6 +330,9 @@ impl SaveHandlerProvider {
                 let cancelled = match has_path {
                     Some(path) => {
                         let rpc_file = Self::serialize(path, save_game)?;
+                        let retention = backup_retention();
+                        rpc::backup_save(BackupParams { path: rpc_file.path.clone(), retention })
+                            .await?;
                         rpc::save_file(rpc_file).await?;
                         false
                     }
@@ -305,7 +364,24 @@ impl SaveHandlerProvider {
         });
     }
 
-    fn deserialize(file_path: PathBuf, input: Vec<u8>) -> Result<SaveGame> {
+    fn restore_backup(ctx: &Context<Self>, path: PathBuf, backup: PathBuf) {
+        ctx.link().send_future(async move {
+            let handle_save = async move {
+                let retention = backup_retention();
+                let rpc_file =
+                    rpc::restore_backup(RestoreParams { backup, path, retention }).await?;
+                let RpcFile { path, file } = rpc_file;
+                Self::deserialize(path, file.decode()?)
+            };
+
+            match handle_save.await.context("Failed to restore the backup") {
+                Ok(save_game) => Msg::BackupRestored(save_game),
+                Err(err) => Msg::Error(err),
+            }
+        });
+    }
+
+    pub fn deserialize(file_path: PathBuf, input: Vec<u8>) -> Result<SaveGame> {
         fn header<'de, T>(header: &'de [u8]) -> Result<T, unreal::Error>
         where
             T: Deserialize<'de>,
@@ -0,0 +1,421 @@
+use std::{fmt::Display, path::PathBuf};
+
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use js_sys::Date;
+use serde::Deserialize;
+use wasm_bindgen::JsValue;
+
+use crate::{
+    save_data::{
+        mass_effect_1::{
+            data::{Property, StructType},
+            player::Player,
+            state::State,
+            Me1MagicNumber, Me1SaveHeader,
+        },
+        mass_effect_1_le::{
+            player::Me1LeClass, Me1LeMagicNumber, Me1LeSaveDataHeader, Me1LeSaveHeader,
+            Me1LeVersion,
+        },
+        mass_effect_2::{Difficulty as Me2Difficulty, Me2LeVersion, Me2SaveHeader, Me2Version},
+        mass_effect_3::{Difficulty as Me3Difficulty, Me3SaveHeader, Me3Version},
+        shared::{
+            plot::{BitVec, PlotTable},
+            SaveTimeStamp,
+        },
+    },
+    services::{
+        rpc::{self, HeaderParams, RpcFile},
+        save_handler::{SaveGame, SaveHandlerProvider},
+    },
+    unreal,
+};
+
+// The player of ME2 and ME3 is in the first 64 KiB of the save
+const HEADER_LEN: usize = 64 * 1024;
+
+const ME1_DIFFICULTIES: [&str; 5] = ["Casual", "Normal", "Veteran", "Hardcore", "Insanity"];
+
+#[derive(Clone, PartialEq)]
+pub struct SaveSummary {
+    pub game: &'static str,
+    pub character_name: String,
+    pub class: String,
+    pub level: i32,
+    pub base_level_name: String,
+    pub timestamp: Option<String>,
+    pub seconds_played: Option<i32>,
+    pub difficulty: Option<&'static str>,
+}
+
+impl SaveSummary {
+    // Only reads the start of the save, its length is checked by `open_summary`
+    pub fn from_header(input: &[u8]) -> Result<Self> {
+        fn header<'de, T>(header: &'de [u8]) -> Result<T, unreal::Error>
+        where
+            T: Deserialize<'de>,
+        {
+            unreal::Deserializer::from_bytes::<T>(header)
+        }
+
+        macro_rules! summary {
+            ($game:literal, $header:ident, $difficulty:ident) => {{
+                let player = &$header.player;
+                SaveSummary {
+                    game: $game,
+                    character_name: player.first_name.clone(),
+                    class: class_name(&player.class_name),
+                    level: player.level,
+                    base_level_name: $header.base_level_name,
+                    timestamp: Some(timestamp(&$header.timestamp)),
+                    seconds_played: Some($header.seconds_played as i32),
+                    difficulty: $difficulty::variants()
+                        .get(usize::from($header.difficulty))
+                        .copied(),
+                }
+            }};
+        }
+
+        let summary = if header::<Me1MagicNumber>(input).is_ok() {
+            let Me1SaveHeader { player, state } = unreal::Deserializer::from_bytes(input)?;
+            Self::me1(&player, &state)
+        } else if header::<Me1LeMagicNumber>(input).is_ok() {
+            let Me1LeSaveHeader { save_data } = unreal::Deserializer::from_bytes(input)?;
+            Self::me1_le(save_data)
+        } else if header::<Me1LeVersion>(input).is_ok() {
+            Self::me1_le(unreal::Deserializer::from_bytes(input)?)
+        } else if let Ok(version) = header::<Me2Version>(input) {
+            let header: Me2SaveHeader = if version.is_xbox360 {
+                unreal::Deserializer::from_be_bytes(input)?
+            } else {
+                unreal::Deserializer::from_bytes(input)?
+            };
+            summary!("Mass Effect 2", header, Me2Difficulty)
+        } else if header::<Me2LeVersion>(input).is_ok() {
+            let header: Me2SaveHeader = unreal::Deserializer::from_bytes(input)?;
+            summary!("Mass Effect 2 Legendary", header, Me2Difficulty)
+        } else if let Ok(version) = header::<Me3Version>(input) {
+            let header: Me3SaveHeader = if version.is_xbox360 {
+                unreal::Deserializer::from_be_bytes(input)?
+            } else {
+                unreal::Deserializer::from_bytes(input)?
+            };
+            summary!("Mass Effect 3", header, Me3Difficulty)
+        } else {
+            bail!("Unsupported file");
+        };
+        Ok(summary)
+    }
+
+    fn me1(player: &Player, state: &State) -> Self {
+        let m_player = player.current_game().and_then(|current_game| {
+            let object_id = player.find_object_id(&current_game.properties, "m_Player")?;
+            Some(player.get_data(object_id))
+        });
+
+        let property = |property_name| {
+            m_player.and_then(|m_player| player.find_property(&m_player.properties, property_name))
+        };
+
+        let character_name = property("m_FirstName")
+            .and_then(|p| match *p.borrow() {
+                Property::Str { ref string, .. } => Some(string.borrow().clone()),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        let class = property("m_ClassBase")
+            .and_then(|p| match *p.borrow() {
+                Property::Name { ref value_name_id, .. } => {
+                    Some(player.get_name(value_name_id.get()))
+                }
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        let level = property("m_XPLevel")
+            .and_then(|p| match *p.borrow() {
+                Property::Int { ref value, .. } => Some(value.get()),
+                _ => None,
+            })
+            .unwrap_or_default();
+
+        let difficulty = player
+            .current_game()
+            .and_then(|current_game| {
+                let m_game_options =
+                    player.find_property(&current_game.properties, "m_GameOptions")?.borrow();
+                match *m_game_options {
+                    Property::Struct {
+                        struct_type: StructType::Properties(ref properties),
+                        ..
+                    } => match *player.find_property(properties, "m_nCombatDifficulty")?.borrow() {
+                        Property::Int { ref value, .. } => Some(value.get() as usize),
+                        _ => None,
+                    },
+                    _ => None,
+                }
+            })
+            .and_then(|idx| ME1_DIFFICULTIES.get(idx).copied());
+
+        SaveSummary {
+            game: "Mass Effect 1",
+            character_name,
+            class,
+            level,
+            base_level_name: state.base_level_name().clone(),
+            timestamp: None,
+            seconds_played: None,
+            difficulty,
+        }
+    }
+
+    fn me1_le(header: Me1LeSaveDataHeader) -> Self {
+        let player = &header.player;
+        let summary = SaveSummary {
+            game: "Mass Effect 1 Legendary",
+            character_name: player.first_name().clone(),
+            class: Me1LeClass::variants()[usize::from(player.player_class().clone())].to_owned(),
+            level: player.level(),
+            base_level_name: header.base_level_name.clone(),
+            timestamp: Some(timestamp(&header.timestamp)),
+            seconds_played: Some(header.seconds_played),
+            difficulty: player
+                .game_options()
+                .first()
+                .and_then(|idx| ME1_DIFFICULTIES.get(idx.get() as usize))
+                .copied(),
+        };
+        summary
+    }
+
+    pub fn diff(&self, other: &SaveSummary) -> Vec<Change> {
+        let mut changes = Vec::new();
+        push_change(&mut changes, "Game", &self.game, &other.game);
+        push_change(&mut changes, "Name", &self.character_name, &other.character_name);
+        push_change(&mut changes, "Class", &self.class, &other.class);
+        push_change(&mut changes, "Level", &self.level, &other.level);
+        push_change(&mut changes, "Location", &self.base_level_name, &other.base_level_name);
+        push_change(
+            &mut changes,
+            "Difficulty",
+            &self.difficulty.unwrap_or_default(),
+            &other.difficulty.unwrap_or_default(),
+        );
+        changes
+    }
+
+    // `3725` => `1h 02m`
+    pub fn played_time(&self) -> Option<String> {
+        self.seconds_played
+            .map(|seconds| format!("{}h {:02}m", seconds / 3600, seconds % 3600 / 60))
+    }
+}
+
+// Milliseconds since the epoch => local date
+pub fn local_date(timestamp: f64) -> String {
+    let date = Date::new(&JsValue::from_f64(timestamp));
+    String::from(date.to_locale_string("default", &JsValue::UNDEFINED))
+}
+
+// Reads the start of the save, then more of it while the header is truncated
+pub async fn open_summary(path: PathBuf) -> Result<SaveSummary> {
+    let mut len = HEADER_LEN;
+    loop {
+        let RpcFile { file, .. } =
+            rpc::read_save_header(HeaderParams { path: path.clone(), len }).await?;
+        let header = file.decode()?;
+
+        match SaveSummary::from_header(&header) {
+            Err(_) if header.len() == len => len *= 4,
+            summary => return summary,
+        }
+    }
+}
+
+// Reads both files from the disk and lists what changed from `old` to `new`
+pub async fn diff_saves(old: PathBuf, new: PathBuf) -> Result<Vec<Change>> {
+    async fn open_save(path: PathBuf) -> Result<(SaveSummary, SaveGame)> {
+        let RpcFile { path, file } = rpc::reload_save(path).await?;
+        let input = file.decode()?;
+        let summary = SaveSummary::from_header(&input)?;
+        Ok((summary, SaveHandlerProvider::deserialize(path, input)?))
+    }
+
+    let (old_summary, old) = open_save(old).await?;
+    let (new_summary, new) = open_save(new).await?;
+
+    let mut changes = old_summary.diff(&new_summary);
+    changes.extend(PlotSnapshot::new(&old).diff(&PlotSnapshot::new(&new)));
+    Ok(changes)
+}
+
+// `SFXGame.SFXPawn_PlayerSoldier` => `Soldier`
+fn class_name(class_name: &str) -> String {
+    let class_name = class_name.rsplit('.').next().unwrap_or(class_name);
+    class_name.trim_start_matches("SFXPawn_Player").to_owned()
+}
+
+fn timestamp(timestamp: &SaveTimeStamp) -> String {
+    let seconds = timestamp.seconds_since_midnight();
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        timestamp.year(),
+        timestamp.month(),
+        timestamp.day(),
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Change {
+    pub label: String,
+    pub old: String,
+    pub new: String,
+}
+
+fn push_change<T: Display + PartialEq>(changes: &mut Vec<Change>, label: &str, old: &T, new: &T) {
+    if old != new {
+        changes.push(Change {
+            label: label.to_owned(),
+            old: old.to_string(),
+            new: new.to_string(),
+        });
+    }
+}
+
+// Normalized plot tables so every game can be diffed the same way
+#[derive(Default)]
+pub struct PlotSnapshot {
+    booleans: Vec<bool>,
+    integers: IndexMap<i32, i32>,
+    floats: IndexMap<i32, f32>,
+}
+
+impl PlotSnapshot {
+    pub fn new(save_game: &SaveGame) -> Self {
+        fn booleans(booleans: &BitVec) -> Vec<bool> {
+            booleans.iter().map(|b| *b).collect()
+        }
+
+        fn vec_to_map<T: Copy>(vec: &[T]) -> IndexMap<i32, T> {
+            vec.iter().enumerate().map(|(i, value)| (i as i32, *value)).collect()
+        }
+
+        fn snapshot(plot: &PlotTable) -> PlotSnapshot {
+            PlotSnapshot {
+                booleans: booleans(&plot.booleans()),
+                integers: vec_to_map(&plot.integers().iter().map(|i| i.get()).collect::<Vec<_>>()),
+                floats: vec_to_map(&plot.floats().iter().map(|f| f.get()).collect::<Vec<_>>()),
+            }
+        }
+
+        match save_game {
+            SaveGame::MassEffect1 { save_game, .. } => snapshot(&save_game.borrow().state().plot()),
+            SaveGame::MassEffect1Le { save_game, .. } => {
+                snapshot(&save_game.borrow().save_data.borrow().plot())
+            }
+            SaveGame::MassEffect1LePs4 { save_game, .. } => snapshot(&save_game.borrow().plot()),
+            SaveGame::MassEffect2 { save_game, .. } => snapshot(&save_game.borrow().plot()),
+            SaveGame::MassEffect2Le { save_game, .. } => snapshot(&save_game.borrow().plot()),
+            SaveGame::MassEffect3 { save_game, .. } => {
+                let me3 = save_game.borrow();
+                let plot = me3.plot();
+                let snapshot = PlotSnapshot {
+                    booleans: booleans(&plot.booleans()),
+                    integers: plot.integers().iter().map(|(&k, v)| (k, v.get())).collect(),
+                    floats: plot.floats().iter().map(|(&k, v)| (k, v.get())).collect(),
+                };
+                snapshot
+            }
+        }
+    }
+
+    pub fn diff(&self, other: &PlotSnapshot) -> Vec<Change> {
+        let mut changes = Vec::new();
+
+        let len = self.booleans.len().max(other.booleans.len());
+        for i in 0..len {
+            let old = self.booleans.get(i).copied().unwrap_or_default();
+            let new = other.booleans.get(i).copied().unwrap_or_default();
+            push_change(&mut changes, &format!("Boolean {}", i), &old, &new);
+        }
+
+        fn diff_map<T: Copy + Default + Display + PartialEq>(
+            changes: &mut Vec<Change>, kind: &str, old: &IndexMap<i32, T>, new: &IndexMap<i32, T>,
+        ) {
+            let mut keys: Vec<i32> = old.keys().chain(new.keys()).copied().collect();
+            keys.sort_unstable();
+            keys.dedup();
+
+            for key in keys {
+                let old = old.get(&key).copied().unwrap_or_default();
+                let new = new.get(&key).copied().unwrap_or_default();
+                push_change(changes, &format!("{} {}", kind, key), &old, &new);
+            }
+        }
+        diff_map(&mut changes, "Integer", &self.integers, &other.integers);
+        diff_map(&mut changes, "Float", &self.floats, &other.floats);
+
+        changes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use anyhow::Result;
+
+    use super::*;
+    use crate::services::save_handler::SaveHandlerProvider;
+
+    #[test]
+    fn summary() -> Result<()> {
+        let files = [
+            ("test/ME1Save.MassEffectSave", "Mass Effect 1"),
+            ("test/ME1LeSave.pcsav", "Mass Effect 1 Legendary"),
+            ("test/ME2Save.pcsav", "Mass Effect 2"),
+            ("test/ME2Save360.xbsav", "Mass Effect 2"),
+            ("test/ME2LeSave.pcsav", "Mass Effect 2 Legendary"),
+            ("test/ME3Save.pcsav", "Mass Effect 3"),
+            ("test/ME3Save360.xbsav", "Mass Effect 3"),
+        ];
+
+        for (file, game) in files {
+            let input = fs::read(file)?;
+            let summary = SaveSummary::from_header(&input)?;
+
+            assert_eq!(summary.game, game);
+            assert!(!summary.character_name.is_empty());
+            assert!(summary.level > 0);
+            assert!(summary.difficulty.is_some());
+
+            // A save is identical to itself
+            assert!(summary.diff(&summary).is_empty());
+            let save_game = SaveHandlerProvider::deserialize(file.into(), input)?;
+            let plot = PlotSnapshot::new(&save_game);
+            assert!(plot.diff(&plot).is_empty());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncated_header() -> Result<()> {
+        // The ME2 and ME3 headers fit in what is read first
+        for file in ["test/ME2Save.pcsav", "test/ME2LeSave.pcsav", "test/ME3Save.pcsav"] {
+            let input = fs::read(file)?;
+            let len = HEADER_LEN.min(input.len() / 2);
+            assert!(SaveSummary::from_header(&input[..len]).is_ok());
+        }
+
+        // The others need more of the file
+        let input = fs::read("test/ME1LeSave.pcsav")?;
+        assert!(SaveSummary::from_header(&input[..input.len() / 2]).is_err());
+
+        Ok(())
+    }
+}
@@ -47,7 +47,7 @@ macro_rules! unimpl_deserialize {
         where
             V: Visitor<'de>,
         {
-            unimplemented!()
+            Err(Error::custom(concat!(stringify!($de_method), " is not supported")))
         }
     };
 }
@@ -139,7 +139,8 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
 
         let mut string = if len < 0 {
             // Unicode
-            let string_len = (len.abs() * 2) as usize;
+            let string_len =
+                (len.unsigned_abs() as usize).checked_mul(2).ok_or(super::Error::Eof)?;
             let bytes = self.read(string_len)?.to_owned();
 
             let (decoded, _, had_errors) =
@@ -204,7 +205,7 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     where
         V: Visitor<'de>,
     {
-        unimplemented!()
+        Err(Error::custom("deserialize_unit_struct is not supported"))
     }
 
     fn deserialize_newtype_struct<V>(self, _: &'static str, visitor: V) -> Result<V::Value>
@@ -297,11 +298,14 @@ impl<'a, 'de> SizedSeqMap<'a, 'de> {
     }
 }
 
+// `len` comes from the input, a hint that big would allocate before failing
+const MAX_SIZE_HINT: usize = 4096;
+
 impl<'de, 'a> SeqAccess<'de> for SizedSeqMap<'a, 'de> {
     type Error = super::Error;
 
     fn size_hint(&self) -> Option<usize> {
-        Some(self.len)
+        Some(self.len.min(MAX_SIZE_HINT))
     }
 
     fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
@@ -321,7 +325,7 @@ impl<'de, 'a> MapAccess<'de> for SizedSeqMap<'a, 'de> {
     type Error = super::Error;
 
     fn size_hint(&self) -> Option<usize> {
-        Some(self.len)
+        Some(self.len.min(MAX_SIZE_HINT))
     }
 
     fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
@@ -362,7 +366,7 @@ impl<'de, 'a> VariantAccess<'de> for &'a mut Deserializer<'de> {
     where
         V: Visitor<'de>,
     {
-        unimplemented!()
+        Err(Error::custom("tuple_variant is not supported"))
     }
 
     fn struct_variant<V>(self, fields: &'static [&'static str], visitor: V) -> Result<V::Value>
@@ -373,3 +377,108 @@ impl<'de, 'a> VariantAccess<'de> for &'a mut Deserializer<'de> {
         self.deserialize_tuple(fields.len(), visitor)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::io::{Cursor, Read};
+
+    use anyhow::Result;
+    use serde::de::DeserializeOwned;
+    use zip::ZipArchive;
+
+    use super::*;
+    use crate::save_data::{
+        mass_effect_1::{player::Player, state::State, Me1SaveGame},
+        mass_effect_1_le::{Me1LeSaveData, Me1LeSaveGame},
+        mass_effect_2::{Me2LeSaveGame, Me2SaveGame},
+        mass_effect_3::Me3SaveGame,
+    };
+
+    // Xorshift, no need for a real rng to mutate bytes
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, max: usize) -> usize {
+            (self.next() % max.max(1) as u64) as usize
+        }
+    }
+
+    fn mutate(rng: &mut Rng, input: &[u8]) -> Vec<u8> {
+        let mut bytes = input.to_vec();
+        match rng.below(4) {
+            // Flip a few bytes
+            0 => {
+                for _ in 0..=rng.below(8) {
+                    let idx = rng.below(bytes.len());
+                    bytes[idx] ^= rng.next() as u8;
+                }
+            }
+            // Overwrite what could be a length
+            1 => {
+                let idx = rng.below(bytes.len().saturating_sub(4));
+                let len = [u32::MAX, i32::MAX as u32, 1 << 31, rng.next() as u32][rng.below(4)];
+                bytes[idx..idx + 4].copy_from_slice(&len.to_le_bytes());
+            }
+            // Truncate
+            2 => bytes.truncate(rng.below(bytes.len())),
+            // Garbage
+            _ => {
+                bytes = (0..rng.below(256)).map(|_| rng.next() as u8).collect();
+            }
+        }
+        bytes
+    }
+
+    // Only the absence of panic matters
+    fn fuzz<T: DeserializeOwned>(rng: &mut Rng, input: &[u8], is_le: bool) {
+        for _ in 0..200 {
+            let bytes = mutate(rng, input);
+            let _ = if is_le {
+                Deserializer::from_bytes::<T>(&bytes)
+            } else {
+                Deserializer::from_be_bytes::<T>(&bytes)
+            };
+        }
+    }
+
+    fn unzip(input: &[u8], file_name: &str) -> Result<Vec<u8>> {
+        let mut offset_bytes = [0; 4];
+        offset_bytes.copy_from_slice(&input[8..12]);
+        let zip_offset = <u32>::from_le_bytes(offset_bytes);
+        let mut zip = ZipArchive::new(Cursor::new(&input[zip_offset as usize..]))?;
+
+        let mut bytes = Vec::new();
+        zip.by_name(file_name)?.read_to_end(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    #[test]
+    fn fuzz_save_games() -> Result<()> {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+
+        let me1 = fs::read("test/ME1Save.MassEffectSave")?;
+        fuzz::<Me1SaveGame>(&mut rng, &me1, true);
+        fuzz::<Player>(&mut rng, &unzip(&me1, "player.sav")?, true);
+        fuzz::<State>(&mut rng, &unzip(&me1, "state.sav")?, true);
+
+        fuzz::<Me1LeSaveGame>(&mut rng, &fs::read("test/ME1LeSave.pcsav")?, true);
+        fuzz::<Me1LeSaveData>(&mut rng, &fs::read("test/ME1LeSave.uncompressed")?, true);
+
+        fuzz::<Me2SaveGame>(&mut rng, &fs::read("test/ME2Save.pcsav")?, true);
+        fuzz::<Me2SaveGame>(&mut rng, &fs::read("test/ME2Save360.xbsav")?, false);
+        fuzz::<Me2LeSaveGame>(&mut rng, &fs::read("test/ME2LeSave.pcsav")?, true);
+
+        fuzz::<Me3SaveGame>(&mut rng, &fs::read("test/ME3Save.pcsav")?, true);
+        fuzz::<Me3SaveGame>(&mut rng, &fs::read("test/ME3Save360.xbsav")?, false);
+
+        Ok(())
+    }
+}